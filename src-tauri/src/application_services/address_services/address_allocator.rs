@@ -0,0 +1,537 @@
+use crate::application_services::excel_services::io_excel_services::{DataType, IOExcelService, IoError};
+use crate::model_domain::address_plan_model::{AddressPlan, AddressRange, BOOL_BITS_PER_BYTE, STATUS_WORD_BITS};
+use crate::model_domain::generation_report_model::AreaUtilization;
+use crate::model_domain::io_table_model::{comm_address_field, IOTableRow, MAIN_PLC_ADDRESS_FIELD, PLC_ADDRESS_FIELDS};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+
+/// 不适用的地址占位符
+const NOT_APPLICABLE: &str = "/";
+
+/// 单个地址区的分配游标
+///
+/// REAL地址区按字节计数，BOOL地址区按位计数（字节*8+位）
+#[derive(Debug)]
+struct AreaCursor {
+    /// 地址区名称
+    name: &'static str,
+    /// 地址范围的显示文本，例如%MD320-%MD13999
+    label: String,
+    /// 地址范围（单位与游标一致，首尾均包含）
+    range: AddressRange,
+    /// 第一个可分配的位置
+    first: u32,
+    /// 下一个待分配的位置
+    next: u32,
+    /// 已占用的单元（字节或位），包括沿用的锁定地址
+    occupied: HashSet<u32>,
+}
+
+impl AreaCursor {
+    fn new(name: &'static str, label: String, range: AddressRange, first: u32) -> Self {
+        Self { name, label, range, first, next: first, occupied: HashSet::new() }
+    }
+
+    /// 从指定位置开始的size个单元是否都在范围内且未被占用
+    fn is_free(&self, position: u32, size: u32) -> bool {
+        position >= self.range.start
+            && position + size - 1 <= self.range.end
+            && (position..position + size).all(|unit| !self.occupied.contains(&unit))
+    }
+
+    /// 占用从指定位置开始的size个单元
+    fn occupy(&mut self, position: u32, size: u32) {
+        self.occupied.extend(position..position + size);
+    }
+}
+
+/// PLC地址分配器
+///
+/// 按地址规划依次分配REAL/BOOL地址，主地址区用尽后转入备用地址区，
+/// 全部用尽时返回错误，不会越过配置的地址范围
+pub struct AddressAllocator<'p> {
+    plan: &'p AddressPlan,
+    /// REAL地址区（主区、备用区）
+    real_areas: Vec<AreaCursor>,
+    /// BOOL地址区（主区、备用区）
+    bool_areas: Vec<AreaCursor>,
+}
+
+impl<'p> AddressAllocator<'p> {
+    /// 按地址规划创建分配器，规划无效时返回错误
+    pub fn new(plan: &'p AddressPlan) -> Result<Self, IoError> {
+        plan.validate().map_err(IoError::AddressPlan)?;
+
+        let real_cursor = |name, range: AddressRange| {
+            let label = format!("{}-{}", plan.format_real(range.start), plan.format_real(range.end));
+            AreaCursor::new(name, label, range, plan.align_real(range.start))
+        };
+        let mut real_areas = vec![real_cursor("REAL主地址区", plan.real_range())];
+        if let Some(range) = plan.real_secondary {
+            real_areas.push(real_cursor("REAL备用地址区", range));
+        }
+
+        // BOOL地址区换算为位序号
+        let bool_cursor = |name, range: AddressRange, start_bit: u32| {
+            let label = format!(
+                "{}-{}",
+                plan.format_bool((range.start, start_bit)),
+                plan.format_bool((range.end, BOOL_BITS_PER_BYTE - 1))
+            );
+            let bits = AddressRange {
+                start: range.start * BOOL_BITS_PER_BYTE,
+                end: range.end * BOOL_BITS_PER_BYTE + BOOL_BITS_PER_BYTE - 1,
+            };
+            AreaCursor::new(name, label, bits, bits.start + start_bit)
+        };
+        let mut bool_areas = vec![bool_cursor("BOOL主地址区", plan.bool_range(), plan.bool_start_bit)];
+        if let Some(range) = plan.bool_secondary {
+            bool_areas.push(bool_cursor("BOOL备用地址区", range, 0));
+        }
+
+        Ok(Self { plan, real_areas, bool_areas })
+    }
+
+    /// 分配一个PLC地址
+    pub fn allocate(&mut self, data_type: DataType) -> Result<String, IoError> {
+        if data_type.is_bit() {
+            let bit = Self::take(&mut self.bool_areas, 1, 1, &self.plan.bool_area)?;
+            return Ok(self.plan.format_bool((bit / BOOL_BITS_PER_BYTE, bit % BOOL_BITS_PER_BYTE)));
+        }
+
+        // 寄存器类数据按字节数和对齐要求分配，地址前缀按数据宽度选择
+        let size = IOExcelService::byte_size(self.plan, data_type);
+        let alignment = self.plan.alignment_for(size);
+        let width = data_type.byte_size();
+        let offset = Self::take(&mut self.real_areas, size, alignment, self.plan.register_area(width))?;
+        Ok(self.plan.format_register(offset, width))
+    }
+
+    /// 占用指定的PLC地址（用于沿用锁定的地址）
+    ///
+    /// 地址无法解析、不在地址区范围内或已被占用时返回false
+    pub fn reserve(&mut self, data_type: DataType, plc_address: &str) -> bool {
+        if data_type.is_bit() {
+            return match self.plan.parse_bool(plc_address) {
+                Ok((byte, bit)) => Self::reserve_units(&mut self.bool_areas, byte * BOOL_BITS_PER_BYTE + bit, 1),
+                Err(_) => false,
+            };
+        }
+
+        match self.plan.parse_register(plc_address, data_type.byte_size()) {
+            Ok(offset) => {
+                let size = IOExcelService::byte_size(self.plan, data_type);
+                Self::reserve_units(&mut self.real_areas, offset, size)
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// 占用地址区中从指定位置开始的size个单元
+    fn reserve_units(areas: &mut [AreaCursor], position: u32, size: u32) -> bool {
+        match areas.iter_mut().find(|cursor| cursor.is_free(position, size)) {
+            Some(cursor) => {
+                cursor.occupy(position, size);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 将PLC地址及其通讯地址写入点表行，主地址同时写入Modbus功能区、偏移、寄存器数和字序
+    ///
+    /// 点表行中的Modbus列只描述主点位，附加点位的映射列在Modbus映射表中；
+    /// 打包到状态字中的报警位，通讯地址为"寄存器.位"
+    fn set_row_address(
+        &self,
+        row: &mut IOTableRow<'_>,
+        field: &'static str,
+        plc_address: String,
+        data_type: DataType
+    ) -> Result<(), IoError> {
+        let comm_address = if self.is_packed_field(field, data_type) {
+            let bool_addr = self.plan.parse_bool(&plc_address).map_err(IoError::AddrParse)?;
+            self.plan.packed_bool_comm_address(bool_addr)
+        } else if field == MAIN_PLC_ADDRESS_FIELD {
+            let point = IOExcelService::modbus_point(self.plan, &plc_address, data_type)?;
+            row.modbus_register_area = Some(Cow::Owned(point.area.to_string()));
+            row.modbus_offset = Some(Cow::Owned(point.offset.to_string()));
+            row.modbus_span = Some(Cow::Owned(point.span.to_string()));
+            row.modbus_word_order = Some(match point.word_order {
+                Some(word_order) => Cow::Owned(word_order.to_string()),
+                None => Cow::Borrowed(NOT_APPLICABLE),
+            });
+            point.address.to_string()
+        } else {
+            IOExcelService::calculate_modbus_address(self.plan, &plc_address, data_type)?.to_string()
+        };
+
+        row.set_field_by_name(field, Cow::Owned(plc_address));
+        row.set_field_by_name(&comm_address_field(field), Cow::Owned(comm_address));
+        Ok(())
+    }
+
+    /// 附加点位中的BOOL地址在打包模式下放入状态字
+    fn is_packed_field(&self, field: &str, field_type: DataType) -> bool {
+        self.plan.modbus.pack_alarm_bits && field != MAIN_PLC_ADDRESS_FIELD && field_type == DataType::BOOL
+    }
+
+    /// 为点表行沿用上次锁定的地址
+    ///
+    /// 只沿用仍然有效且未被占用的地址，其余地址留给assign_row_addresses重新分配
+    pub fn restore_row_addresses(
+        &mut self,
+        row: &mut IOTableRow<'_>,
+        data_type: DataType,
+        with_sub_points: bool,
+        locked: &BTreeMap<String, String>
+    ) -> Result<(), IoError> {
+        let mut packed_fields = Vec::new();
+        for field in Self::row_fields(with_sub_points) {
+            let Some(plc_address) = locked.get(field) else {
+                continue;
+            };
+            let field_type = Self::field_data_type(field, data_type);
+            if self.is_packed_field(field, field_type) {
+                packed_fields.push((field, plc_address));
+            } else if self.reserve(field_type, plc_address) {
+                self.set_row_address(row, field, plc_address.clone(), field_type)?;
+            }
+        }
+
+        // 状态字中的报警位整体沿用：全部位于同一个空闲的状态字中时才沿用
+        if let Some(status_word) = self.locked_status_word(&packed_fields) {
+            if Self::reserve_units(&mut self.bool_areas, status_word, STATUS_WORD_BITS) {
+                for (field, plc_address) in packed_fields {
+                    self.set_row_address(row, field, plc_address.clone(), DataType::BOOL)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 锁定的报警位所在的状态字起始位序号，报警位不完整或不在同一状态字中时返回None
+    fn locked_status_word(&self, packed_fields: &[(&str, &String)]) -> Option<u32> {
+        let packed_count = PLC_ADDRESS_FIELDS
+            .iter()
+            .filter(|field| self.is_packed_field(field, Self::field_data_type(field, DataType::REAL)))
+            .count();
+        if packed_fields.len() != packed_count {
+            return None;
+        }
+
+        let mut words = packed_fields.iter().map(|(_, plc_address)| {
+            let bool_addr = self.plan.parse_bool(plc_address).ok()?;
+            let (word_byte, _) = self.plan.status_word_position(bool_addr);
+            Some(word_byte * BOOL_BITS_PER_BYTE)
+        });
+        let first = words.next()??;
+        words.all(|word| word == Some(first)).then_some(first)
+    }
+
+    /// 为点表行分配全部地址并写入行数据
+    ///
+    /// 按表头从左到右的顺序分配：先为模拟量的附加点位（设定点位、报警、维护）分配地址，
+    /// 最后分配主PLC绝对地址；已沿用锁定地址的列保持不变，不带附加点位的行，其附加点位地址填"/"
+    pub fn assign_row_addresses(
+        &mut self,
+        row: &mut IOTableRow<'_>,
+        data_type: DataType,
+        with_sub_points: bool
+    ) -> Result<(), IoError> {
+        // 打包模式下该点位的状态字起始位序号及已用位数，首次需要时分配
+        let mut status_word: Option<(u32, u32)> = None;
+
+        for field in PLC_ADDRESS_FIELDS {
+            if field != MAIN_PLC_ADDRESS_FIELD && !with_sub_points {
+                row.set_field_by_name(field, Cow::Borrowed(NOT_APPLICABLE));
+                row.set_field_by_name(&comm_address_field(field), Cow::Borrowed(NOT_APPLICABLE));
+                continue;
+            }
+            if row.get_field_by_name(field).is_some() {
+                continue;
+            }
+
+            let field_type = Self::field_data_type(field, data_type);
+            let plc_address = if self.is_packed_field(field, field_type) {
+                let (word, used_bits) = match status_word {
+                    Some(word) => word,
+                    None => (self.allocate_status_word()?, 0),
+                };
+                status_word = Some((word, used_bits + 1));
+                let bit = word + used_bits;
+                self.plan.format_bool((bit / BOOL_BITS_PER_BYTE, bit % BOOL_BITS_PER_BYTE))
+            } else {
+                self.allocate(field_type)?
+            };
+            self.set_row_address(row, field, plc_address, field_type)?;
+        }
+
+        Ok(())
+    }
+
+    /// 在BOOL地址区中分配一个按字对齐的16位状态字，返回起始位序号
+    ///
+    /// 不移动单个BOOL地址的分配游标，状态字之前的空闲位仍可分配给其他点位
+    fn allocate_status_word(&mut self) -> Result<u32, IoError> {
+        for cursor in self.bool_areas.iter_mut() {
+            let mut position = cursor.next.div_ceil(STATUS_WORD_BITS) * STATUS_WORD_BITS;
+            while position + STATUS_WORD_BITS - 1 <= cursor.range.end {
+                if cursor.is_free(position, STATUS_WORD_BITS) {
+                    cursor.occupy(position, STATUS_WORD_BITS);
+                    return Ok(position);
+                }
+                position += STATUS_WORD_BITS;
+            }
+        }
+        Err(Self::exhausted(&self.bool_areas, &self.plan.bool_area))
+    }
+
+    /// 行需要分配的PLC地址列
+    fn row_fields(with_sub_points: bool) -> impl Iterator<Item = &'static str> {
+        PLC_ADDRESS_FIELDS
+            .into_iter()
+            .filter(move |field| with_sub_points || *field == MAIN_PLC_ADDRESS_FIELD)
+    }
+
+    /// 地址列的数据类型：主地址跟随行的数据类型，附加点位中报警和维护使能为BOOL，其余为REAL
+    pub(crate) fn field_data_type(field: &str, data_type: DataType) -> DataType {
+        if field == MAIN_PLC_ADDRESS_FIELD {
+            data_type
+        } else if IOExcelService::is_bool_address_field(field) {
+            DataType::BOOL
+        } else {
+            DataType::REAL
+        }
+    }
+
+    /// 各地址区的使用情况，未使用的备用地址区也会列出
+    pub fn utilization(&self) -> Vec<AreaUtilization> {
+        self.real_areas
+            .iter()
+            .chain(&self.bool_areas)
+            .map(Self::area_utilization)
+            .collect()
+    }
+
+    /// 从地址区依次取出下一个位置，当前区放不下时转入下一个区
+    fn take(areas: &mut [AreaCursor], size: u32, alignment: u32, prefix: &str) -> Result<u32, IoError> {
+        for cursor in areas.iter_mut() {
+            // 从游标处按对齐要求查找，跳过已被锁定地址占用的位置
+            let mut position = cursor.next.div_ceil(alignment) * alignment;
+            while position + size - 1 <= cursor.range.end {
+                if cursor.is_free(position, size) {
+                    cursor.occupy(position, size);
+                    cursor.next = position + size;
+                    return Ok(position);
+                }
+                position += alignment;
+            }
+        }
+
+        Err(Self::exhausted(areas, prefix))
+    }
+
+    /// 地址区用尽错误
+    fn exhausted(areas: &[AreaCursor], prefix: &str) -> IoError {
+        let ranges = areas
+            .iter()
+            .map(|cursor| format!("{}({})", cursor.name, cursor.label))
+            .collect::<Vec<_>>()
+            .join("、");
+        IoError::AddressExhausted { area: prefix.to_string(), ranges }
+    }
+
+    /// 地址区使用情况，按单元（REAL地址区为字节，BOOL地址区为位）统计
+    fn area_utilization(cursor: &AreaCursor) -> AreaUtilization {
+        let capacity = (cursor.range.end + 1).saturating_sub(cursor.first);
+        let used = cursor.occupied.len() as u32;
+        let utilization = if capacity == 0 {
+            0.0
+        } else {
+            used as f64 / capacity as f64 * 100.0
+        };
+        AreaUtilization {
+            area: cursor.name.to_string(),
+            range: cursor.label.clone(),
+            used,
+            capacity,
+            utilization,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_follows_plan() {
+        let plan = AddressPlan {
+            real_start: 322,
+            real_stride: 4,
+            real_alignment: 4,
+            bool_start_bit: 7,
+            ..Default::default()
+        };
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        // 起始地址按4字节对齐
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD324");
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD328");
+        assert_eq!(allocator.allocate(DataType::BOOL).unwrap(), "%MX20.7");
+        assert_eq!(allocator.allocate(DataType::BOOL).unwrap(), "%MX21.0");
+    }
+
+    #[test]
+    fn test_allocate_other_data_types_with_alignment() {
+        let plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        // 地址前缀按数据宽度选择，字节偏移在同一地址区中连续分配
+        assert_eq!(allocator.allocate(DataType::INT).unwrap(), "%MW320");
+        // DINT按4字节对齐，跳过%MW322
+        assert_eq!(allocator.allocate(DataType::DINT).unwrap(), "%MD324");
+        assert_eq!(allocator.allocate(DataType::LREAL).unwrap(), "%ML328");
+        assert_eq!(allocator.allocate(DataType::WORD).unwrap(), "%MW336");
+        assert_eq!(allocator.allocate(DataType::STRING).unwrap(), "%MB340");
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD372");
+
+        let point = IOExcelService::modbus_point(&plan, "%MW320", DataType::INT).unwrap();
+        assert_eq!(point.span, 1);
+        let point = IOExcelService::modbus_point(&plan, "%ML328", DataType::LREAL).unwrap();
+        assert_eq!(point.span, 4);
+        assert!(point.word_order.is_some());
+        let point = IOExcelService::modbus_point(&plan, "%MB340", DataType::STRING).unwrap();
+        assert_eq!(point.span, 16);
+        assert!(point.word_order.is_none());
+
+        // 宽度与前缀不符的地址不能解析
+        assert!(IOExcelService::modbus_point(&plan, "%MD320", DataType::INT).is_err());
+    }
+
+    #[test]
+    fn test_reserve_requires_width_prefix() {
+        let plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        // 旧点表中用%MD表示的INT地址不再沿用
+        assert!(!allocator.reserve(DataType::INT, "%MD320"));
+        assert!(allocator.reserve(DataType::INT, "%MW320"));
+        assert!(allocator.reserve(DataType::LREAL, "%ML328"));
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD324");
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD336");
+    }
+
+    #[test]
+    fn test_spill_to_secondary_then_exhausted() {
+        let plan = AddressPlan {
+            real_start: 320,
+            real_end: 327,
+            real_secondary: Some(AddressRange { start: 2000, end: 2003 }),
+            ..Default::default()
+        };
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD320");
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD324");
+        assert_eq!(allocator.allocate(DataType::REAL).unwrap(), "%MD2000");
+        assert!(matches!(
+            allocator.allocate(DataType::REAL),
+            Err(IoError::AddressExhausted { .. })
+        ));
+
+        // 使用情况按字节统计
+        let utilization = allocator.utilization();
+        assert_eq!(utilization[0].capacity, 8);
+        assert_eq!(utilization[0].used, 8);
+        assert_eq!(utilization[1].used, 4);
+        // 默认BOOL主区为20-300字节
+        assert_eq!(utilization[2].capacity, 281 * 8);
+        assert_eq!(utilization[2].range, "%MX20.0-%MX300.7");
+    }
+
+    #[test]
+    fn test_assign_row_addresses_in_column_order() {
+        let plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        let mut analog = IOTableRow::default();
+        allocator.assign_row_addresses(&mut analog, DataType::REAL, true).unwrap();
+        assert_eq!(analog.sll_setpoint_plc_address.as_deref(), Some("%MD320"));
+        assert_eq!(analog.shh_setpoint_plc_address.as_deref(), Some("%MD332"));
+        assert_eq!(analog.ll_alarm_plc_address.as_deref(), Some("%MX20.0"));
+        assert_eq!(analog.ll_alarm_comm_address.as_deref(), Some("3161"));
+        assert_eq!(analog.maintenance_setpoint_plc_address.as_deref(), Some("%MD336"));
+        assert_eq!(analog.maintenance_enable_switch_plc_address.as_deref(), Some("%MX20.4"));
+        // 主地址最后分配
+        assert_eq!(analog.plc_absolute_address.as_deref(), Some("%MD340"));
+        assert_eq!(analog.modbus_register_area.as_deref(), Some("保持寄存器"));
+        assert_eq!(analog.modbus_offset.as_deref(), Some("3170"));
+        assert_eq!(analog.modbus_word_order.as_deref(), Some("ABCD"));
+
+        let mut digital = IOTableRow::default();
+        allocator.assign_row_addresses(&mut digital, DataType::BOOL, false).unwrap();
+        assert_eq!(digital.sll_setpoint_plc_address.as_deref(), Some(NOT_APPLICABLE));
+        assert_eq!(digital.hh_alarm_comm_address.as_deref(), Some(NOT_APPLICABLE));
+        assert_eq!(digital.plc_absolute_address.as_deref(), Some("%MX20.5"));
+    }
+
+    #[test]
+    fn test_restored_addresses_are_kept_and_skipped() {
+        let plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        let locked = BTreeMap::from([(MAIN_PLC_ADDRESS_FIELD.to_string(), "%MX20.1".to_string())]);
+        let mut locked_row = IOTableRow::default();
+        allocator.restore_row_addresses(&mut locked_row, DataType::BOOL, false, &locked).unwrap();
+        allocator.assign_row_addresses(&mut locked_row, DataType::BOOL, false).unwrap();
+        assert_eq!(locked_row.plc_absolute_address.as_deref(), Some("%MX20.1"));
+        assert_eq!(locked_row.host_comm_address.as_deref(), Some("3162"));
+
+        // 新点位从空闲地址分配，跳过已锁定的地址
+        let mut rows = [IOTableRow::default(), IOTableRow::default()];
+        for row in rows.iter_mut() {
+            allocator.assign_row_addresses(row, DataType::BOOL, false).unwrap();
+        }
+        assert_eq!(rows[0].plc_absolute_address.as_deref(), Some("%MX20.0"));
+        assert_eq!(rows[1].plc_absolute_address.as_deref(), Some("%MX20.2"));
+
+        // 重复或越界的地址不能再次占用
+        assert!(!allocator.reserve(DataType::BOOL, "%MX20.1"));
+        assert!(!allocator.reserve(DataType::BOOL, "%MX301.0"));
+    }
+
+    #[test]
+    fn test_pack_alarm_bits_into_status_word() {
+        let mut plan = AddressPlan::default();
+        plan.modbus.pack_alarm_bits = true;
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        let mut first = IOTableRow::default();
+        allocator.assign_row_addresses(&mut first, DataType::BOOL, false).unwrap();
+        assert_eq!(first.plc_absolute_address.as_deref(), Some("%MX20.0"));
+
+        // 状态字按字对齐，跳过已部分占用的%MX20-%MX21
+        let mut analog = IOTableRow::default();
+        allocator.assign_row_addresses(&mut analog, DataType::REAL, true).unwrap();
+        assert_eq!(analog.ll_alarm_plc_address.as_deref(), Some("%MX22.0"));
+        assert_eq!(analog.ll_alarm_comm_address.as_deref(), Some("43012.0"));
+        assert_eq!(analog.maintenance_enable_switch_plc_address.as_deref(), Some("%MX22.4"));
+        assert_eq!(analog.maintenance_enable_switch_comm_address.as_deref(), Some("43012.4"));
+
+        // 单个BOOL点位继续使用状态字之前的空闲位
+        let mut second = IOTableRow::default();
+        allocator.assign_row_addresses(&mut second, DataType::BOOL, false).unwrap();
+        assert_eq!(second.plc_absolute_address.as_deref(), Some("%MX20.1"));
+    }
+
+    #[test]
+    fn test_overlapping_areas_are_rejected() {
+        let plan = AddressPlan {
+            bool_end_byte: 400,
+            ..Default::default()
+        };
+        assert!(matches!(AddressAllocator::new(&plan), Err(IoError::AddressPlan(_))));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::application_services::storage_services::json_store::{JsonStore, StoreError};
+use crate::model_domain::address_lock_model::AddressLock;
+
+/// 地址锁定的存储类别
+const ADDRESS_LOCK_CATEGORY: &str = "address_locks";
+
+/// 地址锁定服务，按场站保存和读取上次生成的地址分配
+pub struct AddressLockService;
+
+impl AddressLockService {
+    /// 读取场站的地址锁定，从未生成过时返回None
+    pub fn load(store: &JsonStore, station_name: &str) -> Result<Option<AddressLock>, StoreError> {
+        store.load(ADDRESS_LOCK_CATEGORY, station_name)
+    }
+
+    /// 保存场站的地址锁定
+    pub fn save(store: &JsonStore, station_name: &str, lock: &AddressLock) -> Result<(), StoreError> {
+        store.save(ADDRESS_LOCK_CATEGORY, station_name, lock)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::application_services::storage_services::json_store::{JsonStore, StoreError};
+use crate::model_domain::address_plan_model::AddressPlan;
+
+/// 地址规划的存储类别
+const ADDRESS_PLAN_CATEGORY: &str = "address_plans";
+
+/// 地址规划服务，按项目编号保存和读取地址规划
+pub struct AddressPlanService;
+
+impl AddressPlanService {
+    /// 读取项目的地址规划，未保存过时返回默认规划
+    pub fn load(store: &JsonStore, project_number: &str) -> Result<AddressPlan, StoreError> {
+        Ok(store
+            .load(ADDRESS_PLAN_CATEGORY, project_number)?
+            .unwrap_or_default())
+    }
+
+    /// 保存项目的地址规划
+    pub fn save(store: &JsonStore, project_number: &str, plan: &AddressPlan) -> Result<(), StoreError> {
+        store.save(ADDRESS_PLAN_CATEGORY, project_number, plan)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::application_services::excel_services::io_excel_services::{DataType, IOExcelService, IoError};
+use crate::model_domain::dnp3_profile_model::{Dnp3Point, Dnp3PointType, Dnp3Profile};
+use crate::model_domain::io_table_model::{point_name, IOTableRow, MAIN_PLC_ADDRESS_FIELD};
+use std::collections::HashMap;
+
+/// 维护使能开关是可遥控的开关量
+const MAINTENANCE_SWITCH_FIELD: &str = "维护使能开关点位_PLC地址";
+
+/// DNP3索引分配器
+///
+/// 按点表行顺序为点位及报警、设定值附加点位分配DNP3索引，每种点类型从0开始独立编号，
+/// 事件等级和死区按配置确定
+pub struct Dnp3Allocator<'p> {
+    profile: &'p Dnp3Profile,
+    /// 各点类型下一个可用的索引
+    next: HashMap<Dnp3PointType, u32>,
+}
+
+impl<'p> Dnp3Allocator<'p> {
+    /// 按DNP3配置创建分配器，配置无效时返回错误
+    pub fn new(profile: &'p Dnp3Profile) -> Result<Self, IoError> {
+        profile.validate().map_err(IoError::AddressPlan)?;
+        Ok(Self { profile, next: HashMap::new() })
+    }
+
+    /// 为全部点表行分配DNP3点位，结果按点类型和索引排序
+    pub fn allocate_rows(&mut self, rows: &[IOTableRow<'_>]) -> Result<Vec<Dnp3Point>, IoError> {
+        let mut points = Vec::new();
+        for row in rows {
+            let variable_name = row.variable_name_hmi.as_deref().unwrap_or_default();
+            let channel_tag = row.channel_tag.as_deref().unwrap_or_default();
+            let range = row.range_lower_limit.as_deref().and_then(parse_number)
+                .zip(row.range_upper_limit.as_deref().and_then(parse_number));
+
+            for (field, plc_address) in row.plc_address_fields() {
+                // 未分配或不适用的点位不上送
+                let Some(plc_address) = plc_address.filter(|address| *address != "/") else {
+                    continue;
+                };
+
+                let is_main = field == MAIN_PLC_ADDRESS_FIELD;
+                let point_type = if is_main { Self::main_type(row) } else { Self::sub_point_type(field) };
+                let point_name = point_name(field);
+
+                // 主点位按变量名称或通道位号查找覆盖设置，附加点位在名称后加".点位名称"
+                let override_key = |key: &str| if is_main { key.to_string() } else { format!("{}.{}", key, point_name) };
+                let point_override = [variable_name, channel_tag]
+                    .into_iter()
+                    .filter(|key| !key.is_empty())
+                    .find_map(|key| self.profile.overrides.get(&override_key(key)));
+
+                let event_class = point_override
+                    .and_then(|point_override| point_override.event_class)
+                    .unwrap_or_else(|| self.profile.class_for(point_type));
+                let deadband = (point_type == Dnp3PointType::AnalogInput).then(|| {
+                    point_override
+                        .and_then(|point_override| point_override.deadband)
+                        .unwrap_or_else(|| self.profile.deadband_for(range))
+                });
+
+                points.push(Dnp3Point {
+                    point_type,
+                    index: self.allocate(point_type)?,
+                    event_class,
+                    deadband,
+                    channel_tag: channel_tag.to_string(),
+                    variable_name: variable_name.to_string(),
+                    description: row.variable_description.as_deref().unwrap_or_default().to_string(),
+                    point_name: point_name.to_string(),
+                    plc_address: plc_address.to_string(),
+                });
+            }
+        }
+
+        points.sort_by_key(|point| (point.point_type, point.index));
+        Ok(points)
+    }
+
+    /// 分配指定点类型的下一个索引
+    fn allocate(&mut self, point_type: Dnp3PointType) -> Result<u16, IoError> {
+        let next = self.next.entry(point_type).or_insert(0);
+        let index = u16::try_from(*next).map_err(|_| IoError::AddressExhausted {
+            area: format!("DNP3 {}", point_type),
+            ranges: format!("0-{}", u16::MAX),
+        })?;
+        *next += 1;
+        Ok(index)
+    }
+
+    /// 主点位的点类型：按模块类型区分输入输出，软点位按数据类型区分
+    fn main_type(row: &IOTableRow<'_>) -> Dnp3PointType {
+        match row.module_type.as_deref() {
+            Some("AI") => Dnp3PointType::AnalogInput,
+            Some("AO") => Dnp3PointType::AnalogOutput,
+            Some("DI") => Dnp3PointType::BinaryInput,
+            Some("DO") => Dnp3PointType::BinaryOutput,
+            _ => {
+                let data_type = row.data_type.as_deref().and_then(DataType::from_name);
+                if data_type.is_some_and(|data_type| data_type.is_bit()) {
+                    Dnp3PointType::BinaryInput
+                } else {
+                    Dnp3PointType::AnalogInput
+                }
+            }
+        }
+    }
+
+    /// 附加点位的点类型：报警为二进制输入，维护使能开关为二进制输出，设定值为模拟量输出
+    fn sub_point_type(field: &str) -> Dnp3PointType {
+        if field == MAINTENANCE_SWITCH_FIELD {
+            Dnp3PointType::BinaryOutput
+        } else if IOExcelService::is_bool_address_field(field) {
+            Dnp3PointType::BinaryInput
+        } else {
+            Dnp3PointType::AnalogOutput
+        }
+    }
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::address_services::address_allocator::AddressAllocator;
+    use crate::model_domain::address_plan_model::AddressPlan;
+    use crate::model_domain::dnp3_profile_model::{Dnp3PointOverride, EventClass};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_allocate_indices_classes_and_deadbands() {
+        let plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+        let rows: Vec<_> = [("AI", DataType::REAL, true), ("AI", DataType::REAL, true), ("DO", DataType::BOOL, false)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (module_type, data_type, with_sub_points))| {
+                let mut row = IOTableRow {
+                    module_type: Some(Cow::Borrowed(module_type)),
+                    variable_name_hmi: Some(Cow::Owned(format!("PT10{}", i))),
+                    range_lower_limit: Some(Cow::Borrowed("0")),
+                    range_upper_limit: Some(Cow::Borrowed("50")),
+                    ..Default::default()
+                };
+                allocator.assign_row_addresses(&mut row, data_type, with_sub_points).unwrap();
+                row
+            })
+            .collect();
+
+        let mut profile = Dnp3Profile::default();
+        profile.overrides.insert(
+            "PT101".to_string(),
+            Dnp3PointOverride { event_class: Some(EventClass::Class1), deadband: Some(0.1) },
+        );
+        profile.overrides.insert(
+            "PT100.HH报警".to_string(),
+            Dnp3PointOverride { event_class: Some(EventClass::Class3), deadband: None },
+        );
+        let points = Dnp3Allocator::new(&profile).unwrap().allocate_rows(&rows).unwrap();
+
+        let of_type = |point_type| points.iter().filter(|p| p.point_type == point_type).collect::<Vec<_>>();
+        // 每个AI：报警为二进制输入，主点位为模拟量输入，设定值和维护值为模拟量输出，维护使能开关与DO为二进制输出
+        assert_eq!(of_type(Dnp3PointType::BinaryInput).len(), 8);
+        assert_eq!(of_type(Dnp3PointType::AnalogInput).len(), 2);
+        assert_eq!(of_type(Dnp3PointType::BinaryOutput).len(), 3);
+        assert_eq!(of_type(Dnp3PointType::AnalogOutput).len(), 10);
+
+        let analog_inputs = of_type(Dnp3PointType::AnalogInput);
+        assert_eq!(analog_inputs[0].index, 0);
+        assert_eq!(analog_inputs[0].event_class, EventClass::Class2);
+        assert_eq!(analog_inputs[0].deadband, Some(0.5));
+        assert_eq!(analog_inputs[1].event_class, EventClass::Class1);
+        assert_eq!(analog_inputs[1].deadband, Some(0.1));
+
+        let hh = points.iter().find(|p| p.variable_name == "PT100" && p.point_name == "HH报警").unwrap();
+        assert_eq!(hh.event_class, EventClass::Class3);
+        assert_eq!(hh.deadband, None);
+    }
+}
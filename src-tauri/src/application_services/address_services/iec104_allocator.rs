@@ -0,0 +1,152 @@
+use crate::application_services::excel_services::io_excel_services::{DataType, IOExcelService, IoError};
+use crate::model_domain::address_plan_model::AddressRange;
+use crate::model_domain::iec104_profile_model::{Iec104Point, Iec104Profile, Iec104TypeId};
+use crate::model_domain::io_table_model::{point_name, IOTableRow, MAIN_PLC_ADDRESS_FIELD};
+use std::collections::HashMap;
+
+/// IEC 104信息体地址分配器
+///
+/// 按点表行顺序为每个已分配PLC地址的点位及附加点位（报警、设定值）分配IOA，
+/// 各类型信息对象在各自的IOA范围内依次编号
+pub struct Iec104Allocator {
+    /// 各类型的IOA范围及下一个可用的IOA
+    cursors: HashMap<Iec104TypeId, (AddressRange, u32)>,
+}
+
+impl Iec104Allocator {
+    /// 按IOA配置创建分配器，配置无效时返回错误
+    pub fn new(profile: &Iec104Profile) -> Result<Self, IoError> {
+        profile.validate().map_err(IoError::AddressPlan)?;
+        let cursors = profile
+            .ranges()
+            .into_iter()
+            .map(|(type_id, range)| (type_id, (range, range.start)))
+            .collect();
+        Ok(Self { cursors })
+    }
+
+    /// 为全部点表行分配IOA
+    pub fn allocate_rows(&mut self, rows: &[IOTableRow<'_>]) -> Result<Vec<Iec104Point>, IoError> {
+        let mut points = Vec::new();
+        for row in rows {
+            for (field, plc_address) in row.plc_address_fields() {
+                // 未分配或不适用的点位不上送
+                let Some(plc_address) = plc_address.filter(|address| *address != "/") else {
+                    continue;
+                };
+
+                let type_id = if field == MAIN_PLC_ADDRESS_FIELD {
+                    Self::main_type(row)
+                } else {
+                    Self::sub_point_type(field)
+                };
+
+                points.push(Iec104Point {
+                    ioa: self.allocate(type_id)?,
+                    type_id,
+                    channel_tag: row.channel_tag.as_deref().unwrap_or_default().to_string(),
+                    variable_name: row.variable_name_hmi.as_deref().unwrap_or_default().to_string(),
+                    description: row.variable_description.as_deref().unwrap_or_default().to_string(),
+                    point_name: point_name(field).to_string(),
+                    plc_address: plc_address.to_string(),
+                });
+            }
+        }
+        Ok(points)
+    }
+
+    /// 分配指定类型的下一个IOA
+    fn allocate(&mut self, type_id: Iec104TypeId) -> Result<u32, IoError> {
+        let (range, next) = self
+            .cursors
+            .get_mut(&type_id)
+            .ok_or_else(|| IoError::AddressPlan(format!("未配置{}的IOA范围", type_id)))?;
+        if *next > range.end {
+            return Err(IoError::AddressExhausted {
+                area: format!("IEC 104 {}", type_id),
+                ranges: format!("{}-{}", range.start, range.end),
+            });
+        }
+
+        let ioa = *next;
+        *next += 1;
+        Ok(ioa)
+    }
+
+    /// 主点位的信息对象类型：模拟量输入为测量值，模拟量输出为设定值命令，开关量为单点信息，
+    /// 软点位按数据类型区分
+    fn main_type(row: &IOTableRow<'_>) -> Iec104TypeId {
+        match row.module_type.as_deref() {
+            Some("AI") => Iec104TypeId::MMe,
+            Some("AO") => Iec104TypeId::CSe,
+            Some("DI") | Some("DO") => Iec104TypeId::MSp,
+            _ => {
+                let data_type = row.data_type.as_deref().and_then(DataType::from_name);
+                if data_type.is_some_and(|data_type| data_type.is_bit()) {
+                    Iec104TypeId::MSp
+                } else {
+                    Iec104TypeId::MMe
+                }
+            }
+        }
+    }
+
+    /// 附加点位的信息对象类型：报警和维护使能为单点信息，设定值为设定值命令
+    fn sub_point_type(field: &str) -> Iec104TypeId {
+        if IOExcelService::is_bool_address_field(field) {
+            Iec104TypeId::MSp
+        } else {
+            Iec104TypeId::CSe
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::address_services::address_allocator::AddressAllocator;
+    use crate::model_domain::address_plan_model::AddressPlan;
+    use crate::model_domain::io_table_model::MAIN_POINT_NAME;
+    use std::borrow::Cow;
+
+    fn rows(plan: &AddressPlan) -> Vec<IOTableRow<'static>> {
+        let mut allocator = AddressAllocator::new(plan).unwrap();
+        [("AI", DataType::REAL, true), ("DI", DataType::BOOL, false), ("DI", DataType::BOOL, false)]
+            .into_iter()
+            .map(|(module_type, data_type, with_sub_points)| {
+                let mut row = IOTableRow {
+                    module_type: Some(Cow::Borrowed(module_type)),
+                    ..Default::default()
+                };
+                allocator.assign_row_addresses(&mut row, data_type, with_sub_points).unwrap();
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_allocate_per_point_and_sub_point() {
+        let plan = AddressPlan::default();
+        let profile = Iec104Profile::default();
+        let points = Iec104Allocator::new(&profile).unwrap().allocate_rows(&rows(&plan)).unwrap();
+
+        // AI：4个设定值 + 4个报警 + 维护值设定 + 维护使能 + 主点位；DI：各1个主点位
+        assert_eq!(points.len(), 13);
+        let of_type = |type_id| points.iter().filter(|p| p.type_id == type_id).map(|p| p.ioa).collect::<Vec<_>>();
+        assert_eq!(of_type(Iec104TypeId::MMe), vec![0x4001]);
+        assert_eq!(of_type(Iec104TypeId::CSe), (0x6201..0x6206).collect::<Vec<_>>());
+        assert_eq!(of_type(Iec104TypeId::MSp), (1..8).collect::<Vec<_>>());
+        assert_eq!(points[0].point_name, "SLL设定点位");
+        assert_eq!(points.last().unwrap().point_name, MAIN_POINT_NAME);
+    }
+
+    #[test]
+    fn test_exhausted_range() {
+        let profile = Iec104Profile {
+            single_point: AddressRange { start: 1, end: 6 },
+            ..Default::default()
+        };
+        let result = Iec104Allocator::new(&profile).unwrap().allocate_rows(&rows(&AddressPlan::default()));
+        assert!(matches!(result, Err(IoError::AddressExhausted { .. })));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod address_plan_service;
+pub mod address_allocator;
+pub mod address_lock_service;
+pub mod iec104_allocator;
+pub mod dnp3_allocator;
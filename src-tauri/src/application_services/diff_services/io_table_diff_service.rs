@@ -0,0 +1,318 @@
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::model_domain::io_diff_model::{DiffMatchKey, DiffStatus, DiffSummary, FieldChange, IoTableDiff, RowDiff};
+use crate::model_domain::io_import_model::SheetLayout;
+use crate::model_domain::io_table_model::{IOTable, IOTableRow, IO_TABLE_HEADERS};
+use std::collections::{BTreeSet, HashMap};
+
+/// 不参与对比的列（序号随行的增删变化）
+const IGNORED_COLUMNS: [&str; 1] = ["序号"];
+
+/// 参与对比的一版点表
+pub struct DiffSide<'t> {
+    pub table: &'t IOTable<'t>,
+    pub layout: SheetLayout,
+}
+
+impl<'t> DiffSide<'t> {
+    /// 按标准格式生成的点表（例如保存的快照）
+    pub fn standard(table: &'t IOTable<'t>) -> Self {
+        Self { table, layout: SheetLayout::standard(table.len()) }
+    }
+}
+
+/// 点表版本对比服务
+pub struct IoTableDiffService;
+
+impl IoTableDiffService {
+    /// 对比两版点表
+    ///
+    /// 先按主匹配依据配对，剩余的行再按另一依据配对；配对后通道位号不同的点位视为移动。
+    /// 按通道位号配对时，同一通道上位号已变化的行先按位号配对，
+    /// 移入其他点位原通道的点位因此识别为移动，而不是该通道的修改加上新增
+    pub fn diff(old: &DiffSide<'_>, new: &DiffSide<'_>, match_by: DiffMatchKey) -> IoTableDiff {
+        let (primary, secondary) = match match_by {
+            DiffMatchKey::ChannelTag => (channel_key as KeyFn, tag_key as KeyFn),
+            DiffMatchKey::Tag => (tag_key as KeyFn, channel_key as KeyFn),
+        };
+
+        let mut pairing = Pairing {
+            old_matched: vec![false; old.table.rows.len()],
+            pairs: vec![None; new.table.rows.len()],
+        };
+        if match_by == DiffMatchKey::ChannelTag {
+            let old_retagged = retagged_rows(&old.table.rows, &new.table.rows);
+            let new_retagged = retagged_rows(&new.table.rows, &old.table.rows);
+            pairing.pair_by(tag_key, old, new, &old_retagged, &new_retagged);
+        }
+        let all_old = vec![true; old.table.rows.len()];
+        let all_new = vec![true; new.table.rows.len()];
+        for key_fn in [primary, secondary] {
+            pairing.pair_by(key_fn, old, new, &all_old, &all_new);
+        }
+        let Pairing { old_matched, pairs } = pairing;
+
+        let mut summary = DiffSummary::default();
+        let mut rows = Vec::new();
+        for (new_index, old_index) in pairs.into_iter().enumerate() {
+            let row_diff = Self::row_diff(old, new, old_index, Some(new_index));
+            Self::count(&mut summary, &row_diff);
+            if row_diff.status != DiffStatus::Unchanged {
+                rows.push(row_diff);
+            }
+        }
+        for old_index in (0..old.table.rows.len()).filter(|index| !old_matched[*index]) {
+            let row_diff = Self::row_diff(old, new, Some(old_index), None);
+            Self::count(&mut summary, &row_diff);
+            rows.push(row_diff);
+        }
+
+        IoTableDiff {
+            old_table: old.table.table_name.clone(),
+            new_table: new.table.table_name.clone(),
+            match_by,
+            summary,
+            rows,
+        }
+    }
+
+    /// 对比配对的两行，只有一侧时为新增或删除
+    fn row_diff(old: &DiffSide<'_>, new: &DiffSide<'_>, old_index: Option<usize>, new_index: Option<usize>) -> RowDiff {
+        let old_row = old_index.map(|index| &old.table.rows[index]);
+        let new_row = new_index.map(|index| &new.table.rows[index]);
+
+        let changes = match (old_row, new_row) {
+            (Some(old_row), Some(new_row)) => Self::field_changes(old_row, new_row),
+            _ => Vec::new(),
+        };
+        let old_channel_tag = old_row.map(|row| cell_text(row, "通道位号")).unwrap_or_default();
+        let new_channel_tag = new_row.map(|row| cell_text(row, "通道位号")).unwrap_or_default();
+        let status = match (old_row, new_row) {
+            (None, _) => DiffStatus::Added,
+            (_, None) => DiffStatus::Removed,
+            _ if old_channel_tag != new_channel_tag => DiffStatus::Moved,
+            _ if !changes.is_empty() => DiffStatus::Changed,
+            _ => DiffStatus::Unchanged,
+        };
+
+        RowDiff {
+            status,
+            old_row: old_index.map(|index| old.layout.row_number(index)),
+            new_row: new_index.map(|index| new.layout.row_number(index)),
+            old_channel_tag,
+            new_channel_tag,
+            tag: new_row.or(old_row).map(|row| cell_text(row, "位号")).unwrap_or_default(),
+            changes,
+            old_index,
+            new_index,
+        }
+    }
+
+    /// 逐列对比标准列及两行的自定义列，空值与未填写视为相同
+    fn field_changes(old_row: &IOTableRow<'_>, new_row: &IOTableRow<'_>) -> Vec<FieldChange> {
+        let extra_columns: BTreeSet<&str> = old_row.extra_fields.keys()
+            .chain(new_row.extra_fields.keys())
+            .map(String::as_str)
+            .filter(|field| !IO_TABLE_HEADERS.contains(field))
+            .collect();
+        IO_TABLE_HEADERS
+            .iter()
+            .copied()
+            .chain(extra_columns)
+            .filter(|header| !IGNORED_COLUMNS.contains(header))
+            .filter_map(|header| {
+                let old_value = cell_text(old_row, header);
+                let new_value = cell_text(new_row, header);
+                (old_value != new_value).then(|| FieldChange {
+                    column: header.to_string(),
+                    old_value,
+                    new_value,
+                    is_address: is_address_column(header),
+                })
+            })
+            .collect()
+    }
+
+    fn count(summary: &mut DiffSummary, row_diff: &RowDiff) {
+        match row_diff.status {
+            DiffStatus::Added => summary.added += 1,
+            DiffStatus::Removed => summary.removed += 1,
+            DiffStatus::Moved => summary.moved += 1,
+            DiffStatus::Changed => summary.changed += 1,
+            DiffStatus::Unchanged => summary.unchanged += 1,
+        }
+        if row_diff.changes.iter().any(|change| change.is_address) {
+            summary.address_changed += 1;
+        }
+    }
+}
+
+type KeyFn = for<'r> fn(&'r IOTableRow<'_>) -> Option<&'r str>;
+
+/// 新旧两版点表行的配对结果
+struct Pairing {
+    /// 旧表各行是否已配对
+    old_matched: Vec<bool>,
+    /// 新表各行配对的旧表行
+    pairs: Vec<Option<usize>>,
+}
+
+impl Pairing {
+    /// 按匹配依据配对尚未配对、且允许参与本轮配对的行；依据重复时以靠前的行为准
+    fn pair_by(&mut self, key_fn: KeyFn, old: &DiffSide<'_>, new: &DiffSide<'_>, old_eligible: &[bool], new_eligible: &[bool]) {
+        let mut old_by_key: HashMap<&str, usize> = HashMap::new();
+        for (old_index, row) in old.table.rows.iter().enumerate() {
+            if self.old_matched[old_index] || !old_eligible[old_index] {
+                continue;
+            }
+            if let Some(key) = key_fn(row) {
+                old_by_key.entry(key).or_insert(old_index);
+            }
+        }
+        for (new_index, row) in new.table.rows.iter().enumerate() {
+            if self.pairs[new_index].is_some() || !new_eligible[new_index] {
+                continue;
+            }
+            if let Some(old_index) = key_fn(row).and_then(|key| old_by_key.remove(key)) {
+                self.pairs[new_index] = Some(old_index);
+                self.old_matched[old_index] = true;
+            }
+        }
+    }
+}
+
+/// 各行的位号是否与另一版点表同一通道上的位号不同（另一版没有该通道时同样视为不同）
+fn retagged_rows(rows: &[IOTableRow<'_>], other: &[IOTableRow<'_>]) -> Vec<bool> {
+    let mut other_tags: HashMap<&str, Option<&str>> = HashMap::new();
+    for row in other {
+        if let Some(channel) = channel_key(row) {
+            other_tags.entry(channel).or_insert(tag_key(row));
+        }
+    }
+    rows.iter()
+        .map(|row| match (tag_key(row), channel_key(row)) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(tag), Some(channel)) => other_tags.get(channel) != Some(&Some(tag)),
+        })
+        .collect()
+}
+
+/// 通道位号，软点位（"/"）没有通道位号
+fn channel_key<'r>(row: &'r IOTableRow<'_>) -> Option<&'r str> {
+    row.channel_tag.as_deref().map(str::trim).filter(|tag| !tag.is_empty() && *tag != "/")
+}
+
+/// 位号，备用通道的位号不唯一，不参与匹配
+fn tag_key<'r>(row: &'r IOTableRow<'_>) -> Option<&'r str> {
+    row.tag.as_deref().map(str::trim).filter(|tag| !tag.is_empty() && *tag != "/" && *tag != SPARE_LABEL)
+}
+
+/// 去除首尾空白后的单元格内容
+fn cell_text(row: &IOTableRow<'_>, header: &str) -> String {
+    row.get_field_by_name(header).map(|value| value.trim().to_string()).unwrap_or_default()
+}
+
+/// 地址列：PLC地址、通讯地址及Modbus映射
+pub fn is_address_column(header: &str) -> bool {
+    header.ends_with("地址") || header.starts_with("Modbus") || header == "字序"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn row(channel_tag: &str, tag: &str, address: &str) -> IOTableRow<'static> {
+        IOTableRow {
+            channel_tag: Some(Cow::Owned(channel_tag.to_string())),
+            tag: Some(Cow::Owned(tag.to_string())),
+            plc_absolute_address: Some(Cow::Owned(address.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_moved_and_changed() {
+        let mut old_table = IOTable::new("V1".to_string());
+        old_table.add_row(row("1_2_AI_0", "PT-101", "%MD320"));
+        old_table.add_row(row("1_2_AI_1", "PT-102", "%MD324"));
+        old_table.add_row(row("1_2_AI_2", "PT-103", "%MD328"));
+        old_table.add_row(row("1_3_DI_0", "XS-101", "%MX20.0"));
+
+        let mut new_table = IOTable::new("V2".to_string());
+        new_table.add_row(row("1_2_AI_0", "PT-101", "%MD320"));
+        let mut changed = row("1_2_AI_1", "PT-102", "%MD332");
+        changed.variable_description = Some(Cow::Borrowed("出站压力"));
+        new_table.add_row(changed);
+        new_table.add_row(row("1_2_AI_3", "PT-103", "%MD328"));
+        new_table.add_row(row("1_4_DO_0", "XV-101", "%MX30.0"));
+
+        let diff = IoTableDiffService::diff(
+            &DiffSide::standard(&old_table),
+            &DiffSide::standard(&new_table),
+            DiffMatchKey::ChannelTag,
+        );
+
+        assert_eq!(diff.summary, DiffSummary {
+            added: 1,
+            removed: 1,
+            moved: 1,
+            changed: 1,
+            unchanged: 1,
+            address_changed: 1,
+        });
+        let statuses: Vec<(DiffStatus, Option<u32>, Option<u32>)> = diff.rows.iter()
+            .map(|row| (row.status, row.old_row, row.new_row))
+            .collect();
+        assert_eq!(statuses, vec![
+            (DiffStatus::Changed, Some(3), Some(3)),
+            (DiffStatus::Moved, Some(4), Some(4)),
+            (DiffStatus::Added, None, Some(5)),
+            (DiffStatus::Removed, Some(5), None),
+        ]);
+
+        let changed_columns: Vec<(&str, bool)> = diff.rows[0].changes.iter()
+            .map(|change| (change.column.as_str(), change.is_address))
+            .collect();
+        assert_eq!(changed_columns, vec![("变量描述", false), ("PLC绝对地址", true)]);
+        assert_eq!(diff.rows[1].old_channel_tag, "1_2_AI_2");
+        assert_eq!(diff.rows[1].new_channel_tag, "1_2_AI_3");
+    }
+
+    #[test]
+    fn test_point_moved_into_channel_of_removed_point() {
+        let mut old_table = IOTable::new("V1".to_string());
+        old_table.add_row(row("1_2_AI_0", "PT-101", "%MD320"));
+        old_table.add_row(row("1_2_AI_1", "PT-102", "%MD324"));
+
+        // PT-102已删除，PT-101改接到其原通道
+        let mut new_table = IOTable::new("V2".to_string());
+        new_table.add_row(row("1_2_AI_1", "PT-101", "%MD320"));
+
+        let diff = IoTableDiffService::diff(
+            &DiffSide::standard(&old_table),
+            &DiffSide::standard(&new_table),
+            DiffMatchKey::ChannelTag,
+        );
+        let statuses: Vec<(DiffStatus, &str)> = diff.rows.iter()
+            .map(|row| (row.status, row.tag.as_str()))
+            .collect();
+        assert_eq!(statuses, vec![(DiffStatus::Moved, "PT-101"), (DiffStatus::Removed, "PT-102")]);
+        assert_eq!(diff.rows[0].old_channel_tag, "1_2_AI_0");
+    }
+
+    #[test]
+    fn test_diff_compares_extra_template_columns() {
+        let mut old_row = row("1_2_AI_0", "PT-101", "%MD320");
+        old_row.extra_fields.insert("工程单位".to_string(), Cow::Borrowed("kPa"));
+        let mut new_row = row("1_2_AI_0", "PT-101", "%MD320");
+        new_row.extra_fields.insert("工程单位".to_string(), Cow::Borrowed("MPa"));
+        new_row.extra_fields.insert("备注".to_string(), Cow::Borrowed("新增"));
+
+        let changes = IoTableDiffService::field_changes(&old_row, &new_row);
+        let columns: Vec<(&str, &str, &str)> = changes.iter()
+            .map(|change| (change.column.as_str(), change.old_value.as_str(), change.new_value.as_str()))
+            .collect();
+        assert_eq!(columns, vec![("备注", "", "新增"), ("工程单位", "kPa", "MPa")]);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::application_services::excel_services::io_excel_services::get_column_letter;
+use crate::application_services::excel_services::validation_annotator::{ERROR_CELL_COLOR, WARNING_CELL_COLOR};
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::application_services::validation_services::io_table_validator::ALARM_VALUE_FIELDS;
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::validation_model::{Severity, ValidationRule, ValidationRuleSet};
+use umya_spreadsheet::{
+    ConditionalFormatValues, ConditionalFormatting, ConditionalFormattingRule, Formula, PatternValues, Style,
+    Worksheet,
+};
+
+/// 一条条件格式：区域、公式（相对区域左上角单元格）及严重程度
+#[derive(Debug, Clone, PartialEq)]
+struct FormatRule {
+    range: String,
+    formula: String,
+    severity: Severity,
+}
+
+/// 点表条件格式服务
+/// 在点表中添加与工程校验一致的条件格式，离线修改点表时问题单元格在Excel中直接标色
+pub struct ConditionalFormatService;
+
+impl ConditionalFormatService {
+    /// 为数据行添加条件格式，未启用的规则不添加
+    pub fn apply(worksheet: &mut Worksheet, row_count: usize, rules: &ValidationRuleSet, template: &ColumnTemplate) {
+        if row_count == 0 {
+            return;
+        }
+
+        for (priority, rule) in Self::format_rules(row_count as u32 + 1, rules, template).into_iter().enumerate() {
+            let mut formula = Formula::default();
+            formula.set_string_value(rule.formula);
+
+            let mut formatting_rule = ConditionalFormattingRule::default();
+            formatting_rule.set_type(ConditionalFormatValues::Expression);
+            formatting_rule.set_priority(priority as i32 + 1);
+            formatting_rule.set_formula(formula);
+            formatting_rule.set_style(Self::highlight_style(rule.severity));
+
+            let mut formatting = ConditionalFormatting::default();
+            formatting.get_sequence_of_references_mut().set_sqref(rule.range);
+            formatting.add_conditional_collection(formatting_rule);
+            worksheet.add_conditional_formatting_collection(formatting);
+        }
+    }
+
+    /// 第2行至last_row的条件格式，与IoTableValidator的规则对应；列模板中缺少所需的列时跳过该条
+    fn format_rules(last_row: u32, rules: &ValidationRuleSet, template: &ColumnTemplate) -> Vec<FormatRule> {
+        let cell = |header: &str| cell_reference(template, header);
+        let mut format_rules = Vec::new();
+        let mut add = |rule: ValidationRule, header: &str, formula: Option<String>| {
+            let (Some(severity), Some(col), Some(formula)) =
+                (rules.severity_for(rule), template.column_number(header), formula)
+            else {
+                return;
+            };
+            let col = get_column_letter(col);
+            format_rules.push(FormatRule {
+                range: format!("{}2:{}{}", col, col, last_row),
+                formula,
+                severity,
+            });
+        };
+
+        // 报警设定值大于其后任一已填写的设定值
+        for (idx, field) in ALARM_VALUE_FIELDS.iter().enumerate() {
+            let later: Vec<String> = ALARM_VALUE_FIELDS[idx + 1..].iter().filter_map(|later| cell(later)).collect();
+            if later.is_empty() {
+                continue;
+            }
+            let formula = cell(field).map(|value| {
+                let conditions: Vec<String> = later
+                    .iter()
+                    .map(|later| format!("AND(ISNUMBER({}),{}>{})", later, value, later))
+                    .collect();
+                format!("AND(ISNUMBER({}),OR({}))", value, conditions.join(","))
+            });
+            add(ValidationRule::AlarmOrder, field, formula);
+        }
+
+        // 量程低限不小于量程高限；报警设定值超出量程
+        if let (Some(low), Some(high)) = (cell("量程低限"), cell("量程高限")) {
+            add(
+                ValidationRule::LimitOutOfRange,
+                "量程高限",
+                Some(format!("AND(ISNUMBER({}),ISNUMBER({}),{}>={})", low, high, low, high)),
+            );
+            for field in ALARM_VALUE_FIELDS {
+                let formula = cell(field).map(|value| {
+                    format!(
+                        "AND(ISNUMBER({}),ISNUMBER({}),ISNUMBER({}),{}<{},OR({}<{},{}>{}))",
+                        value, low, high, low, high, value, low, value, high
+                    )
+                });
+                add(ValidationRule::LimitOutOfRange, field, formula);
+            }
+        }
+
+        // 已填写HMI变量名称或变量描述的通道未填写位号
+        let hmi = cell("变量名称（HMI）");
+        let used: Vec<String> = [hmi.as_deref(), cell("变量描述").as_deref()]
+            .into_iter()
+            .flatten()
+            .map(filled)
+            .collect();
+        if !used.is_empty() {
+            let formula = cell("位号").map(|tag| format!("AND(LEN(TRIM({}))=0,OR({}))", tag, used.join(",")));
+            add(ValidationRule::MissingTag, "位号", formula);
+        }
+
+        // HMI变量名称重复（COUNTIF不区分大小写）
+        if let (Some(hmi), Some(col)) = (hmi, template.column_number("变量名称（HMI）").map(get_column_letter)) {
+            add(
+                ValidationRule::DuplicateHmiName,
+                "变量名称（HMI）",
+                Some(format!(
+                    "AND(LEN(TRIM({}))>0,{}<>\"/\",COUNTIF(${}$2:${}${},{})>1)",
+                    hmi, hmi, col, col, last_row, hmi
+                )),
+            );
+        }
+
+        format_rules
+    }
+
+    /// 按严重程度标色，颜色与校验标注一致
+    fn highlight_style(severity: Severity) -> Style {
+        let color = match severity {
+            Severity::Error => ERROR_CELL_COLOR,
+            Severity::Warning => WARNING_CELL_COLOR,
+        };
+        let mut style = Style::default();
+        let fill = style.get_fill_mut().get_pattern_fill_mut();
+        fill.set_pattern_type(PatternValues::Solid);
+        // 条件格式的纯色填充使用背景色
+        fill.get_background_color_mut().set_argb(color);
+        style
+    }
+}
+
+/// 第2行该列的单元格，列固定、行相对，例如"$G2"；列模板中没有该列时返回None
+fn cell_reference(template: &ColumnTemplate, header: &str) -> Option<String> {
+    template.column_number(header).map(|col| format!("${}2", get_column_letter(col)))
+}
+
+/// 单元格已填写（不为空、"/"或备用）
+fn filled(cell: &str) -> String {
+    format!("AND(LEN(TRIM({}))>0,{}<>\"/\",{}<>\"{}\")", cell, cell, cell, SPARE_LABEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::column_template_model::TemplateColumn;
+
+    #[test]
+    fn test_format_rules_follow_rule_set() {
+        let standard = ColumnTemplate::standard();
+        let rules = ConditionalFormatService::format_rules(10, &ValidationRuleSet::default(), &standard);
+        let ranges: Vec<&str> = rules.iter().map(|rule| rule.range.as_str()).collect();
+        assert_eq!(ranges, vec![
+            "Q2:Q10", "U2:U10", "Y2:Y10",
+            "P2:P10", "Q2:Q10", "U2:U10", "Y2:Y10", "AC2:AC10",
+            "G2:G10", "I2:I10",
+        ]);
+        assert_eq!(rules[2].formula, "AND(ISNUMBER($Y2),OR(AND(ISNUMBER($AC2),$Y2>$AC2)))");
+        assert_eq!(rules[3].formula, "AND(ISNUMBER($O2),ISNUMBER($P2),$O2>=$P2)");
+        assert_eq!(rules[9].formula, "AND(LEN(TRIM($I2))>0,$I2<>\"/\",COUNTIF($I$2:$I$10,$I2)>1)");
+
+        // 关闭的规则不添加条件格式
+        let mut disabled = ValidationRuleSet::default();
+        disabled.alarm_order.enabled = false;
+        disabled.missing_tag.severity = Severity::Warning;
+        let rules = ConditionalFormatService::format_rules(10, &disabled, &standard);
+        assert_eq!(rules.len(), 7);
+        assert_eq!(rules[5].severity, Severity::Warning);
+
+        // 列模板中没有量程列时不添加超出量程的条件格式，其余规则按模板中的列号生成
+        let template = ColumnTemplate {
+            name: "精简".to_string(),
+            columns: ["位号", "变量名称（HMI）", "SL设定值", "SH设定值"]
+                .iter()
+                .map(|field| TemplateColumn { field: field.to_string(), header: String::new() })
+                .collect(),
+        };
+        let rules = ConditionalFormatService::format_rules(10, &ValidationRuleSet::default(), &template);
+        let ranges: Vec<&str> = rules.iter().map(|rule| rule.range.as_str()).collect();
+        assert_eq!(ranges, vec!["C2:C10", "A2:A10", "B2:B10"]);
+        assert_eq!(rules[1].formula, "AND(LEN(TRIM($A2))=0,OR(AND(LEN(TRIM($B2))>0,$B2<>\"/\",$B2<>\"备用\")))");
+    }
+}
@@ -0,0 +1,218 @@
+use crate::application_services::diff_services::io_table_diff_service::{DiffSide, IoTableDiffService};
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IOExcelService, IoError};
+use crate::model_domain::io_diff_model::{DiffMatchKey, DiffStatus};
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::io_table_model::IOTable;
+use crate::model_domain::revision_model::{DocumentInfo, RevisionEntry, RevisionHistory};
+use std::time::{SystemTime, UNIX_EPOCH};
+use umya_spreadsheet::structs::Comment;
+use umya_spreadsheet::{Spreadsheet, Worksheet};
+
+/// 封面名称
+pub const COVER_SHEET_NAME: &str = "封面";
+
+/// 修订记录表头
+const REVISION_HEADERS: [&str; 5] = ["版本", "日期", "编制", "修订说明", "变更单元格数"];
+
+/// 封面各列的列宽
+const COLUMN_WIDTHS: [f64; 5] = [16.0, 30.0, 14.0, 40.0, 14.0];
+
+/// 标题字号
+const TITLE_FONT_SIZE: f64 = 16.0;
+
+/// 变更单元格的字体颜色
+const CHANGED_CELL_FONT_COLOR: &str = "FFFF0000";
+
+/// 批注作者
+const COMMENT_AUTHOR: &str = "修订标记";
+
+/// 封面及修订记录服务
+/// 在点表中新增封面和修订记录，并标记与上一版相比变化的单元格
+pub struct CoverSheetService;
+
+impl CoverSheetService {
+    /// 生成本次修订：标记主表中与上一版相比变化的单元格，新增封面及修订记录，返回本次修订记录
+    pub fn write_revision(
+        spreadsheet: &mut Spreadsheet,
+        io_table: &IOTable<'_>,
+        template: &ColumnTemplate,
+        station_name: &str,
+        document: &DocumentInfo,
+        history: &RevisionHistory,
+        previous: Option<&IOTable<'_>>,
+    ) -> Result<RevisionEntry, IoError> {
+        let revision = history.next_revision();
+        let changed_cells = match previous {
+            Some(previous) => Self::mark_changes(spreadsheet, io_table, template, previous, &revision)?,
+            None => 0,
+        };
+
+        let date = document.date.trim();
+        let entry = RevisionEntry {
+            revision,
+            date: if date.is_empty() { today() } else { date.to_string() },
+            author: document.author.clone(),
+            description: document.description.clone(),
+            changed_cells,
+            // 点表保存后填写
+            snapshot_version: None,
+        };
+
+        let mut entries = history.entries.clone();
+        entries.push(entry.clone());
+        Self::write_cover(spreadsheet, station_name, document, &entries)?;
+        Ok(entry)
+    }
+
+    /// 标记主表中与上一版相比变化的单元格，返回标记的单元格数
+    ///
+    /// 修改的单元格批注原值，新增的点位只标记通道位号；列模板中没有的列不标记
+    fn mark_changes(
+        spreadsheet: &mut Spreadsheet,
+        io_table: &IOTable<'_>,
+        template: &ColumnTemplate,
+        previous: &IOTable<'_>,
+        revision: &str,
+    ) -> Result<usize, IoError> {
+        let diff = IoTableDiffService::diff(
+            &DiffSide::standard(previous),
+            &DiffSide::standard(io_table),
+            DiffMatchKey::ChannelTag,
+        );
+        let worksheet = spreadsheet
+            .get_sheet_mut(&0)
+            .ok_or_else(|| IoError::DataQuery("IO点表工作表不存在".to_string()))?;
+
+        let mut marked = 0;
+        for row_diff in &diff.rows {
+            let Some(row) = row_diff.new_row else {
+                continue;
+            };
+            if row_diff.status == DiffStatus::Added {
+                marked += Self::mark_cell(worksheet, template, "通道位号", row, format!("修订{}新增", revision));
+                continue;
+            }
+            for change in &row_diff.changes {
+                let text = format!("修订{}变更，原值：{}", revision, change.old_value);
+                marked += Self::mark_cell(worksheet, template, &change.column, row, text);
+            }
+        }
+        Ok(marked)
+    }
+
+    /// 变更单元格标红加粗并添加批注，返回标记的单元格数
+    fn mark_cell(worksheet: &mut Worksheet, template: &ColumnTemplate, header: &str, row: u32, text: String) -> usize {
+        let Some(col) = template.column_number(header) else {
+            return 0;
+        };
+
+        let font = worksheet.get_style_mut((col, row)).get_font_mut();
+        font.set_bold(true);
+        font.get_color_mut().set_argb(CHANGED_CELL_FONT_COLOR);
+
+        let mut comment = Comment::default();
+        comment.new_comment((col, row));
+        comment.set_author(COMMENT_AUTHOR);
+        comment.set_text_string(text);
+        worksheet.add_comments(comment);
+        1
+    }
+
+    /// 新增封面：项目信息、当前版本及修订记录，封面放在第一个工作表
+    fn write_cover(
+        spreadsheet: &mut Spreadsheet,
+        station_name: &str,
+        document: &DocumentInfo,
+        entries: &[RevisionEntry],
+    ) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(COVER_SHEET_NAME)
+            .map_err(|e| IoError::Unknown(format!("创建封面失败: {}", e)))?;
+
+        let project = &document.project;
+        let station = if project.station_name.is_empty() { station_name } else { project.station_name.as_str() };
+        worksheet.get_cell_mut((1, 1)).set_value(format!("{} {} IO点表", project.project_name, station));
+        let title_font = worksheet.get_style_mut((1, 1)).get_font_mut();
+        title_font.set_bold(true);
+        title_font.set_size(TITLE_FONT_SIZE);
+
+        let current = entries.last().cloned().unwrap_or_default();
+        let fields = [
+            ("项目名称", project.project_name.as_str()),
+            ("项目编号", project.project_number.as_str()),
+            ("深化设计编号", project.design_number.as_str()),
+            ("客户名称", project.customer_name.as_str()),
+            ("场站", station),
+            ("技术负责人", project.technical_lead.as_str()),
+            ("项目经理", project.project_manager.as_str()),
+            ("版本", current.revision.as_str()),
+            ("日期", current.date.as_str()),
+            ("编制", current.author.as_str()),
+        ];
+        let mut row = 3;
+        for (label, value) in fields {
+            worksheet.get_cell_mut((1, row)).set_value(label.to_string());
+            let style = worksheet.get_style_mut((1, row));
+            style.get_font_mut().set_bold(true);
+            IOExcelService::apply_common_cell_style(style, false);
+            worksheet.get_cell_mut((2, row)).set_value(value.to_string());
+            IOExcelService::apply_common_cell_style(worksheet.get_style_mut((2, row)), false);
+            row += 1;
+        }
+
+        // 修订记录按版本顺序排列在项目信息下方
+        row += 1;
+        worksheet.get_cell_mut((1, row)).set_value("修订记录".to_string());
+        worksheet.get_style_mut((1, row)).get_font_mut().set_bold(true);
+        row += 1;
+        let rows = entries.iter().map(|entry| [
+            entry.revision.clone(),
+            entry.date.clone(),
+            entry.author.clone(),
+            entry.description.clone(),
+            entry.changed_cells.to_string(),
+        ]);
+        write_simple_table(worksheet, row, &REVISION_HEADERS, rows, &COLUMN_WIDTHS);
+
+        // 新工作表追加在末尾，移到最前
+        spreadsheet.get_sheet_collection_mut().rotate_right(1);
+
+        Ok(())
+    }
+}
+
+/// 当天日期（UTC），格式为YYYY-MM-DD
+fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 将1970-01-01起的天数换算为公历年月日
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_744), (2026, 10, 18));
+    }
+}
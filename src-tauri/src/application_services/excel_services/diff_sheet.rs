@@ -0,0 +1,126 @@
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IoError};
+use crate::model_domain::io_diff_model::{DiffStatus, IoTableDiff, RowDiff};
+use crate::model_domain::io_table_model::{IOTable, IO_TABLE_HEADERS};
+use std::collections::BTreeSet;
+use std::io::Cursor;
+use umya_spreadsheet::structs::Comment;
+use umya_spreadsheet::Spreadsheet;
+
+/// 点表差异表名称
+pub const DIFF_SHEET_NAME: &str = "点表差异";
+
+/// 差异表在点表列之前的说明列
+const LEADING_HEADERS: [&str; 3] = ["状态", "原行号", "新行号"];
+
+/// 差异表的默认列宽
+const COLUMN_WIDTH: f64 = 14.0;
+
+/// 新增行的背景色
+const ADDED_ROW_COLOR: &str = "FFC6EFCE";
+/// 删除行的背景色
+const REMOVED_ROW_COLOR: &str = "FFFFC7CE";
+/// 移动点位的状态及通道位号单元格背景色
+const MOVED_CELL_COLOR: &str = "FFBDD7EE";
+/// 内容变化的单元格背景色
+const CHANGED_CELL_COLOR: &str = "FFFFEB9C";
+/// 地址变化的单元格背景色
+const ADDRESS_CHANGED_CELL_COLOR: &str = "FFF4B084";
+
+/// 批注作者
+const COMMENT_AUTHOR: &str = "点表对比";
+
+/// 点表差异表服务
+/// 逐行列出有变化的点位，新增、删除整行标色，修改的单元格标色并以批注注明原值
+pub struct IoDiffSheetService;
+
+impl IoDiffSheetService {
+    /// 生成只包含差异表的工作簿内容
+    pub fn build_workbook(diff: &IoTableDiff, old_table: &IOTable<'_>, new_table: &IOTable<'_>) -> Result<Vec<u8>, IoError> {
+        let mut spreadsheet = umya_spreadsheet::new_file_empty_worksheet();
+        Self::write_sheet(&mut spreadsheet, diff, old_table, new_table)?;
+
+        let mut buffer = Cursor::new(Vec::new());
+        umya_spreadsheet::writer::xlsx::write_writer(&spreadsheet, &mut buffer)?;
+        Ok(buffer.into_inner())
+    }
+
+    /// 在工作簿中新增差异表
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        diff: &IoTableDiff,
+        old_table: &IOTable<'_>,
+        new_table: &IOTable<'_>,
+    ) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(DIFF_SHEET_NAME)
+            .map_err(|e| IoError::Unknown(format!("创建点表差异表失败: {}", e)))?;
+
+        // 点表列：标准列及两版点表中出现的自定义列
+        let extra_columns: BTreeSet<&str> = old_table.rows.iter()
+            .chain(&new_table.rows)
+            .flat_map(|row| row.extra_fields.keys().map(String::as_str))
+            .filter(|field| !IO_TABLE_HEADERS.contains(field))
+            .collect();
+        let columns: Vec<&str> = IO_TABLE_HEADERS.iter().copied().chain(extra_columns).collect();
+        let column_of = |header: &str| {
+            let position = columns.iter().position(|column| *column == header).unwrap_or_default();
+            (LEADING_HEADERS.len() + position) as u32 + 1
+        };
+
+        let headers: Vec<&str> = LEADING_HEADERS.iter().copied().chain(columns.iter().copied()).collect();
+        let rows = diff.rows.iter().map(|row_diff| {
+            let source = match row_diff.new_index {
+                Some(index) => &new_table.rows[index],
+                None => &old_table.rows[row_diff.old_index.unwrap_or_default()],
+            };
+            let leading = [
+                row_diff.status.to_string(),
+                row_diff.old_row.map(|r| r.to_string()).unwrap_or_default(),
+                row_diff.new_row.map(|r| r.to_string()).unwrap_or_default(),
+            ];
+            leading.into_iter().chain(columns.iter().map(move |header| {
+                source.get_field_by_name(header).map(|value| value.to_string()).unwrap_or_default()
+            }))
+        });
+        write_simple_table(worksheet, 1, &headers, rows, &vec![COLUMN_WIDTH; headers.len()]);
+
+        for (row_idx, row_diff) in diff.rows.iter().enumerate() {
+            let row = row_idx as u32 + 2;
+            if let Some(color) = Self::row_color(row_diff) {
+                for col in 1..=headers.len() as u32 {
+                    worksheet.get_style_mut((col, row)).set_background_color(color.to_string());
+                }
+            }
+
+            if row_diff.status == DiffStatus::Moved {
+                let channel_col = column_of("通道位号");
+                for col in [1, channel_col] {
+                    worksheet.get_style_mut((col, row)).set_background_color(MOVED_CELL_COLOR.to_string());
+                }
+            }
+
+            for change in &row_diff.changes {
+                let col = column_of(&change.column);
+                let color = if change.is_address { ADDRESS_CHANGED_CELL_COLOR } else { CHANGED_CELL_COLOR };
+                worksheet.get_style_mut((col, row)).set_background_color(color.to_string());
+
+                let mut comment = Comment::default();
+                comment.new_comment((col, row));
+                comment.set_author(COMMENT_AUTHOR);
+                comment.set_text_string(format!("原值：{}", change.old_value));
+                worksheet.add_comments(comment);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 新增、删除行整行标色
+    fn row_color(row_diff: &RowDiff) -> Option<&'static str> {
+        match row_diff.status {
+            DiffStatus::Added => Some(ADDED_ROW_COLOR),
+            DiffStatus::Removed => Some(REMOVED_ROW_COLOR),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IoError};
+use crate::model_domain::dnp3_profile_model::Dnp3Point;
+use umya_spreadsheet::Spreadsheet;
+
+/// DNP3点表名称后缀
+pub const DNP3_SHEET_SUFFIX: &str = "DNP3点表";
+
+/// DNP3点表表头
+const DNP3_HEADERS: [&str; 10] = [
+    "点类型", "对象组", "索引", "事件等级", "死区", "通道位号", "变量名称（HMI）", "变量描述", "点位", "PLC地址"
+];
+
+/// DNP3点表的默认列宽
+const COLUMN_WIDTH: f64 = 16.0;
+
+/// DNP3点表服务
+/// 列出每个点位及附加点位的DNP3点类型、索引、事件等级和死区
+pub struct Dnp3SheetService;
+
+impl Dnp3SheetService {
+    /// 在工作簿中新增DNP3点表
+    pub fn write_sheet(spreadsheet: &mut Spreadsheet, sheet_name: &str, points: &[Dnp3Point]) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建DNP3点表失败: {}", e)))?;
+
+        let rows = points.iter().map(|point| [
+            point.point_type.to_string(),
+            point.point_type.static_group().to_string(),
+            point.index.to_string(),
+            point.event_class.to_string(),
+            point.deadband.map(|deadband| deadband.to_string()).unwrap_or_else(|| "/".to_string()),
+            point.channel_tag.clone(),
+            point.variable_name.clone(),
+            point.description.clone(),
+            point.point_name.clone(),
+            point.plc_address.clone(),
+        ]);
+        write_simple_table(worksheet, 1, &DNP3_HEADERS, rows, &[COLUMN_WIDTH; DNP3_HEADERS.len()]);
+
+        Ok(())
+    }
+}
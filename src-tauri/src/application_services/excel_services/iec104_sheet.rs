@@ -0,0 +1,41 @@
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IoError};
+use crate::model_domain::iec104_profile_model::Iec104Point;
+use umya_spreadsheet::Spreadsheet;
+
+/// IEC 104信息体地址表名称后缀
+pub const IEC104_SHEET_SUFFIX: &str = "IEC104信息体地址";
+
+/// IEC 104信息体地址表表头
+const IEC104_HEADERS: [&str; 8] = [
+    "序号", "IOA", "类型", "通道位号", "变量名称（HMI）", "变量描述", "点位", "PLC地址"
+];
+
+/// IEC 104信息体地址表的默认列宽
+const COLUMN_WIDTH: f64 = 18.0;
+
+/// IEC 104信息体地址表服务
+/// 列出每个点位及附加点位分配到的IOA，供调度中心配置转发表
+pub struct Iec104SheetService;
+
+impl Iec104SheetService {
+    /// 在工作簿中新增IEC 104信息体地址表
+    pub fn write_sheet(spreadsheet: &mut Spreadsheet, sheet_name: &str, points: &[Iec104Point]) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建IEC104信息体地址表失败: {}", e)))?;
+
+        let rows = points.iter().enumerate().map(|(row_idx, point)| [
+            (row_idx + 1).to_string(),
+            point.ioa.to_string(),
+            point.type_id.to_string(),
+            point.channel_tag.clone(),
+            point.variable_name.clone(),
+            point.description.clone(),
+            point.point_name.clone(),
+            point.plc_address.clone(),
+        ]);
+        write_simple_table(worksheet, 1, &IEC104_HEADERS, rows, &[COLUMN_WIDTH; IEC104_HEADERS.len()]);
+
+        Ok(())
+    }
+}
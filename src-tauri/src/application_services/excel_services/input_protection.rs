@@ -0,0 +1,167 @@
+use crate::application_services::excel_services::io_excel_services::{get_column_letter, HIGHLIGHT_FIELDS};
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::io_table_model::{IOTableRow, IO_TABLE_HEADERS};
+use umya_spreadsheet::{DataValidation, DataValidationValues, DataValidations, SheetProtection, Worksheet};
+
+/// 有默认值、允许工程师修改的字段（不高亮，但不锁定）
+pub const ADJUSTABLE_FIELDS: [&str; 3] = ["读写属性", "保存历史", "掉电保护"];
+
+/// 下拉选项（列名, 可选值）
+const DROPDOWN_FIELDS: [(&str, &[&str]); 5] = [
+    ("供电类型（有源/无源）", &["有源", "无源"]),
+    ("线制", &["二线制", "三线制", "四线制"]),
+    ("读写属性", &["R/W", "R", "W"]),
+    ("保存历史", &["是", "否"]),
+    ("掉电保护", &["是", "否"]),
+];
+
+/// 只允许填写数值的字段
+const NUMERIC_FIELDS: [&str; 6] = [
+    "量程低限", "量程高限", "SLL设定值", "SL设定值", "SH设定值", "SHH设定值",
+];
+
+/// 输入校验失败时的提示标题
+const ERROR_TITLE: &str = "输入无效";
+
+/// 点表输入保护服务
+/// 为工程师填写的列添加下拉选项和数值校验，并保护工作表，只有待填写的单元格可以修改
+pub struct InputProtectionService;
+
+impl InputProtectionService {
+    /// 单元格是否允许工程师填写
+    ///
+    /// 待填写字段和可调整字段中不为"/"的单元格不锁定；BOOL点位没有量程。
+    /// 列模板中的自定义列（例如工程单位、备注）由工程师填写，同样不锁定。
+    /// 备用通道同样不锁定，以便在点表中直接启用备用通道
+    pub fn is_input_cell(header: &str, row_data: &IOTableRow<'_>) -> bool {
+        if !IO_TABLE_HEADERS.contains(&header) {
+            return true;
+        }
+        if !HIGHLIGHT_FIELDS.contains(&header) && !ADJUSTABLE_FIELDS.contains(&header) {
+            return false;
+        }
+        let data_type = row_data.data_type.as_deref().unwrap_or("BOOL");
+        if data_type == "BOOL" && header.contains("量程") {
+            return false;
+        }
+        row_data.get_field_by_name(header).map(|value| value.as_ref()) != Some("/")
+    }
+
+    /// 为数据行添加输入校验并保护工作表，password为空时不设密码
+    pub fn apply(worksheet: &mut Worksheet, row_count: usize, password: Option<&str>, template: &ColumnTemplate) {
+        if row_count > 0 {
+            worksheet.set_data_validations(Self::data_validations(row_count as u32 + 1, template));
+        }
+
+        let mut protection = SheetProtection::default();
+        protection.set_sheet(true);
+        // 允许调整行高列宽，便于离线查看
+        protection.set_format_columns(false);
+        protection.set_format_rows(false);
+        if let Some(password) = password.map(str::trim).filter(|p| !p.is_empty()) {
+            protection.set_password(password);
+        }
+        worksheet.set_sheet_protection(protection);
+    }
+
+    /// 第2行至last_row的下拉选项及数值校验，列模板中没有的列跳过
+    fn data_validations(last_row: u32, template: &ColumnTemplate) -> DataValidations {
+        let mut validations = DataValidations::default();
+
+        for (header, options) in DROPDOWN_FIELDS {
+            let Some(range) = column_range(template, header, last_row) else {
+                continue;
+            };
+            let mut validation = Self::validation(DataValidationValues::List, &range);
+            validation.set_formula1(format!("\"{}\"", options.join(",")));
+            validation.set_error_message(format!("{}只能选择：{}", header, options.join("、")));
+            validations.add_data_validation_list(validation);
+        }
+
+        for header in NUMERIC_FIELDS {
+            let Some(range) = column_range(template, header, last_row) else {
+                continue;
+            };
+            // 自定义公式相对区域左上角单元格，对区域内每个单元格生效
+            let first_cell = range.split(':').next().unwrap_or_default().to_string();
+            let mut validation = Self::validation(DataValidationValues::Custom, &range);
+            validation.set_formula1(format!("ISNUMBER({})", first_cell));
+            validation.set_error_message(format!("{}只能填写数值", header));
+            validations.add_data_validation_list(validation);
+        }
+
+        validations
+    }
+
+    fn validation(kind: DataValidationValues, range: &str) -> DataValidation {
+        let mut validation = DataValidation::default();
+        validation.set_type(kind);
+        validation.set_allow_blank(true);
+        validation.set_show_error_message(true);
+        validation.set_error_title(ERROR_TITLE);
+        validation.get_sequence_of_references_mut().set_sqref(range);
+        validation
+    }
+}
+
+/// 列在第2行至last_row的区域，例如"D2:D10"
+fn column_range(template: &ColumnTemplate, header: &str, last_row: u32) -> Option<String> {
+    let letter = get_column_letter(template.column_number(header)?);
+    Some(format!("{}2:{}{}", letter, letter, last_row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::column_template_model::TemplateColumn;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_input_cells_exclude_generated_and_placeholder_fields() {
+        let ao_row = IOTableRow {
+            data_type: Some(Cow::Borrowed("REAL")),
+            power_supply_type: Some(Cow::Borrowed("/")),
+            save_history: Some(Cow::Borrowed("是")),
+            ..Default::default()
+        };
+        assert!(InputProtectionService::is_input_cell("位号", &ao_row));
+        assert!(InputProtectionService::is_input_cell("量程低限", &ao_row));
+        assert!(InputProtectionService::is_input_cell("保存历史", &ao_row));
+        assert!(!InputProtectionService::is_input_cell("供电类型（有源/无源）", &ao_row));
+        assert!(!InputProtectionService::is_input_cell("SLL设定点位", &ao_row));
+        assert!(!InputProtectionService::is_input_cell("PLC绝对地址", &ao_row));
+
+        let di_row = IOTableRow {
+            data_type: Some(Cow::Borrowed("BOOL")),
+            is_spare: true,
+            ..Default::default()
+        };
+        assert!(InputProtectionService::is_input_cell("位号", &di_row));
+        assert!(!InputProtectionService::is_input_cell("量程高限", &di_row));
+
+        assert_eq!(column_range(&ColumnTemplate::standard(), "线制", 10).as_deref(), Some("E2:E10"));
+    }
+
+    #[test]
+    fn test_extra_template_columns_are_input_cells() {
+        let template = ColumnTemplate {
+            name: "客户A".to_string(),
+            columns: ["位号", "工程单位", "PLC绝对地址"]
+                .iter()
+                .map(|field| TemplateColumn { field: field.to_string(), header: String::new() })
+                .collect(),
+        };
+        let row = IOTableRow {
+            data_type: Some(Cow::Borrowed("REAL")),
+            plc_absolute_address: Some(Cow::Borrowed("%MD320")),
+            ..Default::default()
+        };
+
+        let input_fields: Vec<&str> = template.columns.iter()
+            .filter(|column| InputProtectionService::is_input_cell(&column.field, &row))
+            .map(|column| column.field.as_str())
+            .collect();
+        assert_eq!(input_fields, vec!["位号", "工程单位"]);
+        assert!(template.columns[1].is_extra());
+    }
+}
@@ -0,0 +1,624 @@
+use crate::application_services::excel_services::io_excel_services::{get_column_letter, DataType, IOChannelType, IoError};
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::io_import_model::{ImportIssue, IoImportResult, SheetLayout};
+use crate::model_domain::io_table_model::{IOTable, IOTableRow, IO_TABLE_HEADERS, POINT_KEY_HEADER};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+use umya_spreadsheet::Worksheet;
+
+/// 表头所在行的最大查找范围
+const HEADER_SEARCH_ROWS: u32 = 10;
+/// 至少匹配的列数，少于此数的工作表不视为IO点表
+const MIN_MATCHED_HEADERS: usize = 3;
+/// 公式引用其他公式单元格的最大深度
+const MAX_FORMULA_DEPTH: u32 = 8;
+
+/// 需要填写数值的列
+const NUMERIC_COLUMNS: [&str; 6] = [
+    "量程低限", "量程高限", "SLL设定值", "SL设定值", "SH设定值", "SHH设定值"
+];
+
+/// 工作表中的单元格内容
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawCell {
+    /// 单元格的值，公式单元格为Excel保存的计算结果（可能为空）
+    pub value: String,
+    /// 公式文本
+    pub formula: Option<String>,
+}
+
+/// 读取后的工作表内容，行列均从1开始
+#[derive(Debug, Clone, Default)]
+pub struct SheetGrid {
+    pub name: String,
+    pub cells: HashMap<(u32, u32), RawCell>,
+    pub max_row: u32,
+    pub max_col: u32,
+}
+
+impl SheetGrid {
+    /// 从工作表读取全部单元格
+    pub fn from_worksheet(worksheet: &Worksheet) -> Self {
+        let mut grid = SheetGrid {
+            name: worksheet.get_name().to_string(),
+            max_row: worksheet.get_highest_row(),
+            max_col: worksheet.get_highest_column(),
+            ..Default::default()
+        };
+        for row in 1..=grid.max_row {
+            for col in 1..=grid.max_col {
+                let Some(cell) = worksheet.get_cell((col, row)) else {
+                    continue;
+                };
+                let formula = cell.is_formula().then(|| cell.get_formula().to_string());
+                grid.set(col, row, cell.get_value().to_string(), formula);
+            }
+        }
+        grid
+    }
+
+    /// 写入单元格内容，同时扩展表格范围
+    pub fn set(&mut self, col: u32, row: u32, value: String, formula: Option<String>) {
+        self.max_row = self.max_row.max(row);
+        self.max_col = self.max_col.max(col);
+        self.cells.insert((col, row), RawCell { value, formula });
+    }
+
+    fn get(&self, col: u32, row: u32) -> Option<&RawCell> {
+        self.cells.get(&(col, row))
+    }
+
+    /// 单元格的显示值：普通单元格取值；公式单元格优先取缓存的计算结果，没有时计算公式
+    fn resolve(&self, col: u32, row: u32, depth: u32) -> Result<String, String> {
+        let Some(cell) = self.get(col, row) else {
+            return Ok(String::new());
+        };
+        match &cell.formula {
+            Some(formula) if cell.value.is_empty() => {
+                if depth >= MAX_FORMULA_DEPTH {
+                    return Err("公式引用层级过深或存在循环引用".to_string());
+                }
+                FormulaEvaluator::new(self, formula, depth + 1).evaluate()
+            }
+            _ => Ok(cell.value.clone()),
+        }
+    }
+}
+
+/// IO点表Excel读取服务
+/// 将按列模板生成的工作簿读回IOTable，允许列顺序调整或存在额外的列
+pub struct IOExcelReader;
+
+impl IOExcelReader {
+    /// 读取点表文件，表名使用文件名
+    pub fn read_file(path: &Path, template: &ColumnTemplate) -> Result<IoImportResult, IoError> {
+        let spreadsheet = umya_spreadsheet::reader::xlsx::read(path)
+            .map_err(|e| IoError::ExcelImport(e.to_string()))?;
+        let grids: Vec<SheetGrid> = spreadsheet
+            .get_sheet_collection()
+            .iter()
+            .map(SheetGrid::from_worksheet)
+            .collect();
+        let table_name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self::parse_workbook(&grids, table_name, template)
+    }
+
+    /// 在各工作表中查找匹配列最多的表头行，并解析其下方的数据
+    pub fn parse_workbook(
+        grids: &[SheetGrid],
+        table_name: String,
+        template: &ColumnTemplate,
+    ) -> Result<IoImportResult, IoError> {
+        // 匹配列数相同时取靠前的工作表，附表（例如已移除通道表）可能使用相同的表头
+        let (_, grid, header_row, columns) = grids
+            .iter()
+            .enumerate()
+            .flat_map(|(sheet_idx, grid)| {
+                (1..=grid.max_row.min(HEADER_SEARCH_ROWS))
+                    .map(move |row| (sheet_idx, grid, row, Self::match_headers(grid, row, template)))
+            })
+            .max_by_key(|(sheet_idx, _, row, columns)| {
+                (columns.len(), std::cmp::Reverse(*sheet_idx), std::cmp::Reverse(*row))
+            })
+            .filter(|(_, _, _, columns)| columns.len() >= MIN_MATCHED_HEADERS)
+            .ok_or_else(|| IoError::ExcelImport("未找到IO点表表头".to_string()))?;
+
+        let missing_columns = template
+            .columns
+            .iter()
+            .filter(|column| !columns.iter().any(|(_, field)| *field == column.field))
+            .map(|column| column.header_text().to_string())
+            .collect();
+        let ignored_columns = (1..=grid.max_col)
+            .filter(|col| !columns.iter().any(|(matched_col, _)| matched_col == col))
+            .filter_map(|col| grid.get(col, header_row))
+            .map(|cell| cell.value.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect();
+
+        let mut table = IOTable::new(table_name);
+        let mut issues = Vec::new();
+        let mut row_numbers = Vec::new();
+        for row in header_row + 1..=grid.max_row {
+            if let Some(io_row) = Self::parse_row(grid, row, &columns, &mut issues) {
+                table.add_row(io_row);
+                row_numbers.push(row);
+            }
+        }
+
+        Ok(IoImportResult {
+            table,
+            sheet_name: grid.name.clone(),
+            issues,
+            missing_columns,
+            ignored_columns,
+            layout: SheetLayout { header_row, row_numbers, columns },
+        })
+    }
+
+    /// 匹配表头行中的列，返回(列号, 字段名)
+    ///
+    /// 先按列模板的表头文字匹配，其次按标准列名匹配，以便读取改用模板前生成的点表；
+    /// 隐藏的点位标识列同样读取
+    fn match_headers(grid: &SheetGrid, row: u32, template: &ColumnTemplate) -> Vec<(u32, String)> {
+        let mut columns: Vec<(u32, String)> = Vec::new();
+        for col in 1..=grid.max_col {
+            let Some(cell) = grid.get(col, row) else {
+                continue;
+            };
+            let text = normalize_header(&cell.value);
+            let field = template
+                .columns
+                .iter()
+                .find(|column| normalize_header(column.header_text()) == text)
+                .map(|column| column.field.as_str())
+                .or_else(|| IO_TABLE_HEADERS.iter().copied().find(|header| normalize_header(header) == text))
+                .or_else(|| (text == POINT_KEY_HEADER).then_some(POINT_KEY_HEADER));
+            // 同名列只取第一列
+            if let Some(field) = field.filter(|field| !columns.iter().any(|(_, f)| f == field)) {
+                columns.push((col, field.to_string()));
+            }
+        }
+        columns
+    }
+
+    /// 解析一行数据，全部为空的行返回None
+    fn parse_row(
+        grid: &SheetGrid,
+        row: u32,
+        columns: &[(u32, String)],
+        issues: &mut Vec<ImportIssue>,
+    ) -> Option<IOTableRow<'static>> {
+        let mut io_row = IOTableRow::default();
+        let mut has_value = false;
+
+        for (col, header) in columns {
+            let mut issue = |value: &str, message: String| issues.push(ImportIssue {
+                row,
+                column: header.to_string(),
+                cell: format!("{}{}", get_column_letter(*col), row),
+                value: value.to_string(),
+                message,
+            });
+
+            let text = match grid.resolve(*col, row, 0) {
+                Ok(text) => text.trim().to_string(),
+                Err(message) => {
+                    let formula = grid.get(*col, row).and_then(|cell| cell.formula.clone()).unwrap_or_default();
+                    issue(&formula, message);
+                    has_value = true;
+                    continue;
+                }
+            };
+            if text.is_empty() {
+                continue;
+            }
+            if header == POINT_KEY_HEADER {
+                io_row.point_key = Some(text);
+                continue;
+            }
+            has_value = true;
+
+            if let Some(message) = Self::check_value(header, &text) {
+                issue(&text, message);
+            }
+            // 不属于标准列的字段保存为自定义列
+            if !io_row.set_field_by_name(header, Cow::Owned(text.clone())) {
+                io_row.extra_fields.insert(header.clone(), Cow::Owned(text));
+            }
+        }
+
+        io_row.is_spare = io_row.tag.as_deref() == Some(SPARE_LABEL);
+        has_value.then_some(io_row)
+    }
+
+    /// 检查单元格内容能否按列的含义解析
+    fn check_value(header: &str, text: &str) -> Option<String> {
+        if text == "/" {
+            return None;
+        }
+        match header {
+            "序号" if text.parse::<u32>().is_err() => Some("序号不是整数".to_string()),
+            "模块类型" if IOChannelType::from_code(text).is_none() => {
+                Some("模块类型应为AI、AO、DI或DO".to_string())
+            }
+            "数据类型" if DataType::from_name(text).is_none() => Some(format!("不支持的数据类型: {}", text)),
+            _ if NUMERIC_COLUMNS.contains(&header) && text.parse::<f64>().is_err() => {
+                Some("不是有效的数值".to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 表头比较时忽略空白，并统一全角、半角括号
+fn normalize_header(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '(' => '（',
+            ')' => '）',
+            _ => c,
+        })
+        .collect()
+}
+
+/// 公式计算结果
+#[derive(Debug, Clone, PartialEq)]
+enum FormulaValue {
+    Blank,
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl FormulaValue {
+    fn into_text(self) -> String {
+        match self {
+            FormulaValue::Blank => String::new(),
+            FormulaValue::Text(text) => text,
+            FormulaValue::Number(number) => number.to_string(),
+            FormulaValue::Bool(value) => if value { "TRUE" } else { "FALSE" }.to_string(),
+        }
+    }
+
+    fn is_true(&self) -> Result<bool, String> {
+        match self {
+            FormulaValue::Bool(value) => Ok(*value),
+            FormulaValue::Number(number) => Ok(*number != 0.0),
+            FormulaValue::Blank => Ok(false),
+            FormulaValue::Text(text) => Err(format!("条件不是布尔值: {}", text)),
+        }
+    }
+}
+
+/// 点表公式计算器
+///
+/// 支持生成点表时写入的公式所用的语法：单元格引用、字符串和数值常量、&连接，
+/// 以及IF、ISBLANK函数
+struct FormulaEvaluator<'g> {
+    grid: &'g SheetGrid,
+    chars: Vec<char>,
+    pos: usize,
+    depth: u32,
+}
+
+impl<'g> FormulaEvaluator<'g> {
+    fn new(grid: &'g SheetGrid, formula: &str, depth: u32) -> Self {
+        let formula = formula.trim().trim_start_matches('=');
+        Self { grid, chars: formula.chars().collect(), pos: 0, depth }
+    }
+
+    fn evaluate(mut self) -> Result<String, String> {
+        let value = self.concat()?;
+        self.skip_spaces();
+        if self.pos < self.chars.len() {
+            return Err(format!("不支持的公式: {}", self.chars.iter().collect::<String>()));
+        }
+        Ok(value.into_text())
+    }
+
+    /// 连接表达式：term (& term)*
+    fn concat(&mut self) -> Result<FormulaValue, String> {
+        let mut value = self.term()?;
+        while self.eat('&') {
+            let right = self.term()?;
+            value = FormulaValue::Text(value.into_text() + &right.into_text());
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<FormulaValue, String> {
+        self.skip_spaces();
+        match self.peek() {
+            Some('"') => self.string(),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => self.number(),
+            Some(c) if c.is_ascii_alphabetic() || c == '$' => self.reference_or_function(),
+            _ => Err(format!("不支持的公式: {}", self.chars.iter().collect::<String>())),
+        }
+    }
+
+    fn string(&mut self) -> Result<FormulaValue, String> {
+        self.pos += 1;
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                // 字符串中的""表示一个引号
+                Some('"') if self.peek() == Some('"') => {
+                    self.pos += 1;
+                    text.push('"');
+                }
+                Some('"') => return Ok(FormulaValue::Text(text)),
+                Some(c) => text.push(c),
+                None => return Err("公式中的字符串缺少结束引号".to_string()),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<FormulaValue, String> {
+        let start = self.pos;
+        self.pos += 1;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse()
+            .map(FormulaValue::Number)
+            .map_err(|_| format!("公式中的数值无效: {}", text))
+    }
+
+    fn reference_or_function(&mut self) -> Result<FormulaValue, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '$') {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().filter(|c| **c != '$').collect();
+
+        self.skip_spaces();
+        if self.eat('(') {
+            return self.function(&name.to_ascii_uppercase());
+        }
+        match name.to_ascii_uppercase().as_str() {
+            "TRUE" => return Ok(FormulaValue::Bool(true)),
+            "FALSE" => return Ok(FormulaValue::Bool(false)),
+            _ => {}
+        }
+
+        let (col, row) = parse_cell_reference(&name).ok_or_else(|| format!("无法识别的单元格引用: {}", name))?;
+        let text = self.grid.resolve(col, row, self.depth)?;
+        if text.is_empty() {
+            return Ok(FormulaValue::Blank);
+        }
+        Ok(text.parse().map(FormulaValue::Number).unwrap_or(FormulaValue::Text(text)))
+    }
+
+    fn function(&mut self, name: &str) -> Result<FormulaValue, String> {
+        let mut args = Vec::new();
+        if !self.eat(')') {
+            loop {
+                args.push(self.concat()?);
+                if self.eat(')') {
+                    break;
+                }
+                if !self.eat(',') {
+                    return Err(format!("函数{}的参数格式错误", name));
+                }
+            }
+        }
+
+        match (name, args.as_slice()) {
+            ("ISBLANK", [value]) => Ok(FormulaValue::Bool(*value == FormulaValue::Blank)),
+            ("IF", [condition, then_value, else_value]) => {
+                Ok(if condition.is_true()? { then_value.clone() } else { else_value.clone() })
+            }
+            ("IF", [condition, then_value]) => {
+                Ok(if condition.is_true()? { then_value.clone() } else { FormulaValue::Bool(false) })
+            }
+            _ => Err(format!("不支持的函数: {}", name)),
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_spaces();
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+}
+
+/// 解析A1形式的单元格引用，返回(列号, 行号)
+pub(crate) fn parse_cell_reference(reference: &str) -> Option<(u32, u32)> {
+    let split = reference.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = reference.split_at(split);
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let col = letters
+        .to_ascii_uppercase()
+        .chars()
+        .fold(0u32, |col, c| col * 26 + (c as u32 - 'A' as u32 + 1));
+    let row = digits.parse().ok()?;
+    Some((col, row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::excel_services::io_excel_services::{EquipmentData, GenerationOptions, IOExcelService};
+    use crate::model_domain::column_template_model::TemplateColumn;
+
+    #[test]
+    fn test_parse_reordered_columns_and_formulas() {
+        let mut grid = SheetGrid { name: "Sheet1".to_string(), ..Default::default() };
+        // 标题行之上有一行说明，列顺序调整并带额外的列
+        grid.set(1, 1, "某站IO点表".to_string(), None);
+        let headers = ["备注", "通道位号", "变量名称(HMI)", "数据类型", "量程高限", "SLL设定点位", "模块类型"];
+        for (i, header) in headers.iter().enumerate() {
+            grid.set(i as u32 + 1, 2, header.to_string(), None);
+        }
+
+        let row = |grid: &mut SheetGrid, r: u32, values: [&str; 7]| {
+            for (i, value) in values.iter().enumerate() {
+                grid.set(i as u32 + 1, r, value.to_string(), None);
+            }
+        };
+        row(&mut grid, 3, ["", "1_2_AI_0", "PT101", "REAL", "1.6", "", "AI"]);
+        row(&mut grid, 4, ["", "1_2_AI_1", "", "REAL", "abc", "", "AI"]);
+        row(&mut grid, 5, ["", "", "", "", "", "", ""]);
+        row(&mut grid, 6, ["", "1_3_DI_0", "XS101", "BYTE", "/", "/", "DI"]);
+
+        // 未保存计算结果的公式按点表公式计算，已有计算结果的直接读取
+        grid.set(6, 3, String::new(), Some("=IF(ISBLANK(C3),\"_LoLoLimit\",C3&\"_LoLoLimit\")".to_string()));
+        grid.set(6, 4, String::new(), Some("=IF(ISBLANK($C4),\"_LoLoLimit\",$C4&\"_LoLoLimit\")".to_string()));
+        grid.set(6, 6, "缓存值".to_string(), Some("=VLOOKUP(C6,A:B,2)".to_string()));
+        grid.set(7, 5, String::new(), Some("=SUM(A1:A2)".to_string()));
+
+        let result = IOExcelReader::parse_workbook(&[grid], "导入".to_string(), &ColumnTemplate::standard()).unwrap();
+        assert_eq!(result.ignored_columns, vec!["备注".to_string()]);
+        assert!(result.missing_columns.contains(&"位号".to_string()));
+        assert!(!result.missing_columns.contains(&"变量名称（HMI）".to_string()));
+
+        let rows = &result.table.rows;
+        assert_eq!(rows.len(), 4);
+        assert_eq!(result.layout.row_numbers, vec![3, 4, 5, 6]);
+        assert_eq!(result.layout.column_number("通道位号"), Some(2));
+        assert_eq!(rows[0].variable_name_hmi.as_deref(), Some("PT101"));
+        assert_eq!(rows[0].sll_setpoint.as_deref(), Some("PT101_LoLoLimit"));
+        assert_eq!(rows[1].sll_setpoint.as_deref(), Some("_LoLoLimit"));
+        assert_eq!(rows[3].sll_setpoint.as_deref(), Some("缓存值"));
+        assert_eq!(rows[0].range_upper_limit.as_deref(), Some("1.6"));
+
+        let issue_cells: Vec<(&str, &str)> = result.issues.iter()
+            .map(|issue| (issue.cell.as_str(), issue.column.as_str()))
+            .collect();
+        assert_eq!(issue_cells, vec![("E4", "量程高限"), ("G5", "模块类型"), ("D6", "数据类型")]);
+        assert_eq!(result.issues[1].value, "=SUM(A1:A2)");
+    }
+
+    #[test]
+    fn test_no_header_found() {
+        let mut grid = SheetGrid::default();
+        grid.set(1, 1, "序号".to_string(), None);
+        assert!(IOExcelReader::parse_workbook(&[grid], String::new(), &ColumnTemplate::standard()).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_column_template() {
+        let template = ColumnTemplate {
+            name: "客户A".to_string(),
+            columns: [("通道位号", "Channel"), ("位号", "Tag"), ("工程单位", ""), ("备注", "")]
+                .iter()
+                .map(|(field, header)| TemplateColumn { field: field.to_string(), header: header.to_string() })
+                .collect(),
+        };
+
+        let mut grid = SheetGrid { name: "Sheet1".to_string(), ..Default::default() };
+        for (i, header) in ["Channel", "Tag", "工程单位", "变量描述"].iter().enumerate() {
+            grid.set(i as u32 + 1, 1, header.to_string(), None);
+        }
+        for (i, value) in ["1_2_AI_0", "PT-101", "MPa", "进站压力"].iter().enumerate() {
+            grid.set(i as u32 + 1, 2, value.to_string(), None);
+        }
+
+        let result = IOExcelReader::parse_workbook(&[grid], "导入".to_string(), &template).unwrap();
+        let row = &result.table.rows[0];
+        assert_eq!(row.channel_tag.as_deref(), Some("1_2_AI_0"));
+        assert_eq!(row.tag.as_deref(), Some("PT-101"));
+        assert_eq!(row.variable_description.as_deref(), Some("进站压力"));
+        assert_eq!(row.get_field_by_name("工程单位").map(|v| v.as_ref()), Some("MPa"));
+        assert_eq!(result.layout.column_number("工程单位"), Some(3));
+        assert_eq!(result.missing_columns, vec!["备注".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_point_key_column() {
+        let mut grid = SheetGrid { name: "Sheet1".to_string(), ..Default::default() };
+        for (i, header) in ["通道位号", "位号", "模块名称", POINT_KEY_HEADER].iter().enumerate() {
+            grid.set(i as u32 + 1, 1, header.to_string(), None);
+        }
+        for (i, value) in ["1_2_AI_0", "PT-101", "进站压力", "进站压力#LK411#1#0"].iter().enumerate() {
+            grid.set(i as u32 + 1, 2, value.to_string(), None);
+        }
+        // 只有点位标识的行不算数据行
+        grid.set(4, 3, "进站压力#LK411#1#1".to_string(), None);
+
+        let result = IOExcelReader::parse_workbook(&[grid], "导入".to_string(), &ColumnTemplate::standard()).unwrap();
+        assert_eq!(result.table.rows.len(), 1);
+        let row = &result.table.rows[0];
+        assert_eq!(row.point_key.as_deref(), Some("进站压力#LK411#1#0"));
+        assert!(row.extra_fields.is_empty());
+        assert!(result.ignored_columns.is_empty());
+    }
+
+    #[test]
+    fn test_read_exported_workbook() {
+        // 列顺序调整、改写表头并追加自定义列
+        let mut columns = ColumnTemplate::standard().columns;
+        columns.rotate_left(5);
+        columns.iter_mut().find(|column| column.field == "位号").unwrap().header = "Tag".to_string();
+        columns.push(TemplateColumn { field: "工程单位".to_string(), header: String::new() });
+        let template = ColumnTemplate { name: "客户A".to_string(), columns };
+
+        let equipment_list = vec![EquipmentData::for_test("进站压力", "LK411", 1)];
+        let mut options = GenerationOptions { column_template: template.clone(), ..Default::default() };
+        let generated = IOExcelService::build_io_table(&equipment_list, "测试站", &options).unwrap().0;
+
+        // 以上次填写的内容合并，使生成的点表中带有变量名称和自定义列的内容
+        let mut previous = IOTable::new("上次点表".to_string());
+        let mut filled = generated.rows[0].clone();
+        filled.tag = Some(Cow::Borrowed("PT-101"));
+        filled.variable_name_hmi = Some(Cow::Borrowed("PT101"));
+        filled.extra_fields.insert("工程单位".to_string(), Cow::Borrowed("MPa"));
+        previous.add_row(filled);
+        options.previous_table = Some(previous);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("测试站_IO点表.xlsx");
+        let report = IOExcelService::export_to_excel(&equipment_list, &path, "测试站", &options).unwrap();
+        let exported = report.io_table.unwrap();
+
+        let result = IOExcelReader::read_file(&path, &template).unwrap();
+        assert!(result.missing_columns.is_empty());
+        assert!(result.ignored_columns.is_empty());
+        assert_eq!(result.layout.column_number("工程单位"), Some(template.columns.len() as u32));
+
+        let rows = &result.table.rows;
+        assert_eq!(rows.len(), exported.rows.len());
+        for (row, expected) in rows.iter().zip(&exported.rows) {
+            assert_eq!(row.point_key, expected.point_key);
+            for field in ["通道位号", "位号", "变量名称（HMI）", "数据类型", "PLC绝对地址", "SLL设定点位_PLC地址"] {
+                assert_eq!(row.get_field_by_name(field), expected.get_field_by_name(field), "{}", field);
+            }
+        }
+
+        // 公式单元格按HMI变量名得到结果，自定义列的内容原样读回
+        assert_eq!(rows[0].tag.as_deref(), Some("PT-101"));
+        assert_eq!(rows[0].sll_setpoint.as_deref(), Some("PT101_LoLoLimit"));
+        assert_eq!(rows[1].sll_setpoint.as_deref(), Some("_LoLoLimit"));
+        assert_eq!(rows[0].get_field_by_name("工程单位").map(|v| v.as_ref()), Some("MPa"));
+    }
+}
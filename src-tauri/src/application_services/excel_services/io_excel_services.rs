@@ -1,29 +1,61 @@
-use crate::model_domain::io_table_model::{IOTable, IOTableRow, IO_TABLE_HEADERS};
+use crate::model_domain::io_table_model::{IOTable, IOTableRow, POINT_KEY_HEADER};
+use crate::model_domain::address_plan_model::AddressPlan;
+use crate::model_domain::address_lock_model::{AddressLock, MovedPoint};
+use crate::model_domain::modbus_profile_model::ModbusPoint;
+use crate::model_domain::generation_report_model::GenerationReport;
+use crate::model_domain::revision_model::{DocumentInfo, RevisionHistory};
+use crate::model_domain::validation_model::ValidationRuleSet;
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::application_services::address_services::address_allocator::AddressAllocator;
+use crate::application_services::address_services::iec104_allocator::Iec104Allocator;
+use crate::application_services::address_services::dnp3_allocator::Dnp3Allocator;
+use crate::application_services::excel_services::status_word_sheet::{StatusWordSheetService, STATUS_WORD_SHEET_SUFFIX};
+use crate::application_services::excel_services::iec104_sheet::{Iec104SheetService, IEC104_SHEET_SUFFIX};
+use crate::application_services::excel_services::dnp3_sheet::{Dnp3SheetService, DNP3_SHEET_SUFFIX};
+use crate::application_services::excel_services::modbus_sheet::{ModbusSheetService, MODBUS_SHEET_SUFFIX};
+use crate::application_services::excel_services::cover_sheet::CoverSheetService;
+use crate::application_services::excel_services::summary_sheet::{SummarySheetService, SUMMARY_SHEET_SUFFIX};
+use crate::application_services::excel_services::module_layout_sheet::{ModuleLayoutSheetService, MODULE_LAYOUT_SHEET_SUFFIX};
+use crate::application_services::excel_services::type_point_sheet::TypePointSheetService;
+use crate::application_services::excel_services::input_protection::InputProtectionService;
+use crate::application_services::excel_services::conditional_format::ConditionalFormatService;
+use crate::application_services::io_planning_services::placement_strategy::{
+    ModulePlacementService, PlacedModule, PlacementStrategy, SlotPosition
+};
+use crate::application_services::io_planning_services::spare_policy::SparePolicy;
+use crate::application_services::io_planning_services::table_merge_service::TableMergeService;
 use std::path::Path;
-use umya_spreadsheet::{Worksheet, Style, Border, Color};
+use umya_spreadsheet::{Spreadsheet, Worksheet, Style, Border, Color};
 use umya_spreadsheet::writer::xlsx::write;
 use umya_spreadsheet::XlsxError;
 use std::collections::HashMap;
 use std::borrow::Cow;
 use once_cell::sync::Lazy;
 use thiserror::Error;
+use serde::{Deserialize, Serialize};
 use serde_json;
 use umya_spreadsheet::structs::HorizontalAlignmentValues;
 use std::fmt;
 
 // 常量定义，替代魔术数字
-/// 模拟量地址起始值（MD）
-const REAL_ADDR_START: u32 = 320;
-/// 布尔量地址起始值（MX）
-const BOOL_ADDR_START: (u32, u32) = (20, 0);
 /// 槽位起始值（跳过第一个槽位，用于通信模块）
-const START_SLOT: u32 = 2;
+pub const START_SLOT: u32 = 2;
 /// 每个机架可用的槽位数
-const AVAILABLE_SLOTS_PER_RACK: u32 = 10;
-/// 布尔量每字节位数
-const BOOL_BITS_PER_BYTE: u32 = 8;
-/// REAL类型每点位占用字节数
-const REAL_BYTES_PER_POINT: u32 = 4;
+pub const AVAILABLE_SLOTS_PER_RACK: u32 = 10;
+/// 待订购机架行的背景色
+const VIRTUAL_RACK_COLOR: &str = "FFFFC7CE";
+/// 备用通道行的背景色
+const SPARE_ROW_COLOR: &str = "FFD9D9D9";
+/// 合并模式下新增通道行的背景色
+const NEW_CHANNEL_COLOR: &str = "FFC6EFCE";
+/// 合并模式下已移除通道行的背景色
+const REMOVED_CHANNEL_COLOR: &str = "FFF4B084";
+/// 已移除通道工作表名称后缀
+const REMOVED_CHANNEL_SHEET_SUFFIX: &str = "已移除通道";
+/// 主点表名称后缀
+pub const MAIN_SHEET_SUFFIX: &str = "IO点表";
+/// Excel工作表名称的最大长度
+const MAX_SHEET_NAME_CHARS: usize = 31;
 
 /// IO错误类型
 #[derive(Error, Debug)]
@@ -31,12 +63,30 @@ pub enum IoError {
     #[error("IO模块数量超出了可用机架数量，当前机架数 {rack_count}，需要机架数 {required_rack}")]
     SlotOverflow { rack_count: u32, required_rack: u32 },
 
+    #[error("模块排布错误: {0}")]
+    InvalidPlacement(String),
+
+    #[error("备用策略错误: {0}")]
+    InvalidSparePolicy(String),
+
+    #[error("地址规划错误: {0}")]
+    AddressPlan(String),
+
+    #[error("{area} 地址已用尽，已使用 {ranges}，请扩大地址范围或配置备用地址区")]
+    AddressExhausted { area: String, ranges: String },
+
+    #[error("命名规则错误: {0}")]
+    NamingRule(String),
+
     #[error("PLC地址解析错误: {0}")]
     AddrParse(String),
 
     #[error("Excel导出错误: {0}")]
     ExcelExport(#[from] XlsxError),
 
+    #[error("Excel导入错误: {0}")]
+    ExcelImport(String),
+
     #[error("数据查询错误: {0}")]
     DataQuery(String),
 
@@ -45,7 +95,7 @@ pub enum IoError {
 }
 
 /// IO通道类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IOChannelType {
     AI,
     AO,
@@ -53,6 +103,19 @@ pub enum IOChannelType {
     DO,
 }
 
+impl IOChannelType {
+    /// 从模块类型文本（AI/AO/DI/DO）解析通道类型
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "AI" => Some(IOChannelType::AI),
+            "AO" => Some(IOChannelType::AO),
+            "DI" => Some(IOChannelType::DI),
+            "DO" => Some(IOChannelType::DO),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for IOChannelType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -64,37 +127,212 @@ impl fmt::Display for IOChannelType {
     }
 }
 
+/// STRING类型固定分配的字节数
+pub const STRING_BYTES: u32 = 32;
+
 /// 数据类型
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DataType {
     REAL,
     BOOL,
+    INT,
+    DINT,
+    UINT,
+    WORD,
+    DWORD,
+    LREAL,
+    STRING,
 }
 
-impl fmt::Display for DataType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl DataType {
+    /// 按类型名称解析，不区分大小写
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "REAL" => Some(DataType::REAL),
+            "BOOL" => Some(DataType::BOOL),
+            "INT" => Some(DataType::INT),
+            "DINT" => Some(DataType::DINT),
+            "UINT" => Some(DataType::UINT),
+            "WORD" => Some(DataType::WORD),
+            "DWORD" => Some(DataType::DWORD),
+            "LREAL" => Some(DataType::LREAL),
+            "STRING" => Some(DataType::STRING),
+            _ => None,
+        }
+    }
+
+    /// 类型名称
+    pub fn as_str(&self) -> &'static str {
         match self {
-            DataType::REAL => write!(f, "REAL"),
-            DataType::BOOL => write!(f, "BOOL"),
+            DataType::REAL => "REAL",
+            DataType::BOOL => "BOOL",
+            DataType::INT => "INT",
+            DataType::DINT => "DINT",
+            DataType::UINT => "UINT",
+            DataType::WORD => "WORD",
+            DataType::DWORD => "DWORD",
+            DataType::LREAL => "LREAL",
+            DataType::STRING => "STRING",
         }
     }
+
+    /// 占用字节数，BOOL按位分配返回0
+    pub fn byte_size(&self) -> u32 {
+        match self {
+            DataType::BOOL => 0,
+            DataType::INT | DataType::UINT | DataType::WORD => 2,
+            DataType::REAL | DataType::DINT | DataType::DWORD => 4,
+            DataType::LREAL => 8,
+            DataType::STRING => STRING_BYTES,
+        }
+    }
+
+    /// 是否为按位分配的类型
+    pub fn is_bit(&self) -> bool {
+        *self == DataType::BOOL
+    }
+
+    /// 是否为跨多个寄存器的数值，需要按字序读取
+    pub fn is_multi_word(&self) -> bool {
+        matches!(self, DataType::REAL | DataType::DINT | DataType::DWORD | DataType::LREAL)
+    }
+
+    /// 是否有量程（可填写量程上下限）
+    pub fn has_range(&self) -> bool {
+        matches!(self, DataType::REAL | DataType::LREAL | DataType::INT | DataType::DINT | DataType::UINT)
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// 设备信息
-#[derive(Debug, Clone)]
+///
+/// 序列化字段名与前端设备项保持一致，可直接回传用于生成点表
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EquipmentData {
+    #[serde(rename = "name")]
     pub equipment_name: String,
+    #[serde(rename = "model")]
     pub spec_model: String,
     pub quantity: u32,
     pub station_name: String,
+    /// 子系统
+    #[serde(default)]
+    pub subsystem: String,
+    /// 是否为冗余配置（数量按实际模块数计，每两块组成一对）
+    #[serde(default)]
+    pub redundant: bool,
     // 可以添加其他设备相关字段
 }
 
+#[cfg(test)]
+impl EquipmentData {
+    /// 测试用设备：场站为"测试站"，不分子系统，非冗余
+    pub(crate) fn for_test(name: &str, model: &str, quantity: u32) -> Self {
+        Self {
+            equipment_name: name.to_string(),
+            spec_model: model.to_string(),
+            quantity,
+            station_name: "测试站".to_string(),
+            subsystem: String::new(),
+            redundant: false,
+        }
+    }
+}
+
+/// 点表生成选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GenerationOptions {
+    /// 模块超出已有机架时是否继续生成，超出部分放入"待订购"虚拟机架
+    pub allow_virtual_racks: bool,
+    /// 模块排布策略
+    pub placement_strategy: PlacementStrategy,
+    /// 指定模块的固定槽位，键为"设备名称#规格型号#序号"（例如：进站压力#LK411#2）
+    pub slot_overrides: HashMap<String, SlotPosition>,
+    /// 备用通道策略
+    pub spare_policy: SparePolicy,
+    /// 各类型的信号数，用于按备用策略追加备用模块；未给出的类型视为已安装通道全部接有信号
+    pub signal_counts: HashMap<IOChannelType, u32>,
+    /// PLC地址规划，为空时使用项目保存的地址规划，未指定项目时使用默认规划
+    pub address_plan: Option<AddressPlan>,
+    /// 是否放弃上次锁定的地址，全部重新分配
+    pub reset_address_lock: bool,
+    /// 上次生成时锁定的地址，由命令层从本地存储读取
+    #[serde(skip)]
+    pub address_lock: Option<AddressLock>,
+    /// 不对应硬件通道的软点位（计数器、模式字、累计量、通讯模块数据等）
+    pub soft_points: Vec<SoftPoint>,
+    /// 合并模式：上次填写过的点表文件路径，重新生成时沿用其中工程师填写的内容
+    pub previous_table_path: Option<String>,
+    /// 上次填写过的点表，由命令层按previous_table_path读取
+    #[serde(skip)]
+    pub previous_table: Option<IOTable<'static>>,
+    /// 保护点表工作表的密码，为空时只保护不设密码
+    pub protection_password: Option<String>,
+    /// 点表列模板名称，为空时使用项目选择的模板，项目未选择时使用标准模板
+    pub column_template_name: Option<String>,
+    /// 点表列模板，由命令层按column_template_name读取
+    #[serde(skip)]
+    pub column_template: ColumnTemplate,
+    /// 点表中条件格式使用的校验规则，未启用的规则不添加
+    pub validation_rules: ValidationRuleSet,
+    /// 是否按AI/AO/DI/DO分别生成只含相关列的点位表
+    pub per_type_sheets: bool,
+    /// 文档信息，填写后生成封面及修订记录，并标记与上一版相比变化的单元格
+    pub document: Option<DocumentInfo>,
+    /// 场站的修订历史，由命令层从本地存储读取
+    #[serde(skip)]
+    pub revision_history: RevisionHistory,
+    /// 上一版点表，由命令层从本地存储读取
+    #[serde(skip)]
+    pub previous_revision: Option<IOTable<'static>>,
+}
+
+/// 未指定地址规划时使用的默认规划
+static DEFAULT_ADDRESS_PLAN: Lazy<AddressPlan> = Lazy::new(AddressPlan::default);
+
+impl GenerationOptions {
+    /// 本次生成使用的地址规划
+    pub fn address_plan(&self) -> &AddressPlan {
+        self.address_plan.as_ref().unwrap_or(&DEFAULT_ADDRESS_PLAN)
+    }
+}
+
+/// 软点位定义
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoftPoint {
+    /// 位号
+    pub tag: String,
+    /// 变量描述
+    pub description: String,
+    /// 数据类型
+    pub data_type: DataType,
+    /// 数据来源（例如通讯模块名称），为空时记为"软点位"
+    #[serde(default)]
+    pub source: String,
+    /// 所属场站
+    #[serde(default)]
+    pub station_name: String,
+}
+
 /// 通道数据统计结果
 #[derive(Debug, Clone, Default)]
 pub struct ChannelTotal {
+    /// 已安装通道数
     pub count: u32,
     pub data_type: String,
+    /// 接有信号的通道数
+    pub used: u32,
+    /// 已安装通道中未接信号的备用通道数
+    pub spare: u32,
+    /// 满足备用策略需追加的模块数
+    pub extra_modules: u32,
 }
 
 /// 需要使用BOOL类型地址的字段前缀
@@ -129,6 +367,8 @@ pub struct ModelChannelMapping {
     pub channel_type: IOChannelType,
     pub channels: u32,
     pub data_type: DataType,
+    /// 是否支持冗余配对（两块模块占用相邻槽位，通道一一对应）
+    pub supports_redundancy: bool,
 }
 
 /// 静态存储的设备型号映射表
@@ -139,28 +379,61 @@ pub static MODEL_MAPPINGS: Lazy<Vec<ModelChannelMapping>> = Lazy::new(|| {
             channel_type: IOChannelType::DI,
             channels: 16,
             data_type: DataType::BOOL,
+            supports_redundancy: false,
         },
         ModelChannelMapping {
             model_key: "LK710".to_string(),
             channel_type: IOChannelType::DO,
             channels: 16,
             data_type: DataType::BOOL,
+            supports_redundancy: true,
         },
         ModelChannelMapping {
             model_key: "LK411".to_string(),
             channel_type: IOChannelType::AI,
             channels: 8,
             data_type: DataType::REAL,
+            supports_redundancy: true,
         },
         ModelChannelMapping {
             model_key: "LK512".to_string(),
             channel_type: IOChannelType::AO,
             channels: 8,
             data_type: DataType::REAL,
+            supports_redundancy: false,
+        },
+    ]
+});
+
+/// 控制器（CPU）型号
+#[derive(Debug, Clone)]
+pub struct ControllerModel {
+    pub model_key: String,
+    /// 是否支持冗余配置（主备CPU），冗余时备用CPU占用1号机架的第一个IO槽位
+    pub supports_redundancy: bool,
+}
+
+/// 静态存储的控制器型号表
+pub static CONTROLLER_MODELS: Lazy<Vec<ControllerModel>> = Lazy::new(|| {
+    vec![
+        ControllerModel {
+            model_key: "LK207".to_string(),
+            supports_redundancy: true,
+        },
+        ControllerModel {
+            model_key: "LK210".to_string(),
+            supports_redundancy: true,
+        },
+        ControllerModel {
+            model_key: "LK220".to_string(),
+            supports_redundancy: true,
         },
     ]
 });
 
+/// 冗余配置关键字，出现在设备名称或技术参数中时视为冗余配置
+const REDUNDANCY_KEYWORD: &str = "冗余";
+
 /// 将前端设备项转换为内部设备数据结构
 pub fn convert_equipment_items(equipment_items: Vec<serde_json::Value>) -> Vec<EquipmentData> {
     equipment_items.into_iter()
@@ -169,12 +442,27 @@ pub fn convert_equipment_items(equipment_items: Vec<serde_json::Value>) -> Vec<E
             let model = item.get("model")?.as_str()?.to_string();
             let quantity = item.get("quantity")?.as_f64()? as u32;
             let station_name = item.get("station_name")?.as_str()?.to_string();
+            // 子系统为可选字段
+            let subsystem = item.get("subsystem")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+
+            // 冗余标记优先取显式字段，否则根据名称和技术参数判断
+            let redundant = item.get("redundant")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_else(|| {
+                    let tech_param = item.get("tech_param").and_then(|v| v.as_str()).unwrap_or_default();
+                    name.contains(REDUNDANCY_KEYWORD) || tech_param.contains(REDUNDANCY_KEYWORD)
+                });
 
             Some(EquipmentData {
                 equipment_name: name,
                 spec_model: model,
                 quantity,
                 station_name,
+                subsystem,
+                redundant,
             })
         })
         .collect()
@@ -189,22 +477,64 @@ impl IOExcelService {
         &MODEL_MAPPINGS
     }
 
+    /// 根据规格型号查找对应的通道映射
+    pub fn find_model_mapping(spec_model: &str) -> Option<&'static ModelChannelMapping> {
+        Self::get_model_channel_mapping()
+            .iter()
+            .find(|model| spec_model.contains(&model.model_key))
+    }
+
+    /// 硬件目录中该类型的首选模块（目录中该类型的第一个型号）
+    pub fn default_mapping_for(channel_type: IOChannelType) -> Option<&'static ModelChannelMapping> {
+        Self::get_model_channel_mapping()
+            .iter()
+            .find(|mapping| mapping.channel_type == channel_type)
+    }
+
+    /// 根据规格型号查找对应的控制器型号
+    pub fn find_controller_model(spec_model: &str) -> Option<&'static ControllerModel> {
+        CONTROLLER_MODELS
+            .iter()
+            .find(|model| spec_model.contains(&model.model_key))
+    }
+
+    /// 设备清单中的冗余控制器，型号不支持冗余时按单CPU处理
+    pub fn redundant_controller(equipment_list: &[EquipmentData]) -> Option<&EquipmentData> {
+        equipment_list.iter().find(|equipment| {
+            equipment.redundant
+                && Self::find_controller_model(&equipment.spec_model)
+                    .is_some_and(|model| model.supports_redundancy)
+        })
+    }
+
+    /// 设备清单中是否包含冗余控制器
+    pub fn has_redundant_controller(equipment_list: &[EquipmentData]) -> bool {
+        Self::redundant_controller(equipment_list).is_some()
+    }
+
     /// 判断字段是否应该使用BOOL类型地址
     pub fn is_bool_address_field(field_name: &str) -> bool {
         BOOL_TYPE_ADDRESS_FIELDS.iter().any(|prefix| field_name.starts_with(prefix))
     }
 
     /// 根据设备清单计算各类型通道总数及数据类型
-    pub fn calculate_channels(equipment_list: &[EquipmentData]) -> HashMap<String, ChannelTotal> {
+    ///
+    /// 冗余模块对只计一组通道；signal_counts为各类型的信号数，未给出的类型视为已安装通道全部接有信号。
+    /// 备用通道不满足备用策略时，按该类型最后一种模块型号计算需追加的模块数
+    pub fn calculate_channels(
+        equipment_list: &[EquipmentData],
+        spare_policy: &SparePolicy,
+        signal_counts: &HashMap<IOChannelType, u32>
+    ) -> Result<HashMap<String, ChannelTotal>, IoError> {
         // 初始化结果
         let mut channel_totals = HashMap::new();
-        channel_totals.insert("AI".to_string(), ChannelTotal { count: 0, data_type: "REAL".to_string() });
-        channel_totals.insert("AO".to_string(), ChannelTotal { count: 0, data_type: "REAL".to_string() });
-        channel_totals.insert("DI".to_string(), ChannelTotal { count: 0, data_type: "BOOL".to_string() });
-        channel_totals.insert("DO".to_string(), ChannelTotal { count: 0, data_type: "BOOL".to_string() });
+        channel_totals.insert("AI".to_string(), ChannelTotal { data_type: "REAL".to_string(), ..Default::default() });
+        channel_totals.insert("AO".to_string(), ChannelTotal { data_type: "REAL".to_string(), ..Default::default() });
+        channel_totals.insert("DI".to_string(), ChannelTotal { data_type: "BOOL".to_string(), ..Default::default() });
+        channel_totals.insert("DO".to_string(), ChannelTotal { data_type: "BOOL".to_string(), ..Default::default() });
 
-        // 获取设备型号映射
-        let model_channel_mapping = Self::get_model_channel_mapping();
+        // 各类型追加模块使用的每模块通道数
+        let mut module_channels: HashMap<IOChannelType, u32> = HashMap::new();
 
         // 处理每个设备
         for equipment in equipment_list {
@@ -221,321 +551,368 @@ impl IOExcelService {
             }
 
             // 查找匹配的设备型号
-            for model in model_channel_mapping.iter() {
-                if spec_model.contains(&model.model_key) {
-                    let channel_type = model.channel_type.to_string();
-                    let channel_count = model.channels;
-
-                    // 计算总通道数并添加到对应类型
-                    let total_channels = quantity * channel_count;
-                    if let Some(total) = channel_totals.get_mut(&channel_type) {
-                        total.count += total_channels;
-                    }
-                    break;
+            if let Some(model) = Self::find_model_mapping(spec_model) {
+                // 计算总通道数并添加到对应类型，冗余设备数量为奇数时与模块排布一样报错
+                let module_count = ModulePlacementService::instance_count(equipment, model)?;
+                let total_channels = module_count * model.channels;
+                if let Some(total) = channel_totals.get_mut(&model.channel_type.to_string()) {
+                    total.count += total_channels;
                 }
+                module_channels.insert(model.channel_type, model.channels);
             }
         }
 
-        channel_totals
+        // 计算已用、备用通道数及需追加的模块数
+        for (channel_type, total) in channel_totals.iter_mut() {
+            let Some(io_type) = IOChannelType::from_code(channel_type) else {
+                continue;
+            };
+            total.used = signal_counts.get(&io_type).copied().unwrap_or(total.count);
+            total.spare = total.count.saturating_sub(total.used);
+
+            let channels_per_module = module_channels.get(&io_type).copied()
+                .or_else(|| Self::default_mapping_for(io_type).map(|mapping| mapping.channels))
+                .unwrap_or(0);
+            total.extra_modules = spare_policy.extra_modules(io_type, total.used, total.count, channels_per_module)?;
+        }
+
+        Ok(channel_totals)
     }
 
     /// 根据PLC绝对地址计算上位机通讯地址
     ///
-    /// 计算方法:
+    /// 计算方法（基址、地址区和编号方式由地址规划决定，以下为默认值）:
     /// - REAL类型: (MD地址数字部分/2) + 43001
     /// - BOOL类型: (MX地址主数字*8) + 位数字 + 3001
-    pub fn calculate_modbus_address(plc_address: &str, data_type: DataType) -> Result<u32, IoError> {
-        match data_type {
-            DataType::REAL => Self::calculate_real_modbus_address(plc_address),
-            DataType::BOOL => Self::calculate_bool_modbus_address(plc_address),
-        }
+    pub fn calculate_modbus_address(
+        plan: &AddressPlan,
+        plc_address: &str,
+        data_type: DataType
+    ) -> Result<u32, IoError> {
+        Ok(Self::modbus_point(plan, plc_address, data_type)?.address)
     }
 
-    /// 计算REAL类型的Modbus地址
-    fn calculate_real_modbus_address(plc_address: &str) -> Result<u32, IoError> {
-        // 对于REAL类型：=(MID(AE2,4,4)/2)+43001
-        // 从%MD100中提取100，然后计算
-        if plc_address.len() < 4 {
-            return Err(IoError::AddrParse(format!("无效的PLC REAL地址: {}", plc_address)));
-        }
-
-        let md_num = plc_address[3..].parse::<u32>()
-            .map_err(|e| IoError::AddrParse(format!("REAL地址解析错误: {}", e)))?;
-
-        Ok((md_num / 2) + 43001)
-    }
-
-    /// 计算BOOL类型的Modbus地址
-    fn calculate_bool_modbus_address(plc_address: &str) -> Result<u32, IoError> {
-        // 对于BOOL类型：=(MID(AE3,4,2)*8)+RIGHT(AE3,1)+3001
-        // 从%MX20.0中提取20和0，然后计算
-        if plc_address.len() < 4 {
-            return Err(IoError::AddrParse(format!("无效的PLC BOOL地址: {}", plc_address)));
-        }
-
-        let parts: Vec<&str> = plc_address[3..].split('.').collect();
-        if parts.is_empty() {
-            return Err(IoError::AddrParse(format!("BOOL地址格式错误: {}", plc_address)));
-        }
-
-        let mx_num = parts[0].parse::<u32>()
-            .map_err(|e| IoError::AddrParse(format!("BOOL地址MX部分解析错误: {}", e)))?;
-
-        let bit_num = if parts.len() > 1 {
-            parts[1].parse::<u32>()
-                .map_err(|e| IoError::AddrParse(format!("BOOL地址bit部分解析错误: {}", e)))?
-        } else { 0 };
-
-        Ok((mx_num * BOOL_BITS_PER_BYTE) + bit_num + 3001)
-    }
-
-    /// 生成PLC地址
-    fn generate_plc_address(data_type: DataType, counter: &mut u32, bool_counter: &mut (u32, u32)) -> String {
+    /// 根据PLC绝对地址计算Modbus映射（功能区、协议偏移、通讯地址及字序）
+    pub fn modbus_point(
+        plan: &AddressPlan,
+        plc_address: &str,
+        data_type: DataType
+    ) -> Result<ModbusPoint, IoError> {
         match data_type {
-            DataType::REAL => {
-                // REAL类型使用%MD地址
-                let addr = format!("%MD{}", *counter);
-                *counter += REAL_BYTES_PER_POINT;
-                addr
-            },
             DataType::BOOL => {
-                // BOOL类型使用%MX地址
-                let addr = format!("%MX{}.{}", bool_counter.0, bool_counter.1);
-
-                // 更新BOOL地址计数器
-                bool_counter.1 += 1;
-                if bool_counter.1 > 7 {
-                    bool_counter.0 += 1;
-                    bool_counter.1 = 0;
-                }
-
-                addr
+                // 对于BOOL类型：=(MID(AE3,4,2)*8)+RIGHT(AE3,1)+3001
+                let bool_addr = plan.parse_bool(plc_address).map_err(IoError::AddrParse)?;
+                Ok(plan.bool_modbus_point(bool_addr))
+            },
+            _ => {
+                // 对于REAL等寄存器类型：=(MID(AE2,4,4)/2)+43001
+                let offset = plan.parse_register(plc_address, data_type.byte_size()).map_err(IoError::AddrParse)?;
+                Ok(plan.register_modbus_point(offset, Self::byte_size(plan, data_type), data_type.is_multi_word()))
             }
         }
     }
 
-    /// 生成PLC和通信地址
-    fn generate_addresses(
-        data_type: DataType,
-        real_counter: &mut u32,
-        bool_counter: &mut (u32, u32)
-    ) -> Result<(String, u32), IoError> {
-        let plc_addr = IOExcelService::generate_plc_address(data_type, real_counter, bool_counter);
-        let modbus = IOExcelService::calculate_modbus_address(&plc_addr, data_type)?;
-        Ok((plc_addr, modbus))
+    /// 按地址规划确定数据类型占用的字节数，REAL按规划中的步长分配
+    pub fn byte_size(plan: &AddressPlan, data_type: DataType) -> u32 {
+        match data_type {
+            DataType::REAL => plan.real_stride.max(1),
+            _ => data_type.byte_size(),
+        }
     }
 
     /// 将IO通道数据导出到Excel
     pub fn export_to_excel(
         equipment_list: &[EquipmentData],
         output_path: &Path,
-        station_name: &str
-    ) -> Result<(), IoError> {
+        station_name: &str,
+        options: &GenerationOptions
+    ) -> Result<GenerationReport, IoError> {
         // 创建新的电子表格
         let mut spreadsheet = umya_spreadsheet::new_file();
         let worksheet = spreadsheet.get_active_sheet_mut();
 
-        // 准备IO点表数据
-        let io_points = Self::prepare_io_points(equipment_list)?;
+        // 主点表以场站命名，分类点位表按名称引用主点表
+        let main_sheet_name = station_sheet_name(station_name, MAIN_SHEET_SUFFIX);
+        worksheet.set_name(main_sheet_name.clone());
 
-        // 创建IO表
-        let mut io_table = IOTable::new(format!("{}_IO表", station_name));
-        for point in io_points {
-            io_table.add_row(point);
-        }
+        // 准备IO点表数据，全部地址在此统一分配
+        let (io_table, mut report, placed_modules) = Self::build_io_table(equipment_list, station_name, options)?;
+
+        // 主点表的列按列模板输出
+        let template = &options.column_template;
 
         // 写入表头
-        Self::write_headers(worksheet);
+        Self::write_headers(worksheet, template);
 
         // 写入数据、设置公式和占位符
-        Self::write_data_and_formulas(worksheet, &io_table)?;
+        Self::write_data_and_formulas(worksheet, &io_table, template)?;
 
         // 调整列宽 - 自动适应内容
-        Self::adjust_column_widths(worksheet, &io_table);
+        Self::adjust_column_widths(worksheet, &io_table, template);
+
+        // 添加下拉选项和数值校验，并保护工作表，只有待填写的单元格可以修改
+        InputProtectionService::apply(worksheet, io_table.len(), options.protection_password.as_deref(), template);
+
+        // 添加与工程校验一致的条件格式，离线修改时问题单元格直接标色
+        ConditionalFormatService::apply(worksheet, io_table.len(), &options.validation_rules, template);
+
+        // 汇总表：各类型通道数、备用及利用率
+        let summary_sheet_name = station_sheet_name(station_name, SUMMARY_SHEET_SUFFIX);
+        SummarySheetService::write_sheet(&mut spreadsheet, &summary_sheet_name, &io_table, &report)?;
+
+        // 模块布置表：按机架、槽位列出生成点表时排布的模块
+        let rack_count = Self::get_rack_count(equipment_list);
+        let layout_sheet_name = station_sheet_name(station_name, MODULE_LAYOUT_SHEET_SUFFIX);
+        ModuleLayoutSheetService::write_sheet(
+            &mut spreadsheet,
+            &layout_sheet_name,
+            &placed_modules,
+            Self::redundant_controller(equipment_list),
+            rack_count,
+        )?;
+
+        // 按通道类型分别生成只含相关列的点位表
+        if options.per_type_sheets {
+            for channel_type in [IOChannelType::AI, IOChannelType::AO, IOChannelType::DI, IOChannelType::DO] {
+                let sheet_name = station_sheet_name(station_name, &channel_type.to_string());
+                TypePointSheetService::write_sheet(
+                    &mut spreadsheet,
+                    &sheet_name,
+                    &main_sheet_name,
+                    &io_table,
+                    template,
+                    channel_type,
+                )?;
+            }
+        }
+
+        // 合并模式下将已移除的通道列入单独的工作表
+        if let Some(merge) = report.merge.as_ref().filter(|merge| !merge.removed_rows.is_empty()) {
+            let sheet_name = station_sheet_name(station_name, REMOVED_CHANNEL_SHEET_SUFFIX);
+            Self::write_removed_channel_sheet(&mut spreadsheet, &sheet_name, &merge.removed_rows, template)?;
+        }
+
+        // Modbus映射表：逐个列出主点位和附加点位的功能区、偏移及字序
+        let modbus_sheet_name = station_sheet_name(station_name, MODBUS_SHEET_SUFFIX);
+        ModbusSheetService::write_sheet(&mut spreadsheet, &modbus_sheet_name, &io_table.rows, options.address_plan())?;
+
+        // 报警位打包到状态字时，生成状态字映射表
+        if options.address_plan().modbus.pack_alarm_bits {
+            let sheet_name = station_sheet_name(station_name, STATUS_WORD_SHEET_SUFFIX);
+            StatusWordSheetService::write_sheet(&mut spreadsheet, &sheet_name, &io_table, options.address_plan())?;
+        }
+
+        // 启用IEC 104时，为每个点位及附加点位分配IOA并生成信息体地址表
+        if options.address_plan().iec104.enabled {
+            let points = Iec104Allocator::new(&options.address_plan().iec104)?.allocate_rows(&io_table.rows)?;
+            let sheet_name = station_sheet_name(station_name, IEC104_SHEET_SUFFIX);
+            Iec104SheetService::write_sheet(&mut spreadsheet, &sheet_name, &points)?;
+        }
+
+        // 启用DNP3时生成DNP3点表
+        if options.address_plan().dnp3.enabled {
+            let points = Dnp3Allocator::new(&options.address_plan().dnp3)?.allocate_rows(&io_table.rows)?;
+            let sheet_name = station_sheet_name(station_name, DNP3_SHEET_SUFFIX);
+            Dnp3SheetService::write_sheet(&mut spreadsheet, &sheet_name, &points)?;
+        }
+
+        // 填写文档信息时生成封面和修订记录，并标记与上一版相比变化的单元格
+        if let Some(document) = &options.document {
+            let revision = CoverSheetService::write_revision(
+                &mut spreadsheet,
+                &io_table,
+                template,
+                station_name,
+                document,
+                &options.revision_history,
+                options.previous_revision.as_ref(),
+            )?;
+            report.revision = Some(revision);
+        }
 
         // 保存Excel
         write(&spreadsheet, output_path)?;
 
-        Ok(())
+        report.io_table = Some(io_table);
+        Ok(report)
     }
 
-    /// 更新机架和槽位，检查是否超出可用机架数
-    fn update_rack_and_slot(
-        current_rack: &mut u32,
-        current_slot: &mut u32,
-        rack_count: u32
-    ) -> Result<(), IoError> {
-        // 检查是否需要切换到下一个机架
-        if *current_slot > AVAILABLE_SLOTS_PER_RACK + 1 {
-            *current_rack += 1;
-            *current_slot = START_SLOT; // 重置为起始槽位（通常为2）
-
-            // 检查是否超出机架数量
-            if *current_rack > rack_count {
-                return Err(IoError::SlotOverflow {
-                    rack_count,
-                    required_rack: *current_rack
-                });
-            }
-        }
+    /// 生成场站的IO点表，供Excel及各协议导出共用，同时返回模块的排布结果
+    pub fn build_io_table<'a>(
+        equipment_list: &'a [EquipmentData],
+        station_name: &str,
+        options: &GenerationOptions
+    ) -> Result<(IOTable<'static>, GenerationReport, Vec<PlacedModule<'a>>), IoError> {
+        let (io_points, report, placed_modules) = Self::prepare_io_points(equipment_list, options)?;
 
-        Ok(())
+        let mut io_table = IOTable::new(format!("{}_IO表", station_name));
+        for point in io_points {
+            io_table.add_row(point);
+        }
+        Ok((io_table, report, placed_modules))
     }
 
     /// 准备IO点表数据
-    fn prepare_io_points(equipment_list: &[EquipmentData]) -> Result<Vec<IOTableRow<'static>>, IoError> {
+    ///
+    /// 所有PLC地址及通讯地址（包括附加点位地址）都在这里分配并保存到点表行中，
+    /// 同时返回地址区使用情况及模块的排布结果（模块布置表沿用，不再重新排布）
+    pub fn prepare_io_points<'a>(
+        equipment_list: &'a [EquipmentData],
+        options: &GenerationOptions
+    ) -> Result<(Vec<IOTableRow<'static>>, GenerationReport, Vec<PlacedModule<'a>>), IoError> {
         // 预估总通道数量，避免频繁扩容
         let estimated_channels = equipment_list.iter()
             .map(|e| e.quantity as usize * 16) // 假设每个设备最多16通道
             .sum();
         let mut io_points = Vec::with_capacity(estimated_channels);
 
-        // 通道计数器（用于生成通道位号）
-        let mut module_counters = HashMap::new();
-        module_counters.insert(IOChannelType::AI, 1);
-        module_counters.insert(IOChannelType::AO, 1);
-        module_counters.insert(IOChannelType::DI, 1);
-        module_counters.insert(IOChannelType::DO, 1);
+        // 各点位的地址类型（主地址数据类型, 是否带附加点位地址）
+        let mut address_specs = Vec::with_capacity(estimated_channels);
 
         // 序号计数器
         let mut index_counter = 1;
 
-        // PLC地址计数器
-        let mut real_address_counter = REAL_ADDR_START; // %MD320开始
-        let mut bool_address_counter = BOOL_ADDR_START; // %MX20.0开始，范围是20-300
-
         // 机架信息
         let rack_count = Self::get_rack_count(equipment_list);
 
-        // 当前槽位跟踪
-        let mut current_rack = 1;
-        let mut current_slot = START_SLOT; // 从指定起始槽位开始，通常是2
-
-        // 获取设备型号映射
-        let model_channel_mapping = Self::get_model_channel_mapping();
-
-        // 按照IO类型对设备进行分类
-        let io_equipment_groups = Self::group_equipment_by_io_type(equipment_list, model_channel_mapping);
-
-        // 按照AI/AO/DI/DO的顺序遍历处理设备
-        for io_type in &[IOChannelType::AI, IOChannelType::AO, IOChannelType::DI, IOChannelType::DO] {
-            if let Some(equipment_group) = io_equipment_groups.get(io_type) {
-                for equipment in equipment_group {
-                    let spec_model = &equipment.spec_model;
-                    // 获取该设备的通道信息
-                    let mut channel_info = None;
-
-                    for model in model_channel_mapping {
-                        if spec_model.contains(&model.model_key) {
-                            channel_info = Some((
-                                model.channel_type,
-                                model.channels,
-                                model.data_type
-                            ));
-                            break;
-                        }
-                    }
-
-                    if let Some((io_type_val, channels, data_type)) = channel_info {
-                        let quantity = equipment.quantity;
-                        let equipment_name = &equipment.equipment_name;
-                        let station_name = &equipment.station_name;
-
-                        // 为每个设备的每个通道创建单独的点表条目
-                        for _ in 0..quantity {
-                            // 获取当前模块号
-                            let _module_num = *module_counters.get(&io_type_val).unwrap_or(&1);
-
-                            // 更新机架和槽位
-                            Self::update_rack_and_slot(&mut current_rack, &mut current_slot, rack_count)?;
-
-                            // 为该模块的每个通道创建条目
-                            for ch in 0..channels {
-                                // 生成新的通道位号格式（例如：1_1_AO_0）
-                                let channel_code = format!("{}_{}_{}_{}", current_rack, current_slot, io_type_val, ch);
-
-                                // 生成PLC绝对地址和更新地址计数器
-                                let (plc_address, modbus_address) = Self::generate_addresses(
-                                    data_type,
-                                    &mut real_address_counter,
-                                    &mut bool_address_counter
-                                )?;
-
-                                // 创建点表数据
-                                let point_data = Self::create_io_point(
-                                    index_counter,
-                                    equipment_name,
-                                    io_type_val,
-                                    channel_code,
-                                    station_name,
-                                    data_type,
-                                    plc_address,
-                                    modbus_address
-                                );
-
-                                // 添加到点表列表
-                                io_points.push(point_data);
-                                index_counter += 1;
-                            }
-
-                            // 每个设备模块增加模块计数器和槽位计数器
-                            if let Some(counter) = module_counters.get_mut(&io_type_val) {
-                                *counter += 1;
-                            }
-                            current_slot += 1;
-                        }
-                    }
+        // 按排布策略为每个模块分配机架和槽位，结果已按机架、槽位排序
+        let placed_modules = ModulePlacementService::place(equipment_list, rack_count, options)?;
+
+        for placed in &placed_modules {
+            let equipment = placed.module.equipment;
+            let io_type_val = placed.module.mapping.channel_type;
+            let channels = placed.module.mapping.channels;
+            let data_type = placed.module.mapping.data_type;
+            let SlotPosition { rack, slot } = placed.position;
+            let is_virtual_rack = rack > rack_count;
+
+            // 为该模块的每个通道创建条目
+            for ch in 0..channels {
+                // 生成新的通道位号格式（例如：1_1_AO_0）
+                let channel_code = format!("{}_{}_{}_{}", rack, slot, io_type_val, ch);
+
+                // 创建点表数据
+                let mut point_data = Self::create_io_point(
+                    index_counter,
+                    &equipment.equipment_name,
+                    io_type_val,
+                    channel_code,
+                    &equipment.station_name,
+                    data_type
+                );
+
+                // 按备用策略追加的备用模块，通道全部标记为备用并保留地址
+                if placed.module.spare {
+                    SparePolicy::mark_spare_row(&mut point_data);
                 }
+
+                // 点位标识不随槽位变化，用于沿用锁定的地址
+                point_data.point_key = Some(format!("{}#{}", placed.module.key(), ch));
+
+                // 冗余模块只生成一个逻辑点，并注明伙伴槽位
+                if let Some(partner) = placed.partner_position() {
+                    let partner_slot = format!("{}_{}", partner.rack, partner.slot);
+                    point_data.module_name = Some(Cow::Owned(
+                        format!("{}（冗余，伙伴槽位{}）", equipment.equipment_name, partner_slot)
+                    ));
+                    point_data.redundant_partner = Some(partner_slot);
+                }
+
+                // 标记待订购机架上的点位
+                if is_virtual_rack {
+                    let module_name = point_data.module_name.take().unwrap_or_default();
+                    point_data.module_name = Some(Cow::Owned(format!("{}（待订购机架）", module_name)));
+                    point_data.is_virtual_rack = true;
+                }
+
+                // 添加到点表列表，模拟量模块同时需要附加点位地址
+                io_points.push(point_data);
+                address_specs.push((data_type, matches!(io_type_val, IOChannelType::AI | IOChannelType::AO)));
+                index_counter += 1;
             }
         }
 
-        Ok(io_points)
-    }
+        // 软点位排在硬件通道之后，同样参与地址分配
+        for soft_point in &options.soft_points {
+            io_points.push(Self::create_soft_point(index_counter, soft_point));
+            address_specs.push((soft_point.data_type, false));
+            index_counter += 1;
+        }
 
-    /// 获取机架数量
-    fn get_rack_count(equipment_list: &[EquipmentData]) -> u32 {
-        for equipment in equipment_list {
-            if equipment.spec_model.contains("LK117") {
-                return equipment.quantity;
+        // PLC地址分配器，地址范围由地址规划决定（默认%MD320开始，%MX20.0-%MX300.7）
+        let mut allocator = AddressAllocator::new(options.address_plan())?;
+
+        // 先沿用上次锁定的地址，避免新增点位挤占已投用的地址
+        let address_lock = options.address_lock.as_ref();
+        if let Some(lock) = address_lock {
+            for (point, (data_type, with_sub_points)) in io_points.iter_mut().zip(&address_specs) {
+                let locked = point.point_key.as_deref().and_then(|key| lock.addresses_for(key));
+                if let Some(locked) = locked {
+                    allocator.restore_row_addresses(point, *data_type, *with_sub_points, locked)?;
+                }
             }
         }
-        1 // 默认为1个机架
-    }
 
-    /// 按IO类型分组设备，优化为一次遍历完成分组
-    fn group_equipment_by_io_type<'a>(
-        equipment_list: &'a [EquipmentData],
-        model_mapping: &[ModelChannelMapping]
-    ) -> HashMap<IOChannelType, Vec<&'a EquipmentData>> {
-        let mut groups = HashMap::new();
-        // 初始化所有IO类型的分组
-        groups.insert(IOChannelType::AI, Vec::new());
-        groups.insert(IOChannelType::AO, Vec::new());
-        groups.insert(IOChannelType::DI, Vec::new());
-        groups.insert(IOChannelType::DO, Vec::new());
-
-        // 创建设备型号到通道类型的映射，避免重复查找
-        let mut model_to_channel_type = HashMap::new();
-        for model in model_mapping {
-            model_to_channel_type.insert(model.model_key.as_str(), model.channel_type);
+        // 其余地址从空闲空间依次分配
+        for (point, (data_type, with_sub_points)) in io_points.iter_mut().zip(&address_specs) {
+            allocator.assign_row_addresses(point, *data_type, *with_sub_points)?;
         }
 
-        // 遍历设备列表进行分类
-        for equipment in equipment_list {
-            let spec_model = &equipment.spec_model;
+        // 合并模式下沿用上次点表中填写的内容，已投用的备用通道取消备用标记
+        let merge = options.previous_table.as_ref()
+            .map(|previous| TableMergeService::merge(&mut io_points, previous));
+
+        let report = GenerationReport {
+            area_utilization: allocator.utilization(),
+            moved_points: address_lock
+                .map(|lock| Self::find_moved_points(&io_points, lock))
+                .unwrap_or_default(),
+            address_lock: AddressLock::from_rows(&io_points),
+            merge,
+            revision: None,
+            io_table: None,
+        };
+        Ok((io_points, report, placed_modules))
+    }
 
-            // 查找匹配的型号并分组
-            for (model_key, channel_type) in &model_to_channel_type {
-                if spec_model.contains(*model_key) {
-                    if let Some(group) = groups.get_mut(channel_type) {
-                        group.push(equipment);
-                    }
-                    break;
+    /// 找出地址与上次锁定不一致的点位
+    fn find_moved_points(io_points: &[IOTableRow<'_>], lock: &AddressLock) -> Vec<MovedPoint> {
+        let mut moved_points = Vec::new();
+        for point in io_points {
+            let Some(point_key) = point.point_key.as_deref() else {
+                continue;
+            };
+            let Some(locked) = lock.addresses_for(point_key) else {
+                continue;
+            };
+
+            for (field, current) in point.plc_address_fields() {
+                let Some(previous) = locked.get(field) else {
+                    continue;
+                };
+                let current = current.unwrap_or("/");
+                if current != previous {
+                    moved_points.push(MovedPoint {
+                        point_key: point_key.to_string(),
+                        channel_tag: point.channel_tag.as_deref().unwrap_or_default().to_string(),
+                        field: field.to_string(),
+                        previous_address: previous.clone(),
+                        current_address: current.to_string(),
+                    });
                 }
             }
         }
+        moved_points
+    }
 
-        groups
+    /// 获取机架数量
+    pub fn get_rack_count(equipment_list: &[EquipmentData]) -> u32 {
+        for equipment in equipment_list {
+            if equipment.spec_model.contains("LK117") {
+                return equipment.quantity;
+            }
+        }
+        1 // 默认为1个机架
     }
 
     /// 创建IO点表行，减少不必要的克隆
@@ -545,9 +922,7 @@ impl IOExcelService {
         io_type: IOChannelType,
         channel_code: String,
         station_name: &str,
-        data_type: DataType,
-        plc_address: String,
-        modbus_address: u32
+        data_type: DataType
     ) -> IOTableRow<'static> {
         let mut point = IOTableRow::default();
 
@@ -568,11 +943,7 @@ impl IOExcelService {
         point.station_name = Some(Cow::Owned(station_name.to_owned()));
 
         // 使用静态字符串优化数据类型字段
-        let data_type_str = match data_type {
-            DataType::REAL => Cow::Borrowed("REAL"),
-            DataType::BOOL => Cow::Borrowed("BOOL"),
-        };
-        point.data_type = Some(data_type_str);
+        point.data_type = Some(Cow::Borrowed(data_type.as_str()));
 
         // 通用属性使用静态字符串引用
         point.read_write_property = Some(Cow::Borrowed("R/W"));
@@ -585,196 +956,103 @@ impl IOExcelService {
             point.wire_system = Some(Cow::Borrowed("/"));
         }
 
-        // 数据类型相关设置
-        match data_type {
-            DataType::REAL => {
-                // REAL类型需要设置量程
-                point.range_lower_limit = Some(Cow::Owned("".to_owned()));
-                point.range_upper_limit = Some(Cow::Owned("".to_owned()));
-                point.sll_value = Some(Cow::Owned("".to_owned()));
-                point.sl_value = Some(Cow::Owned("".to_owned()));
-                point.sh_value = Some(Cow::Owned("".to_owned()));
-                point.shh_value = Some(Cow::Owned("".to_owned()));
-            },
-            DataType::BOOL => {
-                // BOOL类型使用"/"表示不适用
-                point.range_lower_limit = Some(Cow::Borrowed("/"));
-                point.range_upper_limit = Some(Cow::Borrowed("/"));
-                point.sll_value = Some(Cow::Borrowed("/"));
-                point.sl_value = Some(Cow::Borrowed("/"));
-                point.sh_value = Some(Cow::Borrowed("/"));
-                point.shh_value = Some(Cow::Borrowed("/"));
-            }
-        }
+        // 数值类型需要设置量程，其余类型使用"/"表示不适用
+        let range_placeholder = if data_type.has_range() { "" } else { "/" };
+        point.range_lower_limit = Some(Cow::Borrowed(range_placeholder));
+        point.range_upper_limit = Some(Cow::Borrowed(range_placeholder));
+
+        // 报警限值只对模拟量通道有意义
+        let is_analog = matches!(io_type, IOChannelType::AI | IOChannelType::AO);
+        let limit_placeholder = if is_analog { "" } else { "/" };
+        point.sll_value = Some(Cow::Borrowed(limit_placeholder));
+        point.sl_value = Some(Cow::Borrowed(limit_placeholder));
+        point.sh_value = Some(Cow::Borrowed(limit_placeholder));
+        point.shh_value = Some(Cow::Borrowed(limit_placeholder));
+
+        point
+    }
+
+    /// 创建软点位的点表行
+    fn create_soft_point(index: u32, soft_point: &SoftPoint) -> IOTableRow<'static> {
+        let mut point = IOTableRow::default();
+
+        let source = if soft_point.source.is_empty() { "软点位" } else { soft_point.source.as_str() };
+        point.index = Some(Cow::Owned(index.to_string()));
+        point.module_name = Some(Cow::Owned(source.to_owned()));
+        point.module_type = Some(Cow::Borrowed("/"));
+        point.power_supply_type = Some(Cow::Borrowed("/"));
+        point.wire_system = Some(Cow::Borrowed("/"));
+        point.channel_tag = Some(Cow::Borrowed("/"));
+        point.tag = Some(Cow::Owned(soft_point.tag.clone()));
+        point.variable_description = Some(Cow::Owned(soft_point.description.clone()));
+        point.station_name = Some(Cow::Owned(soft_point.station_name.clone()));
+        point.data_type = Some(Cow::Borrowed(soft_point.data_type.as_str()));
+        point.read_write_property = Some(Cow::Borrowed("R/W"));
+        point.save_history = Some(Cow::Borrowed("是"));
+        point.power_off_protection = Some(Cow::Borrowed("是"));
 
-        // 地址信息
-        point.plc_absolute_address = Some(Cow::Owned(plc_address));
-        point.host_comm_address = Some(Cow::Owned(modbus_address.to_string()));
+        let range_placeholder = if soft_point.data_type.has_range() { "" } else { "/" };
+        point.range_lower_limit = Some(Cow::Borrowed(range_placeholder));
+        point.range_upper_limit = Some(Cow::Borrowed(range_placeholder));
+        point.sll_value = Some(Cow::Borrowed("/"));
+        point.sl_value = Some(Cow::Borrowed("/"));
+        point.sh_value = Some(Cow::Borrowed("/"));
+        point.shh_value = Some(Cow::Borrowed("/"));
 
+        point.point_key = Some(format!("软点位#{}", soft_point.tag));
         point
     }
 
-    /// 写入表头
-    fn write_headers(worksheet: &mut Worksheet) {
-        for (col_idx, header) in IO_TABLE_HEADERS.iter().enumerate() {
+    /// 按列模板写入表头
+    fn write_headers(worksheet: &mut Worksheet, template: &ColumnTemplate) {
+        for (col_idx, column) in template.columns.iter().enumerate() {
             let col = col_idx as u32 + 1;
-            worksheet.get_cell_mut((col, 1)).set_value(header.to_string());
+            worksheet.get_cell_mut((col, 1)).set_value(column.header_text().to_string());
 
             // 设置表头样式
             let style = worksheet.get_style_mut((col, 1));
             style.get_font_mut().set_bold(true);
 
             // 应用通用单元格样式
-            let highlight = HIGHLIGHT_FIELDS.contains(header);
+            let highlight = HIGHLIGHT_FIELDS.contains(&column.field.as_str());
             Self::apply_common_cell_style(style, highlight);
         }
+
+        // 点位标识列隐藏，供重新生成时按模块合并
+        let key_col = Self::point_key_column(template);
+        worksheet.get_cell_mut((key_col, 1)).set_value(POINT_KEY_HEADER.to_string());
+        worksheet.get_column_dimension_mut(&get_column_letter(key_col)).set_hidden(true);
     }
 
-    /// 写入数据、设置公式和占位符
-    fn write_data_and_formulas(worksheet: &mut Worksheet, io_table: &IOTable) -> Result<(), IoError> {
-        // PLC地址计数器(统一使用，确保不重复)
-        let mut real_address_counter = REAL_ADDR_START; // %MD320开始
-        let mut bool_address_counter = BOOL_ADDR_START; // %MX20.0开始，范围是20-300
+    /// 点位标识列的列号，位于列模板各列之后
+    fn point_key_column(template: &ColumnTemplate) -> u32 {
+        template.columns.len() as u32 + 1
+    }
 
+    /// 写入数据、设置公式和占位符
+    ///
+    /// 地址已在准备点表数据时分配，这里只负责输出
+    fn write_data_and_formulas(worksheet: &mut Worksheet, io_table: &IOTable, template: &ColumnTemplate) -> Result<(), IoError> {
         for (row_idx, row_data) in io_table.rows.iter().enumerate() {
             let row = row_idx as u32 + 2; // 从第2行开始（跳过表头）
 
-            // 获取数据类型和模块类型
-            let data_type = row_data.data_type.as_ref().map(|c| c.as_ref()).unwrap_or("BOOL");
-            let module_type = row_data.module_type.as_ref().map(|c| c.as_ref()).unwrap_or("");
-            let is_real_type = data_type == "REAL";
-            let is_analog_module = matches!(module_type, "AI" | "AO");
+            // 报警和维护点位只对模拟量通道生成
+            let module_type = row_data.module_type.as_deref().unwrap_or("");
+            let is_analog = matches!(module_type, "AI" | "AO");
 
-            // 写入单元格数据（不包括地址，地址将统一分配）
-            Self::write_row_data(worksheet, row, row_data)?;
+            // 写入单元格数据（包括已分配的地址）
+            Self::write_row_data(worksheet, row, row_data, template)?;
 
             // 设置Excel公式或占位符
-            if is_real_type {
-                Self::set_formulas_for_real_type(worksheet, row)?;
+            if is_analog {
+                Self::set_formulas_for_real_type(worksheet, row, template);
             } else {
-                Self::set_placeholders_for_bool_type(worksheet, row);
+                Self::set_placeholders_for_bool_type(worksheet, row, template);
             }
 
             // 设置维护值设定为"/"
-            if let Some(maint_val_pos) = IO_TABLE_HEADERS.iter().position(|&h| h == "维护值设定") {
-                worksheet.get_cell_mut((maint_val_pos as u32 + 1, row)).set_value("/".to_string());
-            }
-
-            // 从左往右收集所有需要分配地址的列
-            // 格式：(列名, 列索引, 是否REAL类型地址)
-            let mut address_columns = Vec::new();
-
-            // 获取地址列的顺序
-            for (col_idx, header) in IO_TABLE_HEADERS.iter().enumerate() {
-                if header.ends_with("_PLC地址") || *header == "PLC绝对地址" {
-                    // 判断地址类型（REAL或BOOL）
-                    let is_real_addr = if *header == "PLC绝对地址" {
-                        // 主地址使用行的数据类型
-                        is_real_type
-                    } else if header.starts_with("SLL") || header.starts_with("SL") ||
-                        header.starts_with("SH") || header.starts_with("SHH") ||
-                        header.starts_with("维护值设定点位") {
-                        // 这些是REAL类型地址
-                        true
-                    } else {
-                        // 其他都是BOOL类型地址
-                        false
-                    };
-
-                    address_columns.push((header, col_idx, is_real_addr));
-                }
-            }
-
-            // 按照列索引排序，确保从左到右处理
-            address_columns.sort_by_key(|(_header, col_idx, _is_real)| *col_idx);
-
-            // 对于非模拟量模块，所有额外点位地址都设为"/"
-            if !is_analog_module {
-                for (header, col_idx, _) in &address_columns {
-                    // 只有主PLC绝对地址保留实际值，其他都设置为"/"
-                    if **header != "PLC绝对地址" {
-                        worksheet.get_cell_mut((*col_idx as u32 + 1, row)).set_value("/".to_string());
-
-                        // 同时设置对应的通讯地址为"/"
-                        let comm_header = header.replace("_PLC地址", "_通讯地址");
-                        if let Some(comm_idx) = IO_TABLE_HEADERS.iter().position(|&h| h == comm_header) {
-                            worksheet.get_cell_mut((comm_idx as u32 + 1, row)).set_value("/".to_string());
-                        }
-                    }
-                }
-
-                // 非模拟量模块只处理主PLC绝对地址
-                if let Some(plc_addr_pos) = IO_TABLE_HEADERS.iter().position(|&h| h == "PLC绝对地址") {
-                    let main_addr = if is_real_type {
-                        // REAL类型使用%MD地址
-                        let addr = format!("%MD{}", real_address_counter);
-                        real_address_counter += 4; // REAL类型每个点位加4
-                        addr
-                    } else {
-                        // BOOL类型使用%MX地址
-                        let addr = format!("%MX{}.{}", bool_address_counter.0, bool_address_counter.1);
-
-                        // 更新BOOL地址计数器
-                        bool_address_counter.1 += 1;
-                        if bool_address_counter.1 > 7 {
-                            bool_address_counter.0 += 1;
-                            bool_address_counter.1 = 0;
-                        }
-
-                        addr
-                    };
-
-                    // 设置PLC绝对地址
-                    worksheet.get_cell_mut((plc_addr_pos as u32 + 1, row)).set_value(main_addr.clone());
-
-                    // 设置上位机通讯地址
-                    if let Some(host_addr_pos) = IO_TABLE_HEADERS.iter().position(|&h| h == "上位机通讯地址") {
-                        let data_type = if is_real_type { DataType::REAL } else { DataType::BOOL };
-                        let modbus = Self::calculate_modbus_address(&main_addr, data_type)?;
-                        worksheet.get_cell_mut((host_addr_pos as u32 + 1, row)).set_value(modbus.to_string());
-                    }
-                }
-
-                continue;  // 非模拟量模块处理完毕，跳过后续代码
-            }
-
-            // 从左往右依次分配地址（对于模拟量模块）
-            for (header, col_idx, is_real) in &address_columns {
-                // 分配PLC地址
-                let plc_addr = if *is_real {
-                    // REAL类型使用%MD地址
-                    let addr = format!("%MD{}", real_address_counter);
-                    real_address_counter += 4; // REAL类型每个点位加4
-                    addr
-                } else {
-                    // BOOL类型使用%MX地址
-                    let addr = format!("%MX{}.{}", bool_address_counter.0, bool_address_counter.1);
-
-                    // 更新BOOL地址计数器
-                    bool_address_counter.1 += 1;
-                    if bool_address_counter.1 > 7 {
-                        bool_address_counter.0 += 1;
-                        bool_address_counter.1 = 0;
-                    }
-
-                    addr
-                };
-
-                // 设置PLC地址
-                worksheet.get_cell_mut((*col_idx as u32 + 1, row)).set_value(plc_addr.clone());
-
-                // 设置对应的通讯地址
-                let comm_header = if **header == "PLC绝对地址" {
-                    "上位机通讯地址".to_string()
-                } else {
-                    header.replace("_PLC地址", "_通讯地址")
-                };
-
-                if let Some(comm_idx) = IO_TABLE_HEADERS.iter().position(|&h| h == comm_header) {
-                    let data_type = if *is_real { DataType::REAL } else { DataType::BOOL };
-                    let modbus = Self::calculate_modbus_address(&plc_addr, data_type)?;
-                    worksheet.get_cell_mut((comm_idx as u32 + 1, row)).set_value(modbus.to_string());
-                }
+            if let Some(maint_val_col) = template.column_number("维护值设定") {
+                worksheet.get_cell_mut((maint_val_col, row)).set_value("/".to_string());
             }
         }
 
@@ -782,10 +1060,11 @@ impl IOExcelService {
     }
 
     /// 写入单行数据
-    fn write_row_data(worksheet: &mut Worksheet, row: u32, row_data: &IOTableRow) -> Result<(), IoError> {
-        // 按照表头顺序写入每个字段
-        for (col_idx, header) in IO_TABLE_HEADERS.iter().enumerate() {
+    fn write_row_data(worksheet: &mut Worksheet, row: u32, row_data: &IOTableRow, template: &ColumnTemplate) -> Result<(), IoError> {
+        // 按照列模板的顺序写入每个字段，自定义列取自定义列的内容
+        for (col_idx, column) in template.columns.iter().enumerate() {
             let col = col_idx as u32 + 1;
+            let header = column.field.as_str();
 
             // 通过IOTableRow的get_field_by_name方法获取字段值
             if let Some(cow_value) = row_data.get_field_by_name(header) {
@@ -804,20 +1083,55 @@ impl IOExcelService {
             // 1. 字段在需要高亮的列表中
             // 2. 如果是BOOL类型，且字段名包含"量程"，则不高亮
             // 3. 字段值不等于"/"（包括空值和其他值都会高亮）
-            let should_highlight = HIGHLIGHT_FIELDS.contains(header) &&
+            // 4. 备用通道不需要用户填写
+            let should_highlight = HIGHLIGHT_FIELDS.contains(&header) &&
                 !(data_type == "BOOL" && header.contains("量程")) &&
-                field_value != "/";
+                field_value != "/" &&
+                !row_data.is_spare;
 
-            // 设置样式
+            // 设置样式，工作表保护后只有待填写的单元格可以修改
             let style = worksheet.get_style_mut((col, row));
             Self::apply_common_cell_style(style, should_highlight);
+            style.get_protection_mut().set_locked(!InputProtectionService::is_input_cell(header, row_data));
+
+            // 待订购机架上的点位整行标红，高亮优先级低于待填写字段
+            if row_data.is_removed_channel {
+                style.set_background_color(REMOVED_CHANNEL_COLOR.to_string());
+            } else if row_data.is_virtual_rack && !should_highlight {
+                style.set_background_color(VIRTUAL_RACK_COLOR.to_string());
+            } else if row_data.is_spare {
+                style.set_background_color(SPARE_ROW_COLOR.to_string());
+            } else if row_data.is_new_channel && !should_highlight {
+                style.set_background_color(NEW_CHANNEL_COLOR.to_string());
+            }
+        }
+
+        if let Some(point_key) = &row_data.point_key {
+            worksheet.get_cell_mut((Self::point_key_column(template), row)).set_value(point_key.clone());
+        }
+        Ok(())
+    }
+
+    /// 写入已移除通道工作表，保留上次点表中的内容供核对
+    fn write_removed_channel_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        removed_rows: &[IOTableRow],
+        template: &ColumnTemplate
+    ) -> Result<(), IoError> {
+        let worksheet = spreadsheet.new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建已移除通道表失败: {}", e)))?;
+
+        Self::write_headers(worksheet, template);
+        for (row_idx, row_data) in removed_rows.iter().enumerate() {
+            Self::write_row_data(worksheet, row_idx as u32 + 2, row_data, template)?;
         }
 
         Ok(())
     }
 
     /// 应用通用单元格样式
-    fn apply_common_cell_style(style: &mut Style, highlight: bool) {
+    pub(crate) fn apply_common_cell_style(style: &mut Style, highlight: bool) {
         // 设置边框
         style.get_borders_mut().get_bottom_mut().set_border_style(Border::BORDER_THIN);
         style.get_borders_mut().get_top_mut().set_border_style(Border::BORDER_THIN);
@@ -833,29 +1147,27 @@ impl IOExcelService {
         }
     }
 
-    /// 设置REAL类型的公式
-    fn set_formulas_for_real_type(worksheet: &mut Worksheet, row: u32) -> Result<(), IoError> {
-        // 获取HMI变量名所在的列索引
-        let hmi_col_idx = IO_TABLE_HEADERS.iter()
-            .position(|&h| h == "变量名称（HMI）")
-            .ok_or_else(|| IoError::DataQuery("HMI变量列不存在".to_string()))? as u32 + 1;
+    /// 设置REAL类型的公式，列模板中没有HMI变量列时不设置
+    fn set_formulas_for_real_type(worksheet: &mut Worksheet, row: u32, template: &ColumnTemplate) {
+        // 获取HMI变量名所在的列
+        let Some(hmi_col) = template.column_number("变量名称（HMI）") else {
+            return;
+        };
 
         // 使用迭代器简化公式设置，使用静态点位配置
         for (column_name, suffix) in POINT_CONFIGS.iter() {
-            if let Some(pos) = IO_TABLE_HEADERS.iter().position(|&h| h == *column_name) {
+            if let Some(col) = template.column_number(column_name) {
                 let formula = format!("=IF(ISBLANK({}{}),\"{}\",{}{}&\"{}\")",
-                                      get_column_letter(hmi_col_idx), row, suffix,
-                                      get_column_letter(hmi_col_idx), row, suffix);
+                                      get_column_letter(hmi_col), row, suffix,
+                                      get_column_letter(hmi_col), row, suffix);
 
-                worksheet.get_cell_mut((pos as u32 + 1, row)).set_formula(formula);
+                worksheet.get_cell_mut((col, row)).set_formula(formula);
             }
         }
-
-        Ok(())
     }
 
     /// 设置BOOL类型的占位符
-    fn set_placeholders_for_bool_type(worksheet: &mut Worksheet, row: u32) {
+    fn set_placeholders_for_bool_type(worksheet: &mut Worksheet, row: u32, template: &ColumnTemplate) {
         // 需要设置"/"的列
         let placeholder_columns = [
             "SLL设定点位", "SL设定点位", "SH设定点位", "SHH设定点位",
@@ -864,18 +1176,18 @@ impl IOExcelService {
         ];
 
         for column_name in &placeholder_columns {
-            if let Some(pos) = IO_TABLE_HEADERS.iter().position(|&h| h == *column_name) {
-                worksheet.get_cell_mut((pos as u32 + 1, row)).set_value("/".to_string());
+            if let Some(col) = template.column_number(column_name) {
+                worksheet.get_cell_mut((col, row)).set_value("/".to_string());
             }
         }
     }
 
     /// 自动调整列宽
-    fn adjust_column_widths(worksheet: &mut Worksheet, io_table: &IOTable) {
-        let mut column_widths = vec![15.0f64; IO_TABLE_HEADERS.len()]; // 默认宽度
+    fn adjust_column_widths(worksheet: &mut Worksheet, io_table: &IOTable, template: &ColumnTemplate) {
+        let mut column_widths = vec![15.0f64; template.columns.len()]; // 默认宽度
 
         // 计算表头文本宽度
-        Self::calculate_header_widths(&mut column_widths);
+        Self::calculate_header_widths(&mut column_widths, template);
 
         // 计算数据行文本宽度
         for (row_idx, _) in io_table.rows.iter().enumerate() {
@@ -884,7 +1196,7 @@ impl IOExcelService {
         }
 
         // 应用列宽设置
-        for col_idx in 0..IO_TABLE_HEADERS.len() {
+        for col_idx in 0..column_widths.len() {
             let col_letter = get_column_letter(col_idx as u32 + 1);
             // 限制最大宽度为50，避免过宽
             let width = f64::min(column_widths[col_idx], 50.0);
@@ -893,10 +1205,10 @@ impl IOExcelService {
     }
 
     /// 计算表头文本宽度
-    fn calculate_header_widths(column_widths: &mut [f64]) {
-        for (col_idx, header) in IO_TABLE_HEADERS.iter().enumerate() {
+    fn calculate_header_widths(column_widths: &mut [f64], template: &ColumnTemplate) {
+        for (col_idx, column) in template.columns.iter().enumerate() {
             // 根据字符长度估算宽度，中文字符占用更多宽度
-            let estimated_width = Self::estimate_text_width(header);
+            let estimated_width = Self::estimate_text_width(column.header_text());
             // 额外添加一些padding，并设置最小宽度
             let width = f64::max(estimated_width + 2.0, 10.0);
             column_widths[col_idx] = f64::max(column_widths[col_idx], width);
@@ -905,7 +1217,7 @@ impl IOExcelService {
 
     /// 计算单行数据文本宽度
     fn calculate_row_widths(worksheet: &mut Worksheet, row: u32, column_widths: &mut [f64]) {
-        for (col_idx, _) in IO_TABLE_HEADERS.iter().enumerate() {
+        for col_idx in 0..column_widths.len() {
             let col = col_idx as u32 + 1;
 
             // 获取单元格值的字符串表示
@@ -940,9 +1252,14 @@ impl IOExcelService {
     }
 
     /// 向数据处理服务发送场站设备数据
-    pub fn process_station_data(equipment_list: &[EquipmentData]) -> Result<HashMap<String, ChannelTotal>, String> {
+    pub fn process_station_data(
+        equipment_list: &[EquipmentData],
+        spare_policy: &SparePolicy,
+        signal_counts: &HashMap<IOChannelType, u32>
+    ) -> Result<HashMap<String, ChannelTotal>, String> {
         // 计算各类型通道总数
-        let channel_totals = Self::calculate_channels(equipment_list);
+        let channel_totals = Self::calculate_channels(equipment_list, spare_policy, signal_counts)
+            .map_err(|e| e.to_string())?;
 
         // 返回统计结果
         Ok(channel_totals)
@@ -951,8 +1268,9 @@ impl IOExcelService {
     /// 生成并导出IO点表
     pub fn generate_io_table(
         equipment_list: &[EquipmentData],
-        station_name: &str
-    ) -> Result<String, String> {
+        station_name: &str,
+        options: &GenerationOptions
+    ) -> Result<(String, GenerationReport), String> {
         // 不再使用临时路径，而是让用户选择输出路径
         // 用默认文件名构造建议的文件名
         let file_name = format!("{}_IO点表.xlsx", station_name);
@@ -962,15 +1280,33 @@ impl IOExcelService {
         let temp_dir = std::env::temp_dir();
         let temp_path = temp_dir.join(&file_name);
 
-        match Self::export_to_excel(equipment_list, &temp_path, station_name) {
-            Ok(_) => Ok(temp_path.to_string_lossy().to_string()),
-            Err(e) => Err(format!("生成IO点表失败: {:?}", e))
+        match Self::export_to_excel(equipment_list, &temp_path, station_name, options) {
+            Ok(report) => Ok((temp_path.to_string_lossy().to_string(), report)),
+            Err(e) => Err(format!("生成IO点表失败: {}", e))
         }
     }
 }
 
+/// 以场站命名的工作表名称（场站_后缀）
+///
+/// 去除Excel不允许的字符，超长时截断场站名称，保证总长度不超过31个字符
+pub(crate) fn station_sheet_name(station_name: &str, suffix: &str) -> String {
+    let station: String = station_name
+        .trim()
+        .chars()
+        .map(|c| if matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\') { '_' } else { c })
+        .collect();
+    let station = station.trim_matches('\'');
+    if station.is_empty() {
+        return suffix.to_string();
+    }
+    let max_station_chars = MAX_SHEET_NAME_CHARS.saturating_sub(suffix.chars().count() + 1);
+    let station: String = station.chars().take(max_station_chars).collect();
+    format!("{}_{}", station, suffix)
+}
+
 /// 辅助函数：获取列字母（A, B, C...AA, AB...）
-fn get_column_letter(col_num: u32) -> String {
+pub(crate) fn get_column_letter(col_num: u32) -> String {
     let mut temp = col_num;
     let mut col_str = String::new();
 
@@ -983,34 +1319,80 @@ fn get_column_letter(col_num: u32) -> String {
     col_str
 }
 
+/// 辅助函数：在指定行写入加粗的表头
+pub(crate) fn write_table_headers(worksheet: &mut Worksheet, row: u32, headers: &[&str]) {
+    for (col_idx, header) in headers.iter().enumerate() {
+        let col = col_idx as u32 + 1;
+        worksheet.get_cell_mut((col, row)).set_value(header.to_string());
+        let style = worksheet.get_style_mut((col, row));
+        style.get_font_mut().set_bold(true);
+        IOExcelService::apply_common_cell_style(style, false);
+    }
+}
+
+/// 辅助函数：从start_row起写入带表头的表格，返回表格最后一行的行号
+///
+/// 单元格使用通用样式；widths按列设置列宽，为空时不调整
+pub(crate) fn write_simple_table<R>(
+    worksheet: &mut Worksheet,
+    start_row: u32,
+    headers: &[&str],
+    rows: impl IntoIterator<Item = R>,
+    widths: &[f64]
+) -> u32
+where
+    R: IntoIterator<Item = String>,
+{
+    write_table_headers(worksheet, start_row, headers);
+
+    let mut row = start_row;
+    for values in rows {
+        row += 1;
+        for (col_idx, value) in values.into_iter().enumerate() {
+            let col = col_idx as u32 + 1;
+            worksheet.get_cell_mut((col, row)).set_value(value);
+            IOExcelService::apply_common_cell_style(worksheet.get_style_mut((col, row)), false);
+        }
+    }
+
+    for (col_idx, width) in widths.iter().enumerate() {
+        worksheet
+            .get_column_dimension_mut(&get_column_letter(col_idx as u32 + 1))
+            .set_width(*width);
+    }
+    row
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application_services::io_planning_services::spare_policy::SpareRule;
+    use crate::model_domain::io_table_model::IO_TABLE_HEADERS;
     
     #[test]
     fn test_calculate_real_modbus_address() {
         // 测试REAL类型地址计算
-        let result = IOExcelService::calculate_modbus_address("%MD320", DataType::REAL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MD320", DataType::REAL);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 43160); // (320/2) + 43001
+        assert_eq!(result.unwrap(), 43161); // (320/2) + 43001
         
-        let result = IOExcelService::calculate_modbus_address("%MD400", DataType::REAL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MD400", DataType::REAL);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 43200); // (400/2) + 43001
+        assert_eq!(result.unwrap(), 43201); // (400/2) + 43001
     }
     
     #[test]
     fn test_calculate_bool_modbus_address() {
         // 测试BOOL类型地址计算
-        let result = IOExcelService::calculate_modbus_address("%MX20.0", DataType::BOOL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MX20.0", DataType::BOOL);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3161); // (20*8) + 0 + 3001
         
-        let result = IOExcelService::calculate_modbus_address("%MX20.7", DataType::BOOL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MX20.7", DataType::BOOL);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3168); // (20*8) + 7 + 3001
         
-        let result = IOExcelService::calculate_modbus_address("%MX21.0", DataType::BOOL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MX21.0", DataType::BOOL);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3169); // (21*8) + 0 + 3001
     }
@@ -1018,16 +1400,184 @@ mod tests {
     #[test]
     fn test_invalid_address_format() {
         // 测试无效地址格式
-        let result = IOExcelService::calculate_modbus_address("MD320", DataType::REAL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "MD320", DataType::REAL);
         assert!(result.is_err());
         
-        let result = IOExcelService::calculate_modbus_address("%MX20", DataType::BOOL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MX20", DataType::BOOL);
         assert!(result.is_err());
         
-        let result = IOExcelService::calculate_modbus_address("%MX.0", DataType::BOOL);
+        let result = IOExcelService::calculate_modbus_address(&AddressPlan::default(), "%MX.0", DataType::BOOL);
         assert!(result.is_err());
     }
     
+    #[test]
+    fn test_calculate_modbus_address_with_custom_plan() {
+        let plan = AddressPlan {
+            real_area: "%MD".to_string(),
+            real_start: 1000,
+            real_register_offset: 0,
+            bool_start_byte: 500,
+            bool_register_offset: 0,
+            ..Default::default()
+        };
+
+        let result = IOExcelService::calculate_modbus_address(&plan, "%MD1000", DataType::REAL);
+        assert_eq!(result.unwrap(), 40501); // (1000/2) + 40001
+
+        let result = IOExcelService::calculate_modbus_address(&plan, "%MX500.3", DataType::BOOL);
+        assert_eq!(result.unwrap(), 4004); // (500*8) + 3 + 1
+
+        // 地址区前缀与规划不一致时报错
+        let result = IOExcelService::calculate_modbus_address(&plan, "%DB1000", DataType::REAL);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_prepare_io_points_allocates_all_addresses_once() {
+        let equipment = |model: &str| EquipmentData::for_test(model, model, 1);
+        let equipment_list = vec![equipment("LK411"), equipment("LK610")];
+
+        let (rows, report, _) = IOExcelService::prepare_io_points(&equipment_list, &GenerationOptions::default()).unwrap();
+
+        // 所有地址都保存在点表行中且互不重复
+        let addresses: Vec<String> = rows.iter()
+            .flat_map(|row| row.to_vec().into_iter().zip(IO_TABLE_HEADERS.iter()))
+            .filter(|(_, header)| header.ends_with("_PLC地址") || **header == "PLC绝对地址")
+            .filter_map(|(value, _)| value)
+            .filter(|value| value != "/")
+            .collect();
+        let unique: std::collections::HashSet<_> = addresses.iter().collect();
+        assert_eq!(addresses.len(), unique.len());
+
+        // AI：8通道 * (6个REAL + 5个BOOL)；DI：16个BOOL；REAL每点占4字节
+        assert_eq!(report.area_utilization[0].used, 48 * 4);
+        assert_eq!(report.area_utilization[1].used, 56);
+        assert_eq!(addresses.len(), 48 + 56);
+        assert_eq!(rows[8].sll_setpoint_plc_address.as_deref(), Some("/"));
+    }
+
+    #[test]
+    fn test_calculate_channels_reports_used_spare_and_extra_modules() {
+        let equipment = |model: &str, quantity: u32| EquipmentData::for_test(model, model, quantity);
+        let mut spare_policy = SparePolicy::default();
+        spare_policy.rules.insert(IOChannelType::AI, SpareRule::Percentage(20.0));
+        spare_policy.rules.insert(IOChannelType::DI, SpareRule::Percentage(20.0));
+        let mut signal_counts = HashMap::new();
+        signal_counts.insert(IOChannelType::AI, 10);
+
+        let totals = IOExcelService::calculate_channels(
+            &[equipment("LK411", 2), equipment("LK610", 1)], &spare_policy, &signal_counts
+        ).unwrap();
+
+        // AI：16通道接10个信号，6个备用满足20%
+        let ai = &totals["AI"];
+        assert_eq!((ai.count, ai.used, ai.spare, ai.extra_modules), (16, 10, 6, 0));
+        // DI：未给出信号数时视为全部接有信号，需追加1块模块
+        let di = &totals["DI"];
+        assert_eq!((di.count, di.used, di.spare, di.extra_modules), (16, 16, 0, 1));
+
+        // 冗余设备数量为奇数时与模块排布一样报错，不按少一块模块统计
+        let mut redundant_ai = equipment("LK411", 3);
+        redundant_ai.redundant = true;
+        let result = IOExcelService::calculate_channels(&[redundant_ai], &spare_policy, &signal_counts);
+        assert!(matches!(result, Err(IoError::InvalidPlacement(_))));
+    }
+
+    #[test]
+    fn test_soft_points_use_own_data_types() {
+        let soft_point = |tag: &str, data_type: DataType| SoftPoint {
+            tag: tag.to_string(),
+            description: String::new(),
+            data_type,
+            source: String::new(),
+            station_name: "测试站".to_string(),
+        };
+        let options = GenerationOptions {
+            soft_points: vec![
+                soft_point("RUN_MODE", DataType::WORD),
+                soft_point("FLOW_TOTAL", DataType::LREAL),
+            ],
+            ..Default::default()
+        };
+
+        let (rows, _, _) = IOExcelService::prepare_io_points(&[], &options).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].plc_absolute_address.as_deref(), Some("%MW320"));
+        assert_eq!(rows[0].modbus_span.as_deref(), Some("1"));
+        assert_eq!(rows[0].range_lower_limit.as_deref(), Some("/"));
+        assert_eq!(rows[1].plc_absolute_address.as_deref(), Some("%ML324"));
+        assert_eq!(rows[1].modbus_span.as_deref(), Some("4"));
+        assert_eq!(rows[1].range_lower_limit.as_deref(), Some(""));
+        assert_eq!(rows[1].sll_value.as_deref(), Some("/"));
+        assert_eq!(DataType::from_name(" lreal "), Some(DataType::LREAL));
+    }
+
+    #[test]
+    fn test_locked_addresses_survive_inserted_module() {
+        let equipment = |name: &str, model: &str| EquipmentData::for_test(name, model, 1);
+        let before = vec![equipment("进线柜DI", "LK610"), equipment("出线柜DO", "LK710")];
+        let (old_rows, old_report, _) = IOExcelService::prepare_io_points(&before, &GenerationOptions::default()).unwrap();
+
+        // 在中间插入一块DI模块后重新生成
+        let after = vec![
+            equipment("进线柜DI", "LK610"),
+            equipment("新增DI", "LK610"),
+            equipment("出线柜DO", "LK710"),
+        ];
+        let options = GenerationOptions {
+            address_lock: Some(old_report.address_lock),
+            ..Default::default()
+        };
+        let (new_rows, new_report, _) = IOExcelService::prepare_io_points(&after, &options).unwrap();
+
+        // 原有点位地址不变，新增点位使用空闲地址
+        let address_of = |rows: &[IOTableRow], name: &str, ch: usize| {
+            rows.iter()
+                .filter(|row| row.module_name.as_deref() == Some(name))
+                .nth(ch)
+                .and_then(|row| row.plc_absolute_address.as_deref().map(str::to_string))
+        };
+        assert_eq!(address_of(&new_rows, "出线柜DO", 0), address_of(&old_rows, "出线柜DO", 0));
+        assert_eq!(address_of(&new_rows, "新增DI", 0).as_deref(), Some("%MX24.0"));
+        assert!(new_report.moved_points.is_empty());
+    }
+
+    #[test]
+    fn test_locked_addresses_survive_same_model_inserted_before() {
+        let equipment = |name: &str, quantity: u32| EquipmentData::for_test(name, "LK610", quantity);
+        let before = vec![equipment("进线柜DI", 2), equipment("出线柜DI", 1)];
+        let (old_rows, old_report, _) = IOExcelService::prepare_io_points(&before, &GenerationOptions::default()).unwrap();
+
+        // 在出线柜DI之前插入同型号的设备
+        let after = vec![equipment("进线柜DI", 2), equipment("新增DI", 1), equipment("出线柜DI", 1)];
+        let options = GenerationOptions {
+            address_lock: Some(old_report.address_lock),
+            ..Default::default()
+        };
+        let (new_rows, new_report, _) = IOExcelService::prepare_io_points(&after, &options).unwrap();
+
+        let addresses_of = |rows: &[IOTableRow], name: &str| -> Vec<(Option<String>, Option<String>)> {
+            rows.iter()
+                .filter(|row| row.module_name.as_deref() == Some(name))
+                .map(|row| (row.point_key.clone(), row.plc_absolute_address.as_deref().map(str::to_string)))
+                .collect()
+        };
+        assert_eq!(addresses_of(&new_rows, "出线柜DI"), addresses_of(&old_rows, "出线柜DI"));
+        assert_eq!(new_rows[32].point_key.as_deref(), Some("新增DI#LK610#1#0"));
+        assert!(new_report.moved_points.is_empty());
+    }
+
+    #[test]
+    fn test_station_sheet_name() {
+        assert_eq!(station_sheet_name("输气站", "汇总"), "输气站_汇总");
+        assert_eq!(station_sheet_name("A/B站", "AI"), "A_B站_AI");
+        assert_eq!(station_sheet_name("", "IO点表"), "IO点表");
+
+        let long_name = station_sheet_name(&"站".repeat(40), MAIN_SHEET_SUFFIX);
+        assert_eq!(long_name.chars().count(), MAX_SHEET_NAME_CHARS);
+        assert!(long_name.ends_with("_IO点表"));
+    }
+
     #[test]
     fn test_get_column_letter() {
         assert_eq!(get_column_letter(1), "A");
@@ -1 +1,14 @@
-pub mod io_excel_services;
\ No newline at end of file
+pub mod io_excel_services;
+pub mod status_word_sheet;
+pub mod modbus_sheet;
+pub mod iec104_sheet;
+pub mod dnp3_sheet;
+pub mod io_excel_reader;
+pub mod validation_annotator;
+pub mod diff_sheet;
+pub mod cover_sheet;
+pub mod summary_sheet;
+pub mod module_layout_sheet;
+pub mod type_point_sheet;
+pub mod input_protection;
+pub mod conditional_format;
@@ -0,0 +1,152 @@
+use crate::application_services::address_services::address_allocator::AddressAllocator;
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, DataType, IOExcelService, IoError};
+use crate::model_domain::address_plan_model::AddressPlan;
+use crate::model_domain::io_table_model::{comm_address_field, point_name, IOTableRow, MAIN_PLC_ADDRESS_FIELD};
+use crate::model_domain::modbus_profile_model::ModbusPoint;
+use umya_spreadsheet::Spreadsheet;
+
+/// Modbus映射表名称后缀
+pub const MODBUS_SHEET_SUFFIX: &str = "Modbus映射";
+
+/// Modbus映射表表头
+const MODBUS_HEADERS: [&str; 11] = [
+    "序号", "通道位号", "变量名称（HMI）", "点位", "PLC地址", "数据类型",
+    "Modbus功能区", "Modbus偏移", "寄存器数", "字序", "通讯地址"
+];
+
+/// Modbus映射表的默认列宽
+const COLUMN_WIDTH: f64 = 18.0;
+
+/// 不适用的单元格
+const NOT_APPLICABLE: &str = "/";
+
+/// 一个点位或附加点位的Modbus映射
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModbusMappingPoint {
+    pub channel_tag: String,
+    pub variable_name: String,
+    /// 点位名称，附加点位例如LL报警
+    pub point_name: String,
+    pub plc_address: String,
+    pub data_type: DataType,
+    pub point: ModbusPoint,
+    /// 通讯地址，打包到状态字中的报警位为"寄存器.位"
+    pub comm_address: String,
+}
+
+/// Modbus映射表服务
+/// 点表中的Modbus列只描述主点位，本表逐个列出主点位和附加点位（设定点位、报警、维护）的功能区、偏移及字序
+pub struct ModbusSheetService;
+
+impl ModbusSheetService {
+    /// 收集点表行中全部已分配地址的点位，地址为"/"的附加点位跳过
+    ///
+    /// 打包到状态字中的报警位按所在状态字的寄存器给出功能区和偏移
+    pub fn collect_points(rows: &[IOTableRow<'_>], plan: &AddressPlan) -> Result<Vec<ModbusMappingPoint>, IoError> {
+        let mut points = Vec::new();
+        for row in rows {
+            let Some(row_type) = row.data_type.as_deref().and_then(DataType::from_name) else {
+                continue;
+            };
+            for (field, plc_address) in row.plc_address_fields() {
+                let Some(plc_address) = plc_address.filter(|address| *address != NOT_APPLICABLE) else {
+                    continue;
+                };
+
+                let data_type = AddressAllocator::field_data_type(field, row_type);
+                let point = if plan.modbus.pack_alarm_bits && field != MAIN_PLC_ADDRESS_FIELD && data_type == DataType::BOOL {
+                    let bool_addr = plan.parse_bool(plc_address).map_err(IoError::AddrParse)?;
+                    let (word_byte, _) = plan.status_word_position(bool_addr);
+                    plan.status_word_point(word_byte)
+                } else {
+                    IOExcelService::modbus_point(plan, plc_address, data_type)?
+                };
+
+                points.push(ModbusMappingPoint {
+                    channel_tag: row.channel_tag.as_deref().unwrap_or_default().to_string(),
+                    variable_name: row.variable_name_hmi.as_deref().unwrap_or_default().to_string(),
+                    point_name: point_name(field).to_string(),
+                    plc_address: plc_address.to_string(),
+                    data_type,
+                    point,
+                    comm_address: row
+                        .get_field_by_name(&comm_address_field(field))
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                });
+            }
+        }
+        Ok(points)
+    }
+
+    /// 在工作簿中新增Modbus映射表
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        rows: &[IOTableRow<'_>],
+        plan: &AddressPlan,
+    ) -> Result<(), IoError> {
+        let points = Self::collect_points(rows, plan)?;
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建Modbus映射表失败: {}", e)))?;
+
+        let rows = points.iter().enumerate().map(|(row_idx, point)| [
+            (row_idx + 1).to_string(),
+            point.channel_tag.clone(),
+            point.variable_name.clone(),
+            point.point_name.clone(),
+            point.plc_address.clone(),
+            point.data_type.to_string(),
+            point.point.area.to_string(),
+            point.point.offset.to_string(),
+            point.point.span.to_string(),
+            point.point.word_order.map(|word_order| word_order.to_string())
+                .unwrap_or_else(|| NOT_APPLICABLE.to_string()),
+            point.comm_address.clone(),
+        ]);
+        write_simple_table(worksheet, 1, &MODBUS_HEADERS, rows, &[COLUMN_WIDTH; MODBUS_HEADERS.len()]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::io_table_model::PLC_ADDRESS_FIELDS;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_collect_points_includes_sub_points() {
+        let mut plan = AddressPlan::default();
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+        let mut row = IOTableRow { data_type: Some(Cow::Borrowed("REAL")), ..Default::default() };
+        allocator.assign_row_addresses(&mut row, DataType::REAL, true).unwrap();
+        let rows = vec![row];
+
+        let points = ModbusSheetService::collect_points(&rows, &plan).unwrap();
+        assert_eq!(points.len(), PLC_ADDRESS_FIELDS.len());
+        assert!(points.iter().all(|point| !point.comm_address.is_empty()));
+
+        // 附加点位同样给出功能区、偏移和字序
+        let setpoint = points.iter().find(|point| point.point_name == "SLL设定点位").unwrap();
+        assert_eq!(setpoint.data_type, DataType::REAL);
+        assert_eq!(setpoint.point.span, 2);
+        assert!(setpoint.point.word_order.is_some());
+        let alarm = points.iter().find(|point| point.point_name == "LL报警").unwrap();
+        assert_eq!(alarm.data_type, DataType::BOOL);
+        assert_eq!(alarm.point.span, 1);
+        assert_eq!(alarm.comm_address, alarm.point.address.to_string());
+
+        // 打包模式下报警位给出所在状态字的寄存器
+        plan.modbus.pack_alarm_bits = true;
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+        let mut row = IOTableRow { data_type: Some(Cow::Borrowed("REAL")), ..Default::default() };
+        allocator.assign_row_addresses(&mut row, DataType::REAL, true).unwrap();
+        let points = ModbusSheetService::collect_points(&[row], &plan).unwrap();
+        let alarm = points.iter().find(|point| point.point_name == "LL报警").unwrap();
+        assert_eq!(alarm.point.area, plan.status_word_point(0).area);
+        assert_eq!(alarm.comm_address, format!("{}.0", alarm.point.address));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::application_services::excel_services::io_excel_services::{
+    write_simple_table, EquipmentData, IoError, AVAILABLE_SLOTS_PER_RACK, START_SLOT,
+};
+use crate::application_services::io_planning_services::placement_strategy::{
+    PlacedModule, SlotPosition, STANDBY_CONTROLLER_SLOT,
+};
+use std::collections::HashMap;
+use umya_spreadsheet::Spreadsheet;
+
+/// 模块布置表名称后缀
+pub const MODULE_LAYOUT_SHEET_SUFFIX: &str = "模块布置";
+
+/// 模块布置表表头
+const LAYOUT_HEADERS: [&str; 8] = ["机架", "槽位", "模块类型", "规格型号", "模块名称", "子系统", "通道数", "备注"];
+
+/// 模块布置表列宽
+const COLUMN_WIDTHS: [f64; 8] = [8.0, 8.0, 10.0, 16.0, 24.0, 14.0, 10.0, 30.0];
+
+/// 空槽位行的背景色
+const EMPTY_SLOT_COLOR: &str = "FFD9D9D9";
+
+/// 模块布置表服务
+/// 按机架、槽位逐个列出模块，未安装模块的槽位标为空槽
+pub struct ModuleLayoutSheetService;
+
+impl ModuleLayoutSheetService {
+    /// 在工作簿中新增模块布置表
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        placed_modules: &[PlacedModule<'_>],
+        redundant_controller: Option<&EquipmentData>,
+        rack_count: u32,
+    ) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建模块布置表失败: {}", e)))?;
+
+        let slot_rows = Self::slot_rows(placed_modules, redundant_controller, rack_count);
+        let rows = slot_rows.iter().map(|(position, values)| {
+            let values = values.clone().unwrap_or_else(|| {
+                ["/".to_string(), "/".to_string(), "空槽".to_string(), "/".to_string(), "/".to_string(), String::new()]
+            });
+            [position.rack.to_string(), position.slot.to_string()].into_iter().chain(values)
+        });
+        write_simple_table(worksheet, 1, &LAYOUT_HEADERS, rows, &COLUMN_WIDTHS);
+
+        // 空槽位整行标色
+        for (row_idx, (_, values)) in slot_rows.iter().enumerate() {
+            if values.is_none() {
+                for col in 1..=LAYOUT_HEADERS.len() as u32 {
+                    worksheet
+                        .get_style_mut((col, row_idx as u32 + 2))
+                        .set_background_color(EMPTY_SLOT_COLOR.to_string());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 各槽位的内容（模块类型、规格型号、模块名称、子系统、通道数、备注），空槽为None
+    ///
+    /// 列出已有机架及待订购机架的全部IO槽位，冗余控制器的备用CPU列在其占用的槽位
+    fn slot_rows(
+        placed_modules: &[PlacedModule<'_>],
+        redundant_controller: Option<&EquipmentData>,
+        rack_count: u32,
+    ) -> Vec<(SlotPosition, Option<[String; 6]>)> {
+        let mut slots: HashMap<SlotPosition, [String; 6]> = HashMap::new();
+        if let Some(controller) = redundant_controller {
+            slots.insert(STANDBY_CONTROLLER_SLOT, [
+                "CPU".to_string(),
+                controller.spec_model.clone(),
+                controller.equipment_name.clone(),
+                controller.subsystem.clone(),
+                "/".to_string(),
+                "冗余控制器备用CPU".to_string(),
+            ]);
+        }
+        for placed in placed_modules {
+            let module = &placed.module;
+            let partner = placed.partner_position();
+            for position in placed.occupied_positions() {
+                let mut notes = Vec::new();
+                if let Some(partner) = partner {
+                    if position == placed.position {
+                        notes.push(format!("冗余主模块，伙伴槽位{}_{}", partner.rack, partner.slot));
+                    } else {
+                        notes.push(format!("冗余伙伴，主模块槽位{}_{}", placed.position.rack, placed.position.slot));
+                    }
+                }
+                if module.spare {
+                    notes.push("备用模块".to_string());
+                }
+                if position.rack > rack_count {
+                    notes.push("待订购机架".to_string());
+                }
+                slots.insert(position, [
+                    module.mapping.channel_type.to_string(),
+                    module.equipment.spec_model.clone(),
+                    module.equipment.equipment_name.clone(),
+                    module.equipment.subsystem.clone(),
+                    module.mapping.channels.to_string(),
+                    notes.join("；"),
+                ]);
+            }
+        }
+
+        let last_rack = slots.keys().map(|position| position.rack).max().unwrap_or(0).max(rack_count);
+        let mut rows = Vec::new();
+        for rack in 1..=last_rack {
+            for slot in START_SLOT..START_SLOT + AVAILABLE_SLOTS_PER_RACK {
+                let position = SlotPosition { rack, slot };
+                rows.push((position, slots.remove(&position)));
+            }
+        }
+        rows
+    }
+}
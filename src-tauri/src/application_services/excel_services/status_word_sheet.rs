@@ -0,0 +1,127 @@
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IOExcelService, IoError};
+use crate::model_domain::address_plan_model::AddressPlan;
+use crate::model_domain::io_table_model::{comm_address_field, IOTable, MAIN_PLC_ADDRESS_FIELD};
+use umya_spreadsheet::Spreadsheet;
+
+/// 状态字映射表名称后缀
+pub const STATUS_WORD_SHEET_SUFFIX: &str = "状态字映射";
+
+/// 状态字映射表表头
+const STATUS_WORD_HEADERS: [&str; 8] = [
+    "序号", "通道位号", "变量名称（HMI）", "状态字通讯地址", "字内位", "报警点位", "PLC地址", "通讯地址"
+];
+
+/// 状态字映射表的默认列宽
+const COLUMN_WIDTH: f64 = 18.0;
+
+/// 状态字中的一个报警位
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusWordBit {
+    pub channel_tag: String,
+    pub variable_name: String,
+    /// 状态字的通讯地址
+    pub word_address: u32,
+    /// 字内位号
+    pub bit: u32,
+    /// 报警点位名称，例如LL报警
+    pub point_name: String,
+    pub plc_address: String,
+    pub comm_address: String,
+}
+
+/// 状态字映射表服务
+/// 报警位打包到状态字时，列出每个状态字中各位对应的报警点位
+pub struct StatusWordSheetService;
+
+impl StatusWordSheetService {
+    /// 收集点表中打包到状态字的全部报警位
+    pub fn collect_bits(io_table: &IOTable, plan: &AddressPlan) -> Vec<StatusWordBit> {
+        let mut bits = Vec::new();
+        for row in &io_table.rows {
+            for (field, plc_address) in row.plc_address_fields() {
+                if field == MAIN_PLC_ADDRESS_FIELD || !IOExcelService::is_bool_address_field(field) {
+                    continue;
+                }
+                let Some(plc_address) = plc_address else {
+                    continue;
+                };
+                // 非模拟量点位的报警地址为"/"，解析失败直接跳过
+                let Ok(bool_addr) = plan.parse_bool(plc_address) else {
+                    continue;
+                };
+
+                let (word_byte, bit) = plan.status_word_position(bool_addr);
+                bits.push(StatusWordBit {
+                    channel_tag: row.channel_tag.as_deref().unwrap_or_default().to_string(),
+                    variable_name: row.variable_name_hmi.as_deref().unwrap_or_default().to_string(),
+                    word_address: plan.status_word_point(word_byte).address,
+                    bit,
+                    point_name: field.trim_end_matches("_PLC地址").to_string(),
+                    plc_address: plc_address.to_string(),
+                    comm_address: row
+                        .get_field_by_name(&comm_address_field(field))
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                });
+            }
+        }
+        bits
+    }
+
+    /// 在工作簿中新增状态字映射表
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        io_table: &IOTable,
+        plan: &AddressPlan,
+    ) -> Result<(), IoError> {
+        let bits = Self::collect_bits(io_table, plan);
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建状态字映射表失败: {}", e)))?;
+
+        let rows = bits.iter().enumerate().map(|(row_idx, bit)| [
+            (row_idx + 1).to_string(),
+            bit.channel_tag.clone(),
+            bit.variable_name.clone(),
+            bit.word_address.to_string(),
+            bit.bit.to_string(),
+            bit.point_name.clone(),
+            bit.plc_address.clone(),
+            bit.comm_address.clone(),
+        ]);
+        write_simple_table(worksheet, 1, &STATUS_WORD_HEADERS, rows, &[COLUMN_WIDTH; STATUS_WORD_HEADERS.len()]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::address_services::address_allocator::AddressAllocator;
+    use crate::application_services::excel_services::io_excel_services::DataType;
+    use crate::model_domain::io_table_model::IOTableRow;
+
+    #[test]
+    fn test_collect_bits_of_packed_alarms() {
+        let mut plan = AddressPlan::default();
+        plan.modbus.pack_alarm_bits = true;
+        let mut allocator = AddressAllocator::new(&plan).unwrap();
+
+        let mut io_table = IOTable::new("测试站_IO表".to_string());
+        for (data_type, with_sub_points) in [(DataType::REAL, true), (DataType::BOOL, false)] {
+            let mut row = IOTableRow::default();
+            allocator.assign_row_addresses(&mut row, data_type, with_sub_points).unwrap();
+            io_table.add_row(row);
+        }
+
+        // 只有模拟量点位的5个报警位进入状态字
+        let bits = StatusWordSheetService::collect_bits(&io_table, &plan);
+        assert_eq!(bits.len(), 5);
+        assert_eq!(bits[0].point_name, "LL报警");
+        assert_eq!(bits[0].word_address, 43011);
+        assert_eq!(bits[4].bit, 4);
+        assert_eq!(bits[4].comm_address, "43011.4");
+    }
+}
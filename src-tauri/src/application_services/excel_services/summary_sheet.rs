@@ -0,0 +1,159 @@
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IOChannelType, IoError};
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::model_domain::generation_report_model::GenerationReport;
+use crate::model_domain::io_table_model::IOTable;
+use umya_spreadsheet::Spreadsheet;
+
+/// 汇总表名称后缀
+pub const SUMMARY_SHEET_SUFFIX: &str = "汇总";
+
+/// 通道统计表头
+const CHANNEL_HEADERS: [&str; 6] = ["通道类型", "通道总数", "非备用通道", "备用通道", "已填写通道", "利用率(%)"];
+
+/// 地址区统计表头
+const AREA_HEADERS: [&str; 5] = ["地址区", "地址范围", "已用", "容量", "利用率(%)"];
+
+/// 汇总表列宽
+const COLUMN_WIDTHS: [f64; 6] = [18.0, 26.0, 12.0, 12.0, 12.0, 12.0];
+
+/// 参与统计的通道类型
+const CHANNEL_TYPES: [IOChannelType; 4] = [
+    IOChannelType::AI,
+    IOChannelType::AO,
+    IOChannelType::DI,
+    IOChannelType::DO,
+];
+
+/// 单个通道类型的统计
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChannelSummary {
+    pub total: u32,
+    pub spare: u32,
+    /// 已填写位号的通道数
+    pub filled: u32,
+}
+
+impl ChannelSummary {
+    /// 未预留为备用的通道数
+    pub fn non_spare(&self) -> u32 {
+        self.total - self.spare
+    }
+
+    /// 利用率（百分比，按非备用通道计），没有通道时为0
+    pub fn utilization(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.non_spare() as f64 * 100.0 / self.total as f64
+    }
+}
+
+/// 汇总表服务
+/// 按通道类型统计通道总数、备用、已填写位号的通道数和利用率，并列出各地址区的使用情况
+pub struct SummarySheetService;
+
+impl SummarySheetService {
+    /// 在工作簿中新增汇总表
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        io_table: &IOTable<'_>,
+        report: &GenerationReport,
+    ) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建汇总表失败: {}", e)))?;
+
+        let summaries = Self::summarize(io_table);
+        let mut total = ChannelSummary::default();
+        let mut rows: Vec<[String; 6]> = Vec::new();
+        for (channel_type, summary) in &summaries {
+            total.total += summary.total;
+            total.spare += summary.spare;
+            total.filled += summary.filled;
+            rows.push(Self::channel_row(&channel_type.to_string(), summary));
+        }
+        rows.push(Self::channel_row("合计", &total));
+
+        let mut row = write_simple_table(worksheet, 1, &CHANNEL_HEADERS, rows, &[]);
+
+        let soft_points = io_table.rows.iter().filter(|r| r.rack_and_slot().is_none()).count();
+        row += 1;
+        worksheet.get_cell_mut((1, row)).set_value("软点位数".to_string());
+        worksheet.get_cell_mut((2, row)).set_value(soft_points.to_string());
+        row += 2;
+
+        let area_rows = report.area_utilization.iter()
+            .map(|area| [
+                area.area.clone(),
+                area.range.clone(),
+                area.used.to_string(),
+                area.capacity.to_string(),
+                format!("{:.1}", area.utilization),
+            ]);
+        write_simple_table(worksheet, row, &AREA_HEADERS, area_rows, &COLUMN_WIDTHS);
+
+        Ok(())
+    }
+
+    /// 按模块类型统计各类型的通道数、备用通道数及已填写位号的通道数
+    pub fn summarize(io_table: &IOTable<'_>) -> Vec<(IOChannelType, ChannelSummary)> {
+        CHANNEL_TYPES
+            .iter()
+            .map(|channel_type| {
+                let mut summary = ChannelSummary::default();
+                let rows = io_table.rows.iter().filter(|row| {
+                    row.module_type.as_deref().and_then(IOChannelType::from_code) == Some(*channel_type)
+                });
+                for row in rows {
+                    summary.total += 1;
+                    if row.is_spare {
+                        summary.spare += 1;
+                    }
+                    if row.tag.as_deref().map(str::trim).is_some_and(|tag| !tag.is_empty() && tag != "/" && tag != SPARE_LABEL) {
+                        summary.filled += 1;
+                    }
+                }
+                (*channel_type, summary)
+            })
+            .collect()
+    }
+
+    fn channel_row(label: &str, summary: &ChannelSummary) -> [String; 6] {
+        [
+            label.to_string(),
+            summary.total.to_string(),
+            summary.non_spare().to_string(),
+            summary.spare.to_string(),
+            summary.filled.to_string(),
+            format!("{:.1}", summary.utilization()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::io_table_model::IOTableRow;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_summarize_channels_per_type() {
+        let mut io_table = IOTable::new("测试站_IO表".to_string());
+        let rows = [("AI", false, "PT-101"), ("AI", false, ""), ("AI", true, SPARE_LABEL), ("DO", false, "XV-101"), ("/", false, "")];
+        for (module_type, is_spare, tag) in rows {
+            io_table.add_row(IOTableRow {
+                module_type: Some(Cow::Borrowed(module_type)),
+                tag: Some(Cow::Borrowed(tag)),
+                is_spare,
+                ..Default::default()
+            });
+        }
+
+        let summaries = SummarySheetService::summarize(&io_table);
+        assert_eq!(summaries[0], (IOChannelType::AI, ChannelSummary { total: 3, spare: 1, filled: 1 }));
+        assert_eq!(summaries[1].1.total, 0);
+        assert_eq!(summaries[3].1, ChannelSummary { total: 1, spare: 0, filled: 1 });
+        assert!((summaries[0].1.utilization() - 66.666).abs() < 0.01);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::application_services::excel_services::io_excel_services::{
+    get_column_letter, write_simple_table, IOChannelType, IOExcelService, IoError,
+};
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::io_table_model::{IOTable, IO_TABLE_HEADERS};
+use umya_spreadsheet::Spreadsheet;
+
+/// 只有模拟量点位使用的列：从量程低限到维护使能开关点位_通讯地址
+const ANALOG_ONLY_RANGE: (&str, &str) = ("量程低限", "维护使能开关点位_通讯地址");
+
+/// 分类点位表的默认列宽
+const COLUMN_WIDTH: f64 = 15.0;
+
+/// 分类点位表服务
+/// 按AI/AO/DI/DO分别列出点位，只保留该类型相关的列；
+/// 单元格引用主点表，工程师只需在主点表中填写
+pub struct TypePointSheetService;
+
+impl TypePointSheetService {
+    /// 在工作簿中新增一种通道类型的点位表，该类型没有点位时不生成
+    pub fn write_sheet(
+        spreadsheet: &mut Spreadsheet,
+        sheet_name: &str,
+        main_sheet_name: &str,
+        io_table: &IOTable<'_>,
+        template: &ColumnTemplate,
+        channel_type: IOChannelType,
+    ) -> Result<(), IoError> {
+        // 主点表中该类型点位的行号
+        let source_rows: Vec<u32> = io_table.rows.iter()
+            .enumerate()
+            .filter(|(_, row)| row.module_type.as_deref().and_then(IOChannelType::from_code) == Some(channel_type))
+            .map(|(row_idx, _)| row_idx as u32 + 2)
+            .collect();
+        if source_rows.is_empty() {
+            return Ok(());
+        }
+
+        let worksheet = spreadsheet
+            .new_sheet(sheet_name)
+            .map_err(|e| IoError::Unknown(format!("创建{}点位表失败: {}", channel_type, e)))?;
+
+        let columns = Self::columns_for(template, channel_type);
+        // 先写表头和列宽，单元格为引用主点表的公式
+        let headers: Vec<&str> = columns.iter().map(|(_, header)| *header).collect();
+        write_simple_table(worksheet, 1, &headers, Vec::<Vec<String>>::new(), &vec![COLUMN_WIDTH; headers.len()]);
+
+        let sheet_ref = format!("'{}'", main_sheet_name.replace('\'', "''"));
+        for (row_idx, source_row) in source_rows.iter().enumerate() {
+            let row = row_idx as u32 + 2;
+            for (col_idx, (source_col, _)) in columns.iter().enumerate() {
+                let col = col_idx as u32 + 1;
+                let source = format!("{}!{}{}", sheet_ref, get_column_letter(*source_col), source_row);
+                worksheet
+                    .get_cell_mut((col, row))
+                    .set_formula(format!("=IF(ISBLANK({}),\"\",{})", source, source));
+                IOExcelService::apply_common_cell_style(worksheet.get_style_mut((col, row)), false);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 该类型相关的列（主点表列号, 表头），按列模板的顺序排列；开关量点位不含量程、报警及维护列
+    pub fn columns_for(template: &ColumnTemplate, channel_type: IOChannelType) -> Vec<(u32, &str)> {
+        let is_analog = matches!(channel_type, IOChannelType::AI | IOChannelType::AO);
+        let position = |header: &str| IO_TABLE_HEADERS.iter().position(|h| *h == header);
+        let analog_only = position(ANALOG_ONLY_RANGE.0).unwrap_or_default()..=position(ANALOG_ONLY_RANGE.1).unwrap_or_default();
+
+        template.columns.iter()
+            .enumerate()
+            .filter(|(_, column)| is_analog || !position(&column.field).is_some_and(|idx| analog_only.contains(&idx)))
+            .map(|(col_idx, column)| (col_idx as u32 + 1, column.header_text()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digital_sheets_skip_analog_columns() {
+        let template = ColumnTemplate::standard();
+        let analog = TypePointSheetService::columns_for(&template, IOChannelType::AI);
+        assert_eq!(analog.len(), IO_TABLE_HEADERS.len());
+
+        let digital = TypePointSheetService::columns_for(&template, IOChannelType::DI);
+        let headers: Vec<&str> = digital.iter().map(|(_, header)| *header).collect();
+        assert!(!headers.contains(&"量程低限"));
+        assert!(!headers.contains(&"维护使能开关点位_通讯地址"));
+        assert!(headers.contains(&"掉电保护"));
+        // 保留的列仍指向主点表中的原列
+        assert_eq!(digital.last(), Some(&(IO_TABLE_HEADERS.len() as u32, "字序")));
+    }
+}
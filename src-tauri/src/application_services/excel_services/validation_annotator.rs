@@ -0,0 +1,94 @@
+use crate::application_services::excel_services::io_excel_reader::parse_cell_reference;
+use crate::application_services::excel_services::io_excel_services::{write_simple_table, IoError};
+use crate::model_domain::validation_model::{Severity, ValidationIssue, ValidationReport};
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::path::Path;
+use umya_spreadsheet::structs::Comment;
+use umya_spreadsheet::Spreadsheet;
+
+/// 校验结果工作表名称
+pub const VALIDATION_SHEET_NAME: &str = "校验结果";
+
+/// 校验结果表头
+const VALIDATION_HEADERS: [&str; 6] = ["单元格", "行号", "列名", "严重程度", "规则", "说明"];
+
+/// 校验结果表的列宽
+const COLUMN_WIDTHS: [f64; 6] = [10.0, 8.0, 20.0, 10.0, 18.0, 60.0];
+
+/// 存在错误的单元格背景色
+pub(crate) const ERROR_CELL_COLOR: &str = "FFFF7C80";
+/// 只有警告的单元格背景色
+pub(crate) const WARNING_CELL_COLOR: &str = "FFFFD966";
+
+/// 批注作者
+const COMMENT_AUTHOR: &str = "点表校验";
+
+/// 校验结果标注服务
+/// 在点表副本中将有问题的单元格标色并添加批注，同时附加校验结果工作表
+pub struct ValidationAnnotator;
+
+impl ValidationAnnotator {
+    /// 读取点表文件并生成标注后的副本内容
+    pub fn annotate_file(source: &Path, report: &ValidationReport) -> Result<Vec<u8>, IoError> {
+        let mut spreadsheet = umya_spreadsheet::reader::xlsx::read(source)
+            .map_err(|e| IoError::ExcelImport(e.to_string()))?;
+        Self::annotate(&mut spreadsheet, report)?;
+
+        let mut buffer = Cursor::new(Vec::new());
+        umya_spreadsheet::writer::xlsx::write_writer(&spreadsheet, &mut buffer)?;
+        Ok(buffer.into_inner())
+    }
+
+    /// 在校验的工作表中标注问题单元格，并新增校验结果工作表
+    pub fn annotate(spreadsheet: &mut Spreadsheet, report: &ValidationReport) -> Result<(), IoError> {
+        // 同一单元格的多个问题合并为一条批注
+        let mut cells: BTreeMap<(u32, u32), Vec<&ValidationIssue>> = BTreeMap::new();
+        for issue in &report.issues {
+            if let Some((col, row)) = parse_cell_reference(&issue.cell) {
+                cells.entry((row, col)).or_default().push(issue);
+            }
+        }
+
+        let worksheet = spreadsheet
+            .get_sheet_by_name_mut(&report.sheet_name)
+            .ok_or_else(|| IoError::DataQuery(format!("工作表{}不存在", report.sheet_name)))?;
+        for ((row, col), issues) in &cells {
+            let has_error = issues.iter().any(|issue| issue.severity == Severity::Error);
+            let color = if has_error { ERROR_CELL_COLOR } else { WARNING_CELL_COLOR };
+            worksheet.get_style_mut((*col, *row)).set_background_color(color.to_string());
+
+            let text = issues
+                .iter()
+                .map(|issue| format!("[{}] {}", issue.severity, issue.message))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let mut comment = Comment::default();
+            comment.new_comment((*col, *row));
+            comment.set_author(COMMENT_AUTHOR);
+            comment.set_text_string(text);
+            worksheet.add_comments(comment);
+        }
+
+        Self::write_summary_sheet(spreadsheet, report)
+    }
+
+    /// 新增校验结果工作表，逐条列出问题
+    fn write_summary_sheet(spreadsheet: &mut Spreadsheet, report: &ValidationReport) -> Result<(), IoError> {
+        let worksheet = spreadsheet
+            .new_sheet(VALIDATION_SHEET_NAME)
+            .map_err(|e| IoError::Unknown(format!("创建校验结果表失败: {}", e)))?;
+
+        let rows = report.issues.iter().map(|issue| [
+            issue.cell.clone(),
+            issue.row.to_string(),
+            issue.column.clone(),
+            issue.severity.to_string(),
+            issue.rule.to_string(),
+            issue.message.clone(),
+        ]);
+        write_simple_table(worksheet, 1, &VALIDATION_HEADERS, rows, &COLUMN_WIDTHS);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::application_services::excel_services::io_excel_services::{
+    EquipmentData, IOChannelType, IOExcelService, IoError, AVAILABLE_SLOTS_PER_RACK,
+};
+use crate::application_services::io_planning_services::spare_policy::SparePolicy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 机架型号
+const RACK_MODEL: &str = "LK117";
+/// 按AI → AO → DI → DO顺序输出建议清单
+const SIZING_ORDER: [IOChannelType; 4] = [
+    IOChannelType::AI,
+    IOChannelType::AO,
+    IOChannelType::DI,
+    IOChannelType::DO,
+];
+
+/// 单个信号
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalEntry {
+    pub channel_type: IOChannelType,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// 信号清单，可同时给出各类型数量和逐条信号
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SignalList {
+    pub counts: HashMap<IOChannelType, u32>,
+    pub signals: Vec<SignalEntry>,
+}
+
+impl SignalList {
+    /// 汇总各类型的信号数量
+    pub fn totals(&self) -> HashMap<IOChannelType, u32> {
+        let mut totals = self.counts.clone();
+        for signal in &self.signals {
+            *totals.entry(signal.channel_type).or_insert(0) += 1;
+        }
+        totals
+    }
+}
+
+/// 单个通道类型的选型结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeSizing {
+    pub channel_type: IOChannelType,
+    /// 信号数
+    pub signal_count: u32,
+    /// 选用的模块型号
+    pub model_key: String,
+    /// 逻辑模块数（冗余模块对计为1）
+    pub module_count: u32,
+    /// 已安装通道数
+    pub installed_channels: u32,
+    /// 备用通道数
+    pub spare_channels: u32,
+    /// 是否冗余配置
+    pub redundant: bool,
+}
+
+/// IO选型结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IoSizingResult {
+    /// 建议设备清单，可直接用于生成点表
+    pub equipment_list: Vec<EquipmentData>,
+    /// 各类型选型明细
+    pub details: Vec<TypeSizing>,
+    /// 所需机架数
+    pub rack_count: u32,
+}
+
+/// 根据信号清单反推IO模块和机架数量
+pub struct IoSizingService;
+
+impl IoSizingService {
+    /// 按信号清单和备用策略计算所需模块及机架
+    ///
+    /// `redundant_types` 中的类型在型号支持时按冗余模块对配置
+    pub fn size_from_signals(
+        signal_list: &SignalList,
+        spare_policy: &SparePolicy,
+        station_name: &str,
+        redundant_types: &[IOChannelType]
+    ) -> Result<IoSizingResult, IoError> {
+        let totals = signal_list.totals();
+        let mut equipment_list = Vec::new();
+        let mut details = Vec::new();
+        let mut physical_modules = 0;
+
+        for channel_type in SIZING_ORDER {
+            let signal_count = totals.get(&channel_type).copied().unwrap_or(0);
+            if signal_count == 0 {
+                continue;
+            }
+
+            let mapping = IOExcelService::default_mapping_for(channel_type).ok_or_else(|| {
+                IoError::DataQuery(format!("硬件目录中没有 {} 类型的模块", channel_type))
+            })?;
+            let module_count = spare_policy.extra_modules(channel_type, signal_count, 0, mapping.channels)?;
+            let installed_channels = module_count * mapping.channels;
+            let redundant = mapping.supports_redundancy && redundant_types.contains(&channel_type);
+            let quantity = if redundant { module_count * 2 } else { module_count };
+            physical_modules += quantity;
+
+            equipment_list.push(EquipmentData {
+                equipment_name: Self::module_name(channel_type).to_string(),
+                spec_model: mapping.model_key.clone(),
+                quantity,
+                station_name: station_name.to_string(),
+                subsystem: String::new(),
+                redundant,
+            });
+            details.push(TypeSizing {
+                channel_type,
+                signal_count,
+                model_key: mapping.model_key.clone(),
+                module_count,
+                installed_channels,
+                spare_channels: installed_channels - signal_count,
+                redundant,
+            });
+        }
+
+        let rack_count = u32::max(physical_modules.div_ceil(AVAILABLE_SLOTS_PER_RACK), 1);
+        equipment_list.push(EquipmentData {
+            equipment_name: "机架".to_string(),
+            spec_model: RACK_MODEL.to_string(),
+            quantity: rack_count,
+            station_name: station_name.to_string(),
+            subsystem: String::new(),
+            redundant: false,
+        });
+
+        Ok(IoSizingResult {
+            equipment_list,
+            details,
+            rack_count,
+        })
+    }
+
+    /// 建议清单中的设备名称
+    fn module_name(channel_type: IOChannelType) -> &'static str {
+        match channel_type {
+            IOChannelType::AI => "模拟量输入模块",
+            IOChannelType::AO => "模拟量输出模块",
+            IOChannelType::DI => "数字量输入模块",
+            IOChannelType::DO => "数字量输出模块",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::io_planning_services::spare_policy::SpareRule;
+
+    #[test]
+    fn test_size_from_signals_with_spare() {
+        let mut signal_list = SignalList::default();
+        signal_list.counts.insert(IOChannelType::AI, 14);
+        signal_list.counts.insert(IOChannelType::DI, 30);
+        signal_list.signals.push(SignalEntry {
+            channel_type: IOChannelType::DO,
+            tag: Some("XV-101".to_string()),
+            description: None,
+        });
+
+        let mut spare_policy = SparePolicy::default();
+        spare_policy.rules.insert(IOChannelType::AI, SpareRule::Percentage(20.0));
+        spare_policy.rules.insert(IOChannelType::DI, SpareRule::Fixed(4));
+
+        let result = IoSizingService::size_from_signals(
+            &signal_list, &spare_policy, "测试站", &[IOChannelType::AI]
+        ).unwrap();
+
+        // AI：2块模块16通道只剩2个备用，不满足20%（4个），需要3块；冗余配置为6块
+        let ai = &result.details[0];
+        assert_eq!(ai.module_count, 3);
+        assert_eq!(ai.spare_channels, 10);
+        assert_eq!(result.equipment_list[0].quantity, 6);
+        // DI：30个信号加4个备用需要3块
+        assert_eq!(result.details[1].module_count, 3);
+        // DO：1块
+        assert_eq!(result.details[2].module_count, 1);
+        // 共10块模块，1个机架
+        assert_eq!(result.rack_count, 1);
+        assert_eq!(result.equipment_list.last().unwrap().spec_model, RACK_MODEL);
+    }
+
+    #[test]
+    fn test_full_percentage_spare_is_rejected() {
+        let mut signal_list = SignalList::default();
+        signal_list.counts.insert(IOChannelType::AO, 1);
+        let mut spare_policy = SparePolicy::default();
+        spare_policy.rules.insert(IOChannelType::AO, SpareRule::Percentage(100.0));
+
+        let result = IoSizingService::size_from_signals(&signal_list, &spare_policy, "测试站", &[]);
+        assert!(matches!(result, Err(IoError::InvalidSparePolicy(_))));
+    }
+}
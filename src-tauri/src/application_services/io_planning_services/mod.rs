@@ -0,0 +1,5 @@
+pub mod rack_planning_service;
+pub mod placement_strategy;
+pub mod spare_policy;
+pub mod io_sizing_service;
+pub mod table_merge_service;
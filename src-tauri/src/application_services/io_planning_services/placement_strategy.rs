@@ -0,0 +1,514 @@
+use crate::application_services::excel_services::io_excel_services::{
+    EquipmentData, GenerationOptions, IOChannelType, IOExcelService, IoError, ModelChannelMapping,
+    AVAILABLE_SLOTS_PER_RACK, START_SLOT,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 默认的IO类型排布顺序
+const IO_TYPE_ORDER: [IOChannelType; 4] = [
+    IOChannelType::AI,
+    IOChannelType::AO,
+    IOChannelType::DI,
+    IOChannelType::DO,
+];
+
+/// 冗余控制器的备用CPU占用的槽位（1号机架的第一个IO槽位）
+pub const STANDBY_CONTROLLER_SLOT: SlotPosition = SlotPosition { rack: 1, slot: START_SLOT };
+
+/// 模块排布策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlacementStrategy {
+    /// 按AI → AO → DI → DO顺序依次填满机架
+    #[default]
+    Sequential,
+    /// 各类型模块轮流交错排布
+    Interleaved,
+    /// 按子系统分组连续排布，组内按AI → AO → DI → DO顺序
+    BySubsystem,
+    /// 将模块均匀分散到各机架，降低单机架故障影响
+    EvenSpread,
+}
+
+/// 槽位位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SlotPosition {
+    pub rack: u32,
+    pub slot: u32,
+}
+
+/// 单个IO模块实例
+#[derive(Debug, Clone)]
+pub struct ModuleInstance<'a> {
+    pub equipment: &'a EquipmentData,
+    pub mapping: &'static ModelChannelMapping,
+    /// 同一设备（设备名称及规格型号）下的序号（从1开始），冗余模块按对计数
+    pub instance: u32,
+    /// 是否为冗余模块对
+    pub redundant: bool,
+    /// 是否为按备用策略追加的备用模块
+    pub spare: bool,
+}
+
+impl ModuleInstance<'_> {
+    /// 模块标识，格式为"设备名称#规格型号#序号"，用于槽位指定和点位标识
+    ///
+    /// 序号只在同一设备内计数，设备清单中增删或调整其他设备不影响该标识
+    pub fn key(&self) -> String {
+        format!("{}#{}#{}", self.equipment.equipment_name, self.equipment.spec_model, self.instance)
+    }
+
+    /// 占用的槽位数，冗余模块对占用两个相邻槽位
+    pub fn width(&self) -> u32 {
+        if self.redundant { 2 } else { 1 }
+    }
+}
+
+/// 已分配槽位的模块
+#[derive(Debug, Clone)]
+pub struct PlacedModule<'a> {
+    pub module: ModuleInstance<'a>,
+    pub position: SlotPosition,
+}
+
+impl PlacedModule<'_> {
+    /// 冗余伙伴模块的位置（紧邻主模块的下一个槽位）
+    pub fn partner_position(&self) -> Option<SlotPosition> {
+        self.module.redundant.then(|| SlotPosition {
+            rack: self.position.rack,
+            slot: self.position.slot + 1,
+        })
+    }
+
+    /// 模块占用的全部槽位
+    pub fn occupied_positions(&self) -> impl Iterator<Item = SlotPosition> + '_ {
+        (0..self.module.width()).map(move |offset| SlotPosition {
+            rack: self.position.rack,
+            slot: self.position.slot + offset,
+        })
+    }
+}
+
+/// 模块排布服务
+pub struct ModulePlacementService;
+
+impl ModulePlacementService {
+    /// 将设备清单展开为单个模块实例，保持设备清单顺序
+    ///
+    /// 冗余配置的设备每两块模块展开为一个模块对
+    pub fn expand_modules(equipment_list: &[EquipmentData]) -> Result<Vec<ModuleInstance<'_>>, IoError> {
+        let mut instance_counters: HashMap<(&str, &str), u32> = HashMap::new();
+        let mut modules = Vec::new();
+
+        for equipment in equipment_list {
+            let Some(mapping) = IOExcelService::find_model_mapping(&equipment.spec_model) else {
+                continue;
+            };
+
+            let redundant = equipment.redundant && mapping.supports_redundancy;
+            if equipment.redundant && !mapping.supports_redundancy {
+                log::warn!("型号 {} 不支持冗余配置，按普通模块处理", equipment.spec_model);
+            }
+
+            for _ in 0..Self::instance_count(equipment, mapping)? {
+                let counter = instance_counters
+                    .entry((equipment.equipment_name.as_str(), equipment.spec_model.as_str()))
+                    .or_insert(0);
+                *counter += 1;
+                modules.push(ModuleInstance {
+                    equipment,
+                    mapping,
+                    instance: *counter,
+                    redundant,
+                    spare: false,
+                });
+            }
+        }
+
+        Ok(modules)
+    }
+
+    /// 设备展开的模块实例数，冗余设备每两块模块组成一个模块对
+    ///
+    /// 冗余设备的数量不是偶数时返回错误
+    pub fn instance_count(equipment: &EquipmentData, mapping: &ModelChannelMapping) -> Result<u32, IoError> {
+        if !(equipment.redundant && mapping.supports_redundancy) {
+            return Ok(equipment.quantity);
+        }
+        if equipment.quantity % 2 != 0 {
+            return Err(IoError::InvalidPlacement(format!(
+                "冗余设备 {} 的数量 {} 不是偶数，无法组成模块对",
+                equipment.equipment_name, equipment.quantity
+            )));
+        }
+        Ok(equipment.quantity / 2)
+    }
+
+    /// 按选项中的策略和槽位指定为所有模块分配槽位
+    ///
+    /// 返回结果按机架、槽位排序
+    pub fn place<'a>(
+        equipment_list: &'a [EquipmentData],
+        rack_count: u32,
+        options: &GenerationOptions
+    ) -> Result<Vec<PlacedModule<'a>>, IoError> {
+        let modules = Self::add_spare_modules(Self::expand_modules(equipment_list)?, options)?;
+        let mut occupied = HashSet::new();
+        let mut placed = Vec::with_capacity(modules.len());
+
+        // 冗余控制器的备用CPU占用1号机架的第一个IO槽位
+        if IOExcelService::has_redundant_controller(equipment_list) {
+            occupied.insert(STANDBY_CONTROLLER_SLOT);
+        }
+
+        // 先放置指定了固定槽位的模块
+        let mut free_modules = Vec::with_capacity(modules.len());
+        for module in modules {
+            match options.slot_overrides.get(&module.key()) {
+                Some(position) => {
+                    Self::check_override(&module, *position, &occupied)?;
+                    Self::check_rack(position.rack, rack_count, options)?;
+                    Self::occupy(&mut occupied, &mut placed, module, *position);
+                }
+                None => free_modules.push(module),
+            }
+        }
+
+        // 其余模块按策略排布
+        match options.placement_strategy {
+            PlacementStrategy::EvenSpread => {
+                let ordered = Self::order_by_type(free_modules);
+                let total_slots = ordered.iter().map(ModuleInstance::width).sum::<u32>() + occupied.len() as u32;
+                let required_racks = total_slots.div_ceil(AVAILABLE_SLOTS_PER_RACK);
+                let spread_racks = u32::max(u32::max(rack_count, required_racks), 1);
+
+                for (idx, module) in ordered.into_iter().enumerate() {
+                    let preferred_rack = idx as u32 % spread_racks + 1;
+                    let position = Self::next_free_slot_from(&occupied, preferred_rack, spread_racks, module.width());
+                    Self::check_rack(position.rack, rack_count, options)?;
+                    Self::occupy(&mut occupied, &mut placed, module, position);
+                }
+            }
+            strategy => {
+                let ordered = match strategy {
+                    PlacementStrategy::Interleaved => Self::order_interleaved(free_modules),
+                    PlacementStrategy::BySubsystem => Self::order_by_subsystem(free_modules),
+                    _ => Self::order_by_type(free_modules),
+                };
+
+                for module in ordered {
+                    let position = Self::next_free_slot_from(&occupied, 1, 1, module.width());
+                    Self::check_rack(position.rack, rack_count, options)?;
+                    Self::occupy(&mut occupied, &mut placed, module, position);
+                }
+            }
+        }
+
+        placed.sort_by_key(|placed_module| placed_module.position);
+        Ok(placed)
+    }
+
+    /// 按备用策略为各类型追加备用模块
+    ///
+    /// 已用通道数取选项中的信号数，未给出的类型视为已安装通道全部接有信号（与通道统计一致）；
+    /// 备用模块沿用该类型最后一块模块的设备和型号，序号依次递增，按普通模块占用一个槽位
+    fn add_spare_modules<'a>(
+        mut modules: Vec<ModuleInstance<'a>>,
+        options: &GenerationOptions
+    ) -> Result<Vec<ModuleInstance<'a>>, IoError> {
+        for channel_type in IO_TYPE_ORDER {
+            let of_type = |module: &&ModuleInstance<'_>| module.mapping.channel_type == channel_type;
+            let Some(last) = modules.iter().rev().find(of_type).cloned() else {
+                continue;
+            };
+            let installed: u32 = modules.iter().filter(of_type).map(|module| module.mapping.channels).sum();
+            let used = options.signal_counts.get(&channel_type).copied().unwrap_or(installed);
+            let extra = options.spare_policy.extra_modules(channel_type, used, installed, last.mapping.channels)?;
+            for offset in 1..=extra {
+                modules.push(ModuleInstance {
+                    instance: last.instance + offset,
+                    redundant: false,
+                    spare: true,
+                    ..last.clone()
+                });
+            }
+        }
+        Ok(modules)
+    }
+
+    /// 按AI → AO → DI → DO排序，同类型保持原有顺序
+    fn order_by_type(modules: Vec<ModuleInstance<'_>>) -> Vec<ModuleInstance<'_>> {
+        let mut ordered = modules;
+        ordered.sort_by_key(|module| Self::type_rank(module.mapping.channel_type));
+        ordered
+    }
+
+    /// 各类型轮流取一个模块，直到全部取完
+    fn order_interleaved(modules: Vec<ModuleInstance<'_>>) -> Vec<ModuleInstance<'_>> {
+        let mut queues: Vec<Vec<ModuleInstance<'_>>> = IO_TYPE_ORDER.iter().map(|_| Vec::new()).collect();
+        for module in modules {
+            queues[Self::type_rank(module.mapping.channel_type)].push(module);
+        }
+
+        let total: usize = queues.iter().map(Vec::len).sum();
+        let mut iters: Vec<_> = queues.into_iter().map(Vec::into_iter).collect();
+        let mut ordered = Vec::with_capacity(total);
+        while ordered.len() < total {
+            for iter in iters.iter_mut() {
+                if let Some(module) = iter.next() {
+                    ordered.push(module);
+                }
+            }
+        }
+        ordered
+    }
+
+    /// 按子系统首次出现的顺序分组，组内按IO类型排序
+    fn order_by_subsystem(modules: Vec<ModuleInstance<'_>>) -> Vec<ModuleInstance<'_>> {
+        let mut subsystem_order: Vec<&str> = Vec::new();
+        for module in &modules {
+            let subsystem = module.equipment.subsystem.as_str();
+            if !subsystem_order.contains(&subsystem) {
+                subsystem_order.push(subsystem);
+            }
+        }
+
+        let mut ordered = modules;
+        ordered.sort_by_key(|module| {
+            let group = subsystem_order
+                .iter()
+                .position(|s| *s == module.equipment.subsystem)
+                .unwrap_or(usize::MAX);
+            (group, Self::type_rank(module.mapping.channel_type))
+        });
+        ordered
+    }
+
+    /// 记录模块位置并标记其占用的全部槽位
+    fn occupy<'a>(
+        occupied: &mut HashSet<SlotPosition>,
+        placed: &mut Vec<PlacedModule<'a>>,
+        module: ModuleInstance<'a>,
+        position: SlotPosition
+    ) {
+        let placed_module = PlacedModule { module, position };
+        occupied.extend(placed_module.occupied_positions());
+        placed.push(placed_module);
+    }
+
+    /// IO类型在默认顺序中的位置
+    fn type_rank(channel_type: IOChannelType) -> usize {
+        IO_TYPE_ORDER.iter().position(|t| *t == channel_type).unwrap_or(IO_TYPE_ORDER.len())
+    }
+
+    /// 从指定机架开始查找连续 `width` 个空闲槽位，找不到时依次查找后续机架
+    ///
+    /// `wrap_racks` 大于1时先在前 `wrap_racks` 个机架内循环查找
+    fn next_free_slot_from(
+        occupied: &HashSet<SlotPosition>,
+        start_rack: u32,
+        wrap_racks: u32,
+        width: u32
+    ) -> SlotPosition {
+        let wrap_racks = u32::max(wrap_racks, 1);
+        for offset in 0..wrap_racks {
+            let rack = (start_rack - 1 + offset) % wrap_racks + 1;
+            if let Some(position) = Self::free_slot_in_rack(occupied, rack, width) {
+                return position;
+            }
+        }
+
+        let mut rack = wrap_racks + 1;
+        loop {
+            if let Some(position) = Self::free_slot_in_rack(occupied, rack, width) {
+                return position;
+            }
+            rack += 1;
+        }
+    }
+
+    /// 查找机架内第一段连续 `width` 个空闲槽位的起始位置
+    fn free_slot_in_rack(occupied: &HashSet<SlotPosition>, rack: u32, width: u32) -> Option<SlotPosition> {
+        (START_SLOT..=START_SLOT + AVAILABLE_SLOTS_PER_RACK - width)
+            .map(|slot| SlotPosition { rack, slot })
+            .find(|position| {
+                (0..width).all(|offset| !occupied.contains(&SlotPosition {
+                    rack,
+                    slot: position.slot + offset,
+                }))
+            })
+    }
+
+    /// 检查固定槽位是否合法且未被占用
+    fn check_override(
+        module: &ModuleInstance<'_>,
+        position: SlotPosition,
+        occupied: &HashSet<SlotPosition>
+    ) -> Result<(), IoError> {
+        // 冗余模块对的伙伴槽位也必须在范围内
+        let last_slot = START_SLOT + AVAILABLE_SLOTS_PER_RACK - module.width();
+        if position.rack == 0 || position.slot < START_SLOT || position.slot > last_slot {
+            return Err(IoError::InvalidPlacement(format!(
+                "模块 {} 指定的位置 {}机架{}槽 无效，槽位范围为 {}-{}",
+                module.key(), position.rack, position.slot, START_SLOT, last_slot
+            )));
+        }
+        let conflict = (0..module.width()).any(|offset| occupied.contains(&SlotPosition {
+            rack: position.rack,
+            slot: position.slot + offset,
+        }));
+        if conflict {
+            return Err(IoError::InvalidPlacement(format!(
+                "模块 {} 指定的位置 {}机架{}槽 已被占用",
+                module.key(), position.rack, position.slot
+            )));
+        }
+        Ok(())
+    }
+
+    /// 检查机架是否超出已有机架数
+    fn check_rack(rack: u32, rack_count: u32, options: &GenerationOptions) -> Result<(), IoError> {
+        if rack > rack_count && !options.allow_virtual_racks {
+            return Err(IoError::SlotOverflow {
+                rack_count,
+                required_rack: rack,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::io_planning_services::spare_policy::SpareRule;
+
+    fn equipment(model: &str, quantity: u32, subsystem: &str) -> EquipmentData {
+        EquipmentData {
+            subsystem: subsystem.to_string(),
+            ..EquipmentData::for_test(model, model, quantity)
+        }
+    }
+
+    fn models(placed: &[PlacedModule<'_>]) -> Vec<String> {
+        placed.iter().map(|p| p.module.key()).collect()
+    }
+
+    #[test]
+    fn test_interleaved_placement() {
+        let equipment_list = vec![equipment("LK610", 2, ""), equipment("LK411", 2, "")];
+        let options = GenerationOptions {
+            placement_strategy: PlacementStrategy::Interleaved,
+            ..Default::default()
+        };
+
+        let placed = ModulePlacementService::place(&equipment_list, 1, &options).unwrap();
+        assert_eq!(models(&placed), vec!["LK411#LK411#1", "LK610#LK610#1", "LK411#LK411#2", "LK610#LK610#2"]);
+        assert_eq!(placed[0].position, SlotPosition { rack: 1, slot: START_SLOT });
+    }
+
+    #[test]
+    fn test_even_spread_and_override() {
+        let equipment_list = vec![
+            equipment("LK117", 2, ""),
+            equipment("LK411", 2, "A"),
+            equipment("LK610", 2, "B"),
+        ];
+        let mut options = GenerationOptions {
+            placement_strategy: PlacementStrategy::EvenSpread,
+            ..Default::default()
+        };
+        options.slot_overrides.insert("LK610#LK610#2".to_string(), SlotPosition { rack: 2, slot: 11 });
+
+        let placed = ModulePlacementService::place(&equipment_list, 2, &options).unwrap();
+        let rack_of = |key: &str| placed.iter().find(|p| p.module.key() == key).unwrap().position.rack;
+        assert_eq!(rack_of("LK411#LK411#1"), 1);
+        assert_eq!(rack_of("LK411#LK411#2"), 2);
+        assert_eq!(rack_of("LK610#LK610#1"), 1);
+        assert_eq!(placed.last().unwrap().position, SlotPosition { rack: 2, slot: 11 });
+    }
+
+    #[test]
+    fn test_redundant_pairs_use_adjacent_slots() {
+        let mut redundant_ai = equipment("LK411", 4, "");
+        redundant_ai.redundant = true;
+        let equipment_list = vec![equipment("LK610", 9, ""), redundant_ai, equipment("LK117", 2, "")];
+
+        let placed = ModulePlacementService::place(&equipment_list, 2, &GenerationOptions::default()).unwrap();
+        let pairs: Vec<_> = placed.iter().filter(|p| p.module.redundant).collect();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].position, SlotPosition { rack: 1, slot: 2 });
+        assert_eq!(pairs[0].partner_position(), Some(SlotPosition { rack: 1, slot: 3 }));
+        assert_eq!(pairs[1].position, SlotPosition { rack: 1, slot: 4 });
+
+        // DI模块占满剩余槽位后进入第二个机架
+        assert_eq!(placed.last().unwrap().position, SlotPosition { rack: 2, slot: 4 });
+    }
+
+    #[test]
+    fn test_redundant_controller_reserves_standby_slot() {
+        let mut controller = equipment("LK207", 2, "");
+        controller.redundant = true;
+        let equipment_list = vec![controller, equipment("LK411", 1, "")];
+
+        let placed = ModulePlacementService::place(&equipment_list, 1, &GenerationOptions::default()).unwrap();
+        assert_eq!(placed[0].position, SlotPosition { rack: 1, slot: START_SLOT + 1 });
+
+        // 备用CPU所在槽位不能再指定给IO模块
+        let mut options = GenerationOptions::default();
+        options.slot_overrides.insert("LK411#LK411#1".to_string(), STANDBY_CONTROLLER_SLOT);
+        let result = ModulePlacementService::place(&equipment_list, 1, &options);
+        assert!(matches!(result, Err(IoError::InvalidPlacement(_))));
+    }
+
+    #[test]
+    fn test_spare_policy_adds_spare_modules() {
+        let mut options = GenerationOptions::default();
+        options.spare_policy.rules.insert(IOChannelType::AI, SpareRule::Percentage(20.0));
+        let equipment_list = vec![equipment("LK411", 2, ""), equipment("LK610", 1, "")];
+
+        let placed = ModulePlacementService::place(&equipment_list, 1, &options).unwrap();
+        assert_eq!(models(&placed), vec!["LK411#LK411#1", "LK411#LK411#2", "LK411#LK411#3", "LK610#LK610#1"]);
+        let spare: Vec<bool> = placed.iter().map(|p| p.module.spare).collect();
+        assert_eq!(spare, vec![false, false, true, false]);
+    }
+
+    #[test]
+    fn test_spare_modules_follow_signal_counts() {
+        let mut options = GenerationOptions::default();
+        options.spare_policy.rules.insert(IOChannelType::AI, SpareRule::Percentage(20.0));
+        options.spare_policy.rules.insert(IOChannelType::DO, SpareRule::Percentage(20.0));
+        // 选型时已按备用策略留出备用通道，不再追加
+        options.signal_counts.insert(IOChannelType::AI, 12);
+        let mut redundant_do = equipment("LK710", 2, "");
+        redundant_do.redundant = true;
+        let equipment_list = vec![equipment("LK411", 2, ""), redundant_do];
+
+        let placed = ModulePlacementService::place(&equipment_list, 1, &options).unwrap();
+        assert_eq!(models(&placed), vec!["LK411#LK411#1", "LK411#LK411#2", "LK710#LK710#1", "LK710#LK710#2"]);
+
+        // 冗余模块对后追加的备用模块是普通模块，只占一个槽位
+        let spare = placed.last().unwrap();
+        assert!(spare.module.spare);
+        assert!(!spare.module.redundant);
+        assert_eq!(spare.occupied_positions().count(), 1);
+    }
+
+    #[test]
+    fn test_redundant_odd_quantity_is_rejected() {
+        let mut redundant_do = equipment("LK710", 3, "");
+        redundant_do.redundant = true;
+        let result = ModulePlacementService::place(
+            std::slice::from_ref(&redundant_do), 1, &GenerationOptions::default()
+        );
+        assert!(matches!(result, Err(IoError::InvalidPlacement(_))));
+    }
+
+    #[test]
+    fn test_overflow_without_virtual_racks() {
+        let equipment_list = vec![equipment("LK411", 11, "")];
+        let result = ModulePlacementService::place(&equipment_list, 1, &GenerationOptions::default());
+        assert!(matches!(result, Err(IoError::SlotOverflow { rack_count: 1, required_rack: 2 })));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::application_services::excel_services::io_excel_services::{
+    EquipmentData, GenerationOptions, IOExcelService, IoError, AVAILABLE_SLOTS_PER_RACK,
+};
+use crate::application_services::io_planning_services::placement_strategy::{
+    ModulePlacementService, STANDBY_CONTROLLER_SLOT,
+};
+use crate::model_domain::rack_plan_model::{RackCapacityPlan, RackUtilization};
+use std::collections::BTreeMap;
+
+/// 机架容量规划服务
+pub struct RackPlanningService;
+
+impl RackPlanningService {
+    /// 根据设备清单计算所需机架数及每个机架的槽位利用率
+    ///
+    /// 与生成点表共用模块排布：按选项中的排布策略、槽位指定和备用策略放置模块，
+    /// 冗余模块对不跨机架，超出LK117数量的机架作为待订购机架列出
+    pub fn plan_capacity(
+        equipment_list: &[EquipmentData],
+        options: &GenerationOptions
+    ) -> Result<RackCapacityPlan, IoError> {
+        let installed_racks = IOExcelService::get_rack_count(equipment_list);
+        let redundant_controller = IOExcelService::has_redundant_controller(equipment_list);
+
+        // 规划时允许放入待订购机架，以得到所需的机架数
+        let options = GenerationOptions {
+            allow_virtual_racks: true,
+            ..options.clone()
+        };
+        let placed = ModulePlacementService::place(equipment_list, installed_racks, &options)?;
+
+        // 各机架占用的槽位数，冗余控制器的备用CPU占用一个IO槽位
+        let mut used_slots: BTreeMap<u32, u32> = BTreeMap::new();
+        if redundant_controller {
+            *used_slots.entry(STANDBY_CONTROLLER_SLOT.rack).or_insert(0) += 1;
+        }
+        for placed_module in &placed {
+            *used_slots.entry(placed_module.position.rack).or_insert(0) += placed_module.module.width();
+        }
+
+        // 至少需要1个机架
+        let required_racks = used_slots.keys().copied().max().unwrap_or(0).max(1);
+        let racks_to_order = required_racks.saturating_sub(installed_racks);
+        let rack_total = u32::max(installed_racks, required_racks);
+        let racks = (1..=rack_total)
+            .map(|rack| {
+                let used = used_slots.get(&rack).copied().unwrap_or(0);
+                Self::build_utilization(rack, used, rack > installed_racks)
+            })
+            .collect();
+
+        Ok(RackCapacityPlan {
+            installed_racks,
+            required_racks,
+            racks_to_order,
+            total_modules: used_slots.values().sum(),
+            slots_per_rack: AVAILABLE_SLOTS_PER_RACK,
+            racks,
+            redundant_controller,
+        })
+    }
+
+    /// 构造单个机架的利用率信息
+    pub fn build_utilization(rack: u32, used_slots: u32, is_virtual: bool) -> RackUtilization {
+        RackUtilization {
+            rack,
+            used_slots,
+            available_slots: AVAILABLE_SLOTS_PER_RACK,
+            utilization: used_slots as f64 * 100.0 / AVAILABLE_SLOTS_PER_RACK as f64,
+            is_virtual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::excel_services::io_excel_services::IOChannelType;
+    use crate::application_services::io_planning_services::spare_policy::SpareRule;
+
+    fn equipment(model: &str, quantity: u32) -> EquipmentData {
+        EquipmentData::for_test(model, model, quantity)
+    }
+
+    #[test]
+    fn test_plan_capacity_with_overflow() {
+        let equipment_list = vec![
+            equipment("LK117", 1),
+            equipment("LK411", 6),
+            equipment("LK610", 7),
+        ];
+
+        let plan = RackPlanningService::plan_capacity(&equipment_list, &GenerationOptions::default()).unwrap();
+        assert_eq!(plan.total_modules, 13);
+        assert_eq!(plan.installed_racks, 1);
+        assert_eq!(plan.required_racks, 2);
+        assert_eq!(plan.racks_to_order, 1);
+        assert!(!plan.is_sufficient());
+        assert_eq!(plan.racks.len(), 2);
+        assert_eq!(plan.racks[0].used_slots, 10);
+        assert!(!plan.racks[0].is_virtual);
+        assert_eq!(plan.racks[1].used_slots, 3);
+        assert!(plan.racks[1].is_virtual);
+    }
+
+    #[test]
+    fn test_plan_capacity_reports_empty_installed_racks() {
+        let equipment_list = vec![equipment("LK117", 3), equipment("LK710", 4)];
+
+        let plan = RackPlanningService::plan_capacity(&equipment_list, &GenerationOptions::default()).unwrap();
+        assert_eq!(plan.required_racks, 1);
+        assert_eq!(plan.racks_to_order, 0);
+        assert_eq!(plan.racks.len(), 3);
+        assert_eq!(plan.racks[0].utilization, 40.0);
+        assert_eq!(plan.racks[2].used_slots, 0);
+        assert!(!plan.redundant_controller);
+
+        // 冗余控制器的备用CPU占用一个IO槽位
+        let mut controller = equipment("LK207", 2);
+        controller.redundant = true;
+        let equipment_list = vec![controller, equipment("LK117", 1), equipment("LK411", 10)];
+        let plan = RackPlanningService::plan_capacity(&equipment_list, &GenerationOptions::default()).unwrap();
+        assert!(plan.redundant_controller);
+        assert_eq!(plan.total_modules, 11);
+        assert_eq!(plan.racks_to_order, 1);
+    }
+
+    #[test]
+    fn test_plan_capacity_matches_placement() {
+        // 9块DI占用1-9槽位，冗余AI模块对不跨机架，进入第二个机架
+        let mut redundant_ai = equipment("LK411", 2);
+        redundant_ai.redundant = true;
+        let mut options = GenerationOptions::default();
+        options.spare_policy.rules.insert(IOChannelType::DI, SpareRule::Fixed(4));
+        let equipment_list = vec![equipment("LK117", 1), equipment("LK610", 9), redundant_ai];
+
+        let plan = RackPlanningService::plan_capacity(&equipment_list, &options).unwrap();
+        // DI按备用策略追加1块备用模块，占满第一个机架
+        assert_eq!(plan.racks[0].used_slots, 10);
+        assert_eq!(plan.racks[1].used_slots, 2);
+        assert_eq!(plan.total_modules, 12);
+        assert_eq!(plan.racks_to_order, 1);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::application_services::excel_services::io_excel_services::{IOChannelType, IoError};
+use crate::model_domain::io_table_model::IOTableRow;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// 备用通道的位号及描述
+pub const SPARE_LABEL: &str = "备用";
+
+/// 单个通道类型的备用规则
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", content = "value", rename_all = "snake_case")]
+pub enum SpareRule {
+    /// 按已安装通道数的百分比预留
+    Percentage(f64),
+    /// 预留固定数量的通道
+    Fixed(u32),
+}
+
+/// 备用通道策略，未配置的通道类型不预留备用
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SparePolicy {
+    pub rules: HashMap<IOChannelType, SpareRule>,
+}
+
+impl SparePolicy {
+    /// 按已安装通道数计算应预留的备用通道数，不超过已安装通道数
+    pub fn spare_for(&self, channel_type: IOChannelType, installed: u32) -> u32 {
+        let spare = match self.rules.get(&channel_type) {
+            Some(SpareRule::Percentage(percent)) => {
+                (installed as f64 * percent.max(0.0) / 100.0).ceil() as u32
+            }
+            Some(SpareRule::Fixed(count)) => *count,
+            None => 0,
+        };
+        u32::min(spare, installed)
+    }
+
+    /// 满足备用策略需追加的最少模块数
+    ///
+    /// 已安装installed个通道、其中used个通道接有信号，追加的模块每块channels_per_module个通道；
+    /// 已安装通道不足以接入全部信号时先补足信号所需的模块
+    pub fn extra_modules(
+        &self,
+        channel_type: IOChannelType,
+        used: u32,
+        installed: u32,
+        channels_per_module: u32
+    ) -> Result<u32, IoError> {
+        if channels_per_module == 0 {
+            return Ok(0);
+        }
+
+        let mut modules = used.saturating_sub(installed).div_ceil(channels_per_module);
+        // 备用比例低于100%时必然收敛，这里限制最大模块数以防策略配置错误
+        let max_modules = used.div_ceil(channels_per_module).saturating_mul(100).max(100);
+        while modules <= max_modules {
+            let total = installed + modules * channels_per_module;
+            if total - used >= self.spare_for(channel_type, total) {
+                return Ok(modules);
+            }
+            modules += 1;
+        }
+
+        Err(IoError::InvalidSparePolicy(format!(
+            "{} 类型的备用策略无法满足，请检查备用比例是否小于100%",
+            channel_type
+        )))
+    }
+
+    /// 将单行标记为备用，备用通道保留原有的PLC地址和通讯地址
+    pub fn mark_spare_row(row: &mut IOTableRow<'_>) {
+        row.tag = Some(Cow::Borrowed(SPARE_LABEL));
+        row.variable_description = Some(Cow::Borrowed(SPARE_LABEL));
+        row.is_spare = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extra_modules_per_type() {
+        let mut policy = SparePolicy::default();
+        policy.rules.insert(IOChannelType::AI, SpareRule::Percentage(20.0));
+        policy.rules.insert(IOChannelType::DI, SpareRule::Fixed(4));
+
+        // 16个信号占满2块AI模块，追加1块后24通道中8个备用，满足20%（5个）
+        assert_eq!(policy.extra_modules(IOChannelType::AI, 16, 16, 8).unwrap(), 1);
+        // 已有备用足够时不追加
+        assert_eq!(policy.extra_modules(IOChannelType::AI, 12, 16, 8).unwrap(), 0);
+        // 已安装通道不够接入信号时先补足
+        assert_eq!(policy.extra_modules(IOChannelType::DI, 30, 16, 16).unwrap(), 2);
+        // 未配置策略的类型不追加
+        assert_eq!(policy.extra_modules(IOChannelType::DO, 16, 16, 16).unwrap(), 0);
+
+        let mut row = IOTableRow::default();
+        SparePolicy::mark_spare_row(&mut row);
+        assert!(row.is_spare);
+        assert_eq!(row.tag.as_deref(), Some(SPARE_LABEL));
+    }
+}
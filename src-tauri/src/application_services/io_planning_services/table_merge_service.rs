@@ -0,0 +1,248 @@
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::model_domain::generation_report_model::MergeSummary;
+use crate::model_domain::io_table_model::{IOTable, IOTableRow, IO_TABLE_HEADERS};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// 合并时沿用上次点表内容的字段（工程师填写的内容）
+pub const USER_ENTERED_FIELDS: [&str; 14] = [
+    "供电类型（有源/无源）", "线制", "位号", "变量名称（HMI）", "变量描述",
+    "量程低限", "量程高限", "SLL设定值", "SL设定值", "SH设定值", "SHH设定值",
+    "读写属性", "保存历史", "掉电保护"
+];
+
+/// 备用通道的位号和描述由备用策略生成，不沿用
+const SPARE_FIELDS: [&str; 2] = ["位号", "变量描述"];
+
+/// 生成点表时追加在模块名称后的说明，比较模块时去掉
+const MODULE_NAME_NOTES: [&str; 2] = ["（待订购机架）", "（冗余，伙伴槽位"];
+
+/// 点表合并服务：设备清单变更后重新生成点表时，沿用上次点表中工程师填写的内容
+pub struct TableMergeService;
+
+impl TableMergeService {
+    /// 按通道位号（机架_槽位_类型_通道）将上次点表合并到本次生成的点表行
+    ///
+    /// 仍存在的通道沿用上次填写的内容，上次没有的通道标记为新增，
+    /// 本次已不存在的通道作为已移除通道返回；软点位不参与合并。
+    /// 通道位号相同但模块不同（点位标识或模块名称不一致）的通道按已移除和新增处理，并列入replaced_channels
+    pub fn merge(rows: &mut [IOTableRow<'static>], previous: &IOTable<'_>) -> MergeSummary {
+        let mut previous_rows: HashMap<&str, &IOTableRow<'_>> = HashMap::new();
+        for row in &previous.rows {
+            if let Some(key) = Self::channel_key(row) {
+                // 通道位号重复时以靠前的行为准
+                previous_rows.entry(key).or_insert(row);
+            }
+        }
+
+        let mut summary = MergeSummary {
+            previous_table: previous.table_name.clone(),
+            ..Default::default()
+        };
+        let mut current_keys = HashSet::new();
+        for row in rows.iter_mut() {
+            let Some(key) = Self::channel_key(row).map(str::to_string) else {
+                continue;
+            };
+            match previous_rows.get(key.as_str()) {
+                Some(previous_row) if Self::same_module(row, previous_row) => {
+                    Self::copy_user_fields(row, previous_row);
+                    summary.kept_channels += 1;
+                    current_keys.insert(key);
+                }
+                Some(_) => {
+                    // 槽位上的模块已更换，上次的通道在下面作为已移除通道列出
+                    row.is_new_channel = true;
+                    summary.new_channels.push(key.clone());
+                    summary.replaced_channels.push(key);
+                }
+                None => {
+                    row.is_new_channel = true;
+                    summary.new_channels.push(key.clone());
+                    current_keys.insert(key);
+                }
+            }
+        }
+
+        for row in &previous.rows {
+            let Some(key) = Self::channel_key(row) else {
+                continue;
+            };
+            if current_keys.contains(key) || summary.removed_channels.iter().any(|removed| removed == key) {
+                continue;
+            }
+            let mut removed = Self::to_owned_row(row);
+            removed.is_removed_channel = true;
+            summary.removed_channels.push(key.to_string());
+            summary.removed_rows.push(removed);
+        }
+
+        summary
+    }
+
+    /// 沿用上次填写的内容
+    ///
+    /// 上次为空的字段和本次为"/"（不适用）的字段保持本次生成的内容，列模板中的自定义列全部沿用；
+    /// 本次按策略预留为备用、但上次已填写位号的通道视为已投用，取消备用标记
+    fn copy_user_fields(row: &mut IOTableRow<'static>, previous: &IOTableRow<'_>) {
+        let previous_is_spare = previous.tag.as_deref().map(str::trim) == Some(SPARE_LABEL);
+        for field in USER_ENTERED_FIELDS {
+            if previous_is_spare && SPARE_FIELDS.contains(&field) {
+                continue;
+            }
+            let Some(value) = previous.get_field_by_name(field).map(|v| v.trim()).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            if row.get_field_by_name(field).map(|v| v.as_ref()) == Some("/") {
+                continue;
+            }
+            row.set_field_by_name(field, Cow::Owned(value.to_string()));
+        }
+        for (field, value) in &previous.extra_fields {
+            row.extra_fields.insert(field.clone(), Cow::Owned(value.to_string()));
+        }
+
+        if row.is_spare && !previous_is_spare && previous.tag.as_deref().is_some_and(|tag| !tag.trim().is_empty()) {
+            row.is_spare = false;
+            if row.variable_description.as_deref() == Some(SPARE_LABEL) {
+                row.variable_description = Some(Cow::Borrowed(""));
+            }
+        }
+    }
+
+    /// 两行是否属于同一模块：都有点位标识时比较点位标识，否则比较去掉生成说明后的模块名称
+    fn same_module(row: &IOTableRow<'_>, previous: &IOTableRow<'_>) -> bool {
+        if let (Some(key), Some(previous_key)) = (&row.point_key, &previous.point_key) {
+            return key == previous_key;
+        }
+        Self::module_name(row) == Self::module_name(previous)
+    }
+
+    /// 去掉待订购机架、冗余伙伴等说明后的模块名称
+    fn module_name<'r>(row: &'r IOTableRow<'_>) -> &'r str {
+        let mut name = row.module_name.as_deref().unwrap_or_default().trim();
+        for note in MODULE_NAME_NOTES {
+            if let Some(position) = name.find(note) {
+                name = &name[..position];
+            }
+        }
+        name.trim()
+    }
+
+    /// 通道位号，只有能解析出机架和槽位的硬件通道才参与合并
+    fn channel_key<'r>(row: &'r IOTableRow<'_>) -> Option<&'r str> {
+        row.rack_and_slot()?;
+        row.channel_tag.as_deref().map(str::trim)
+    }
+
+    /// 复制为独立的点表行
+    fn to_owned_row(row: &IOTableRow<'_>) -> IOTableRow<'static> {
+        let mut owned = IOTableRow {
+            is_spare: row.is_spare,
+            point_key: row.point_key.clone(),
+            extra_fields: row.extra_fields.iter().map(|(field, value)| (field.clone(), Cow::Owned(value.to_string()))).collect(),
+            ..Default::default()
+        };
+        for (header, value) in IO_TABLE_HEADERS.iter().zip(row.to_vec()) {
+            if let Some(value) = value {
+                owned.set_field_by_name(header, Cow::Owned(value));
+            }
+        }
+        owned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(channel_tag: &str, tag: &str) -> IOTableRow<'static> {
+        IOTableRow {
+            module_type: Some(Cow::Borrowed("AI")),
+            channel_tag: Some(Cow::Owned(channel_tag.to_string())),
+            tag: Some(Cow::Owned(tag.to_string())),
+            variable_description: Some(Cow::Borrowed("")),
+            range_lower_limit: Some(Cow::Borrowed("")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_user_fields_and_marks_changes() {
+        let mut previous = IOTable::new("上次点表".to_string());
+        let mut filled = row("1_2_AI_0", "PT-101");
+        filled.variable_description = Some(Cow::Borrowed("进站压力"));
+        filled.range_lower_limit = Some(Cow::Borrowed("0"));
+        filled.extra_fields.insert("工程单位".to_string(), Cow::Borrowed("MPa"));
+        previous.add_row(filled);
+        previous.add_row(row("1_2_AI_1", "PT-102"));
+        previous.add_row(row("1_3_AI_0", "TT-101"));
+
+        let mut spare = row("1_2_AI_1", SPARE_LABEL);
+        spare.is_spare = true;
+        let mut rows = vec![row("1_2_AI_0", ""), spare, row("1_4_AI_0", "")];
+        let summary = TableMergeService::merge(&mut rows, &previous);
+
+        assert_eq!(rows[0].tag.as_deref(), Some("PT-101"));
+        assert_eq!(rows[0].variable_description.as_deref(), Some("进站压力"));
+        assert_eq!(rows[0].range_lower_limit.as_deref(), Some("0"));
+        assert_eq!(rows[0].get_field_by_name("工程单位").map(|v| v.as_ref()), Some("MPa"));
+        // 上次已填写位号的备用通道视为已投用
+        assert_eq!(rows[1].tag.as_deref(), Some("PT-102"));
+        assert!(!rows[1].is_spare);
+        assert!(rows[2].is_new_channel);
+
+        assert_eq!(summary.kept_channels, 2);
+        assert_eq!(summary.new_channels, vec!["1_4_AI_0".to_string()]);
+        assert_eq!(summary.removed_channels, vec!["1_3_AI_0".to_string()]);
+        assert!(summary.removed_rows[0].is_removed_channel);
+        assert_eq!(summary.removed_rows[0].tag.as_deref(), Some("TT-101"));
+    }
+
+    #[test]
+    fn test_merge_treats_replaced_module_as_removed_and_new() {
+        let with_module = |channel_tag: &str, tag: &str, module_name: &str| IOTableRow {
+            module_name: Some(Cow::Owned(module_name.to_string())),
+            ..row(channel_tag, tag)
+        };
+        let mut previous = IOTable::new("上次点表".to_string());
+        previous.add_row(with_module("1_2_AI_0", "PT-101", "进站压力"));
+        previous.add_row(with_module("1_3_AI_0", "TT-101", "进站温度"));
+
+        // 1_2槽位改装了其他设备的模块；模块名称后的待订购机架说明不影响比较
+        let mut rows = vec![
+            with_module("1_2_AI_0", "", "出站压力"),
+            with_module("1_3_AI_0", "", "进站温度（待订购机架）"),
+        ];
+        let summary = TableMergeService::merge(&mut rows, &previous);
+
+        assert_eq!(rows[0].tag.as_deref(), Some(""));
+        assert!(rows[0].is_new_channel);
+        assert_eq!(rows[1].tag.as_deref(), Some("TT-101"));
+        assert_eq!(summary.kept_channels, 1);
+        assert_eq!(summary.replaced_channels, vec!["1_2_AI_0".to_string()]);
+        assert_eq!(summary.new_channels, vec!["1_2_AI_0".to_string()]);
+        assert_eq!(summary.removed_channels, vec!["1_2_AI_0".to_string()]);
+        assert_eq!(summary.removed_rows[0].tag.as_deref(), Some("PT-101"));
+    }
+
+    #[test]
+    fn test_merge_compares_point_keys_of_same_named_modules() {
+        let with_key = |channel_tag: &str, tag: &str, point_key: &str| IOTableRow {
+            module_name: Some(Cow::Borrowed("进站压力")),
+            point_key: Some(point_key.to_string()),
+            ..row(channel_tag, tag)
+        };
+        let mut previous = IOTable::new("上次点表".to_string());
+        previous.add_row(with_key("1_2_AI_0", "PT-101", "进站压力#LK411#1#0"));
+        previous.add_row(with_key("1_3_AI_0", "PT-102", "进站压力#LK411#2#0"));
+
+        // 同名设备的第1个模块被移除，第2个模块前移到1_2槽位
+        let mut rows = vec![with_key("1_2_AI_0", "", "进站压力#LK411#2#0")];
+        let summary = TableMergeService::merge(&mut rows, &previous);
+
+        assert!(rows[0].is_new_channel);
+        assert_eq!(summary.replaced_channels, vec!["1_2_AI_0".to_string()]);
+        assert_eq!(summary.removed_rows[0].point_key.as_deref(), Some("进站压力#LK411#1#0"));
+    }
+}
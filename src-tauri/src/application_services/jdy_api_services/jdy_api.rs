@@ -40,6 +40,7 @@ impl FieldNames {
     const QUANTITY: &'static str = "_widget_1635777485580"; // 数量（number类型）
     const UNIT: &'static str = "_widget_1654703913698"; // 单位（text类型）
     const EXTERNAL_PARAM: &'static str = "_widget_1641439463480"; // 技术参数(外部)（text类型）
+    const SUBSYSTEM: &'static str = "_widget_1636353456514"; // 子系统（text类型）
 
     // 其他可能需要的字段...后期有需要可增加
 }
@@ -256,6 +257,7 @@ impl JiandaoyunApiClient {
                 FieldNames::QUANTITY,       // 数量
                 FieldNames::UNIT,           // 单位
                 FieldNames::EXTERNAL_PARAM, // 技术参数(外部)
+                FieldNames::SUBSYSTEM,      // 子系统
             ])
             .add_filter_condition(FieldNames::STATION_NAME, &station_name);
 
@@ -1,3 +1,9 @@
 pub mod jdy_api_services;
 pub mod query_services;
 pub mod excel_services;
+pub mod io_planning_services;
+pub mod address_services;
+pub mod storage_services;
+pub mod protocol_export_services;
+pub mod validation_services;
+pub mod diff_services;
@@ -0,0 +1,72 @@
+use crate::model_domain::dnp3_profile_model::Dnp3Point;
+
+/// DNP3点表CSV的表头，使用外站组态工具通用的英文列名
+const CSV_HEADERS: [&str; 9] = [
+    "Type", "Group", "Index", "Class", "Deadband", "Name", "Description", "Point", "PlcAddress"
+];
+
+/// DNP3点表CSV导出服务
+pub struct Dnp3CsvService;
+
+impl Dnp3CsvService {
+    /// 生成供外站组态导入的CSV文本，名称优先使用变量名称，未填写时使用通道位号
+    pub fn build_csv(points: &[Dnp3Point]) -> String {
+        let mut csv = CSV_HEADERS.join(",");
+        csv.push_str("\r\n");
+
+        for point in points {
+            let name = if point.variable_name.is_empty() { &point.channel_tag } else { &point.variable_name };
+            let fields = [
+                point.point_type.code().to_string(),
+                point.point_type.static_group().to_string(),
+                point.index.to_string(),
+                point.event_class.to_string(),
+                point.deadband.map(|deadband| deadband.to_string()).unwrap_or_default(),
+                name.clone(),
+                point.description.clone(),
+                point.point_name.clone(),
+                point.plc_address.clone(),
+            ];
+            let line: Vec<String> = fields.iter().map(|field| escape_csv(field)).collect();
+            csv.push_str(&line.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+/// 含逗号、引号或换行的字段加引号，内部引号双写
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::dnp3_profile_model::{Dnp3PointType, EventClass};
+    use crate::model_domain::io_table_model::MAIN_POINT_NAME;
+
+    #[test]
+    fn test_build_csv() {
+        let point = Dnp3Point {
+            point_type: Dnp3PointType::AnalogInput,
+            index: 3,
+            event_class: EventClass::Class2,
+            deadband: Some(0.5),
+            channel_tag: "1_2_AI_0".to_string(),
+            variable_name: String::new(),
+            description: "进口压力,\"高\"".to_string(),
+            point_name: MAIN_POINT_NAME.to_string(),
+            plc_address: "%MD320".to_string(),
+        };
+
+        let csv = Dnp3CsvService::build_csv(&[point]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "Type,Group,Index,Class,Deadband,Name,Description,Point,PlcAddress");
+        assert_eq!(lines[1], "AI,30,3,2,0.5,1_2_AI_0,\"进口压力,\"\"高\"\"\",主点位,%MD320");
+    }
+}
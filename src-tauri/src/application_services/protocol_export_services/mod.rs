@@ -0,0 +1,3 @@
+pub mod opcua_nodeset_service;
+pub mod dnp3_csv_service;
+pub mod sparkplug_service;
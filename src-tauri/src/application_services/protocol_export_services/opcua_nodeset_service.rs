@@ -0,0 +1,413 @@
+use crate::application_services::excel_services::io_excel_services::{DataType, IOExcelService, POINT_CONFIGS};
+use crate::model_domain::io_table_model::{IOTable, IOTableRow};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// NodeSet2中自定义节点使用的命名空间索引
+const NAMESPACE_INDEX: u32 = 1;
+/// 命名空间URI前缀，后接场站名称
+const NAMESPACE_URI_PREFIX: &str = "urn:table-generation-tool:";
+/// 未填写场站时使用的场站名称
+const DEFAULT_STATION: &str = "未命名场站";
+/// 软点位所在文件夹名称
+const SOFT_POINT_FOLDER: &str = "软点位";
+
+/// 标准节点：Objects文件夹
+const OBJECTS_FOLDER: &str = "i=85";
+/// 标准类型：FolderType
+const FOLDER_TYPE: &str = "i=61";
+/// 标准类型：BaseDataVariableType
+const BASE_DATA_VARIABLE_TYPE: &str = "i=63";
+/// 标准类型：AnalogItemType
+const ANALOG_ITEM_TYPE: &str = "i=2368";
+/// 标准类型：PropertyType
+const PROPERTY_TYPE: &str = "i=68";
+/// Range结构的XML编码
+const RANGE_XML_ENCODING: &str = "i=885";
+
+/// 文件中使用的别名
+const ALIASES: [(&str, &str); 13] = [
+    ("Boolean", "i=1"),
+    ("Int16", "i=4"),
+    ("UInt16", "i=5"),
+    ("Int32", "i=6"),
+    ("UInt32", "i=7"),
+    ("Float", "i=10"),
+    ("Double", "i=11"),
+    ("String", "i=12"),
+    ("Range", "i=884"),
+    ("Organizes", "i=35"),
+    ("HasTypeDefinition", "i=40"),
+    ("HasProperty", "i=46"),
+    ("HasComponent", "i=47"),
+];
+
+/// OPC UA NodeSet2导出服务
+///
+/// 将IO点表转换为NodeSet2 XML地址空间：场站/机架/模块为文件夹，点位为变量，
+/// 报警和设定值等附加点位作为点位变量的子变量
+pub struct OpcUaNodeSetService;
+
+impl OpcUaNodeSetService {
+    /// 生成NodeSet2 XML文本
+    pub fn build_nodeset(io_table: &IOTable<'_>) -> String {
+        let mut builder = NodeSetBuilder::default();
+        for row in &io_table.rows {
+            builder.add_row(row);
+        }
+
+        let namespace_uri = format!("{}{}", NAMESPACE_URI_PREFIX, builder.namespace_name(&io_table.table_name));
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str(concat!(
+            "<UANodeSet xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ",
+            "xmlns:uax=\"http://opcfoundation.org/UA/2008/02/Types.xsd\" ",
+            "xmlns=\"http://opcfoundation.org/UA/2011/03/UANodeSet.xsd\">\n"
+        ));
+        let _ = writeln!(xml, "  <NamespaceUris>\n    <Uri>{}</Uri>\n  </NamespaceUris>", escape_xml(&namespace_uri));
+        let _ = writeln!(
+            xml,
+            "  <Models>\n    <Model ModelUri=\"{}\">\n      <RequiredModel ModelUri=\"http://opcfoundation.org/UA/\" />\n    </Model>\n  </Models>",
+            escape_xml(&namespace_uri)
+        );
+
+        xml.push_str("  <Aliases>\n");
+        for (alias, node_id) in ALIASES {
+            let _ = writeln!(xml, "    <Alias Alias=\"{}\">{}</Alias>", alias, node_id);
+        }
+        xml.push_str("  </Aliases>\n");
+
+        xml.push_str(&builder.nodes);
+        xml.push_str("</UANodeSet>\n");
+        xml
+    }
+
+    /// 点位数据类型对应的OPC UA数据类型别名
+    pub fn data_type_alias(data_type: DataType) -> &'static str {
+        match data_type {
+            DataType::BOOL => "Boolean",
+            DataType::INT => "Int16",
+            DataType::UINT | DataType::WORD => "UInt16",
+            DataType::DINT => "Int32",
+            DataType::DWORD => "UInt32",
+            DataType::REAL => "Float",
+            DataType::LREAL => "Double",
+            DataType::STRING => "String",
+        }
+    }
+}
+
+/// 逐行构建节点，已创建的文件夹和节点标识只输出一次
+#[derive(Default)]
+struct NodeSetBuilder {
+    nodes: String,
+    node_ids: HashSet<String>,
+    first_station: Option<String>,
+}
+
+impl NodeSetBuilder {
+    /// 命名空间名称：使用第一个场站名称，没有点位时使用表名
+    fn namespace_name(&self, table_name: &str) -> String {
+        self.first_station.clone().unwrap_or_else(|| table_name.to_string())
+    }
+
+    fn add_row(&mut self, row: &IOTableRow<'_>) {
+        let station = non_empty(row.station_name.as_deref()).unwrap_or(DEFAULT_STATION);
+        self.first_station.get_or_insert_with(|| station.to_string());
+
+        let station_id = node_id(station);
+        self.add_folder(&station_id, station, OBJECTS_FOLDER);
+
+        // 硬件点位按机架、模块分组，软点位按数据来源分组
+        let module_name = non_empty(row.module_name.as_deref()).unwrap_or(SOFT_POINT_FOLDER);
+        let module_id = match row.rack_and_slot() {
+            Some((rack, slot)) => {
+                let rack_name = format!("机架{}", rack);
+                let rack_id = format!("{}.{}", station_id, node_id(&rack_name));
+                self.add_folder(&rack_id, &rack_name, &station_id);
+
+                let module_folder = format!("{}槽_{}", slot, module_name);
+                let module_id = format!("{}.{}", rack_id, node_id(&module_folder));
+                self.add_folder(&module_id, &module_folder, &rack_id);
+                module_id
+            }
+            None => {
+                let module_id = format!("{}.{}", station_id, node_id(module_name));
+                self.add_folder(&module_id, module_name, &station_id);
+                module_id
+            }
+        };
+
+        let data_type = row.data_type.as_deref().and_then(DataType::from_name).unwrap_or(DataType::REAL);
+        let name = row.display_name().unwrap_or("点位");
+        let variable_id = self.unique_id(format!("{}.{}", module_id, node_id(name)));
+        let access_level = if row.read_write_property.as_deref() == Some("R") { 1 } else { 3 };
+        let range = row.range_lower_limit.as_deref().and_then(parse_number)
+            .zip(row.range_upper_limit.as_deref().and_then(parse_number));
+
+        self.add_variable(Variable {
+            node_id: &variable_id,
+            name,
+            description: non_empty(row.variable_description.as_deref()),
+            data_type: OpcUaNodeSetService::data_type_alias(data_type),
+            access_level,
+            parent_id: &module_id,
+            parent_reference: "Organizes",
+            type_definition: if range.is_some() { ANALOG_ITEM_TYPE } else { BASE_DATA_VARIABLE_TYPE },
+        });
+        if let Some((low, high)) = range {
+            self.add_eu_range(&variable_id, low, high);
+        }
+
+        // 报警和设定值附加点位作为子变量
+        for (column_name, suffix) in POINT_CONFIGS.iter() {
+            let plc_address = row.get_field_by_name(&format!("{}_PLC地址", column_name));
+            let assigned = non_empty(plc_address.map(|value| value.as_ref())).filter(|address| *address != "/");
+            if assigned.is_none() {
+                continue;
+            }
+
+            let child_name = suffix.trim_start_matches('_');
+            let is_bool = IOExcelService::is_bool_address_field(column_name);
+            let child_id = self.unique_id(format!("{}.{}", variable_id, child_name));
+            self.add_variable(Variable {
+                node_id: &child_id,
+                name: child_name,
+                description: Some(column_name),
+                data_type: if is_bool { "Boolean" } else { OpcUaNodeSetService::data_type_alias(data_type) },
+                access_level: 3,
+                parent_id: &variable_id,
+                parent_reference: "HasComponent",
+                type_definition: BASE_DATA_VARIABLE_TYPE,
+            });
+        }
+    }
+
+    /// 添加文件夹节点，已存在时跳过
+    fn add_folder(&mut self, id: &str, name: &str, parent_id: &str) {
+        if !self.node_ids.insert(id.to_string()) {
+            return;
+        }
+
+        let parent = qualified_parent(parent_id);
+        let _ = writeln!(
+            self.nodes,
+            "  <UAObject NodeId=\"{id}\" BrowseName=\"{ns}:{name}\" ParentNodeId=\"{parent}\">\n    <DisplayName>{name}</DisplayName>\n    <References>\n      <Reference ReferenceType=\"HasTypeDefinition\">{folder}</Reference>\n      <Reference ReferenceType=\"Organizes\" IsForward=\"false\">{parent}</Reference>\n    </References>\n  </UAObject>",
+            id = qualified(id),
+            ns = NAMESPACE_INDEX,
+            name = escape_xml(name),
+            parent = parent,
+            folder = FOLDER_TYPE,
+        );
+    }
+
+    fn add_variable(&mut self, variable: Variable<'_>) {
+        let description = variable
+            .description
+            .map(|text| format!("\n    <Description>{}</Description>", escape_xml(text)))
+            .unwrap_or_default();
+        let _ = writeln!(
+            self.nodes,
+            "  <UAVariable NodeId=\"{id}\" BrowseName=\"{ns}:{name}\" ParentNodeId=\"{parent}\" DataType=\"{data_type}\" AccessLevel=\"{access}\" UserAccessLevel=\"{access}\">\n    <DisplayName>{name}</DisplayName>{description}\n    <References>\n      <Reference ReferenceType=\"HasTypeDefinition\">{type_definition}</Reference>\n      <Reference ReferenceType=\"{reference}\" IsForward=\"false\">{parent}</Reference>\n    </References>\n  </UAVariable>",
+            id = qualified(variable.node_id),
+            ns = NAMESPACE_INDEX,
+            name = escape_xml(variable.name),
+            parent = qualified_parent(variable.parent_id),
+            data_type = variable.data_type,
+            access = variable.access_level,
+            description = description,
+            type_definition = variable.type_definition,
+            reference = variable.parent_reference,
+        );
+    }
+
+    /// 为模拟量变量添加EURange属性
+    fn add_eu_range(&mut self, variable_id: &str, low: f64, high: f64) {
+        let id = self.unique_id(format!("{}.EURange", variable_id));
+        let _ = writeln!(
+            self.nodes,
+            "  <UAVariable NodeId=\"{id}\" BrowseName=\"EURange\" ParentNodeId=\"{parent}\" DataType=\"Range\">\n    <DisplayName>EURange</DisplayName>\n    <References>\n      <Reference ReferenceType=\"HasTypeDefinition\">{property}</Reference>\n      <Reference ReferenceType=\"HasProperty\" IsForward=\"false\">{parent}</Reference>\n    </References>\n    <Value>\n      <uax:ExtensionObject>\n        <uax:TypeId>\n          <uax:Identifier>{encoding}</uax:Identifier>\n        </uax:TypeId>\n        <uax:Body>\n          <uax:Range>\n            <uax:Low>{low}</uax:Low>\n            <uax:High>{high}</uax:High>\n          </uax:Range>\n        </uax:Body>\n      </uax:ExtensionObject>\n    </Value>\n  </UAVariable>",
+            id = qualified(&id),
+            parent = qualified(variable_id),
+            property = PROPERTY_TYPE,
+            encoding = RANGE_XML_ENCODING,
+        );
+    }
+
+    /// 节点标识重复时追加序号
+    fn unique_id(&mut self, id: String) -> String {
+        let mut candidate = id.clone();
+        let mut counter = 2;
+        while self.node_ids.contains(&candidate) {
+            candidate = format!("{}_{}", id, counter);
+            counter += 1;
+        }
+        self.node_ids.insert(candidate.clone());
+        candidate
+    }
+}
+
+/// 变量节点的属性
+struct Variable<'v> {
+    node_id: &'v str,
+    name: &'v str,
+    description: Option<&'v str>,
+    data_type: &'static str,
+    access_level: u8,
+    parent_id: &'v str,
+    parent_reference: &'static str,
+    type_definition: &'static str,
+}
+
+/// 名称在字符串节点标识中的形式，"."用作层级分隔符
+fn node_id(name: &str) -> String {
+    name.replace(['.', ';'], "_")
+}
+
+/// 带命名空间的节点标识
+fn qualified(id: &str) -> String {
+    escape_xml(&format!("ns={};s={}", NAMESPACE_INDEX, id))
+}
+
+/// 父节点标识，标准节点保持原样
+fn qualified_parent(parent_id: &str) -> String {
+    if parent_id == OBJECTS_FOLDER {
+        parent_id.to_string()
+    } else {
+        qualified(parent_id)
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse().ok()
+}
+
+/// 转义XML特殊字符
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::excel_services::io_excel_services::{EquipmentData, GenerationOptions};
+    use libxml::parser::Parser;
+    use libxml::schemas::{SchemaParserContext, SchemaValidationContext};
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+    use std::collections::HashMap;
+
+    /// 测试用的NodeSet2 schema
+    const NODESET_SCHEMA: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/schemas/UANodeSet.xsd"));
+
+    fn io_table() -> IOTable<'static> {
+        let equipment = |model: &str| EquipmentData::for_test(&format!("{}<柜>", model), model, 1);
+        let (mut io_table, _, _) = IOExcelService::build_io_table(
+            &[equipment("LK411"), equipment("LK610")],
+            "测试站",
+            &GenerationOptions::default(),
+        )
+        .unwrap();
+        io_table.rows[0].range_lower_limit = Some("0".into());
+        io_table.rows[0].range_upper_limit = Some("1.6".into());
+        io_table
+    }
+
+    #[test]
+    fn test_nodeset_validates_against_schema() {
+        let xml = OpcUaNodeSetService::build_nodeset(&io_table());
+        let document = Parser::default().parse_string(&xml).unwrap();
+        let mut schema_parser = SchemaParserContext::from_buffer(NODESET_SCHEMA);
+        let mut validator = SchemaValidationContext::from_parser(&mut schema_parser).unwrap();
+
+        let result = validator.validate_document(&document).map_err(|errors| {
+            errors.into_iter().filter_map(|error| error.message).collect::<Vec<_>>()
+        });
+        assert_eq!(result, Ok(()));
+    }
+
+    /// schema无法检查的约定：别名、节点标识唯一及引用的节点存在
+    #[test]
+    fn test_nodeset_references_existing_nodes() {
+        let xml = OpcUaNodeSetService::build_nodeset(&io_table());
+        let aliases: HashSet<&str> = ALIASES.iter().map(|(alias, _)| *alias).collect();
+
+        // 解析时同时校验结束标签匹配，保证XML格式正确
+        let mut reader = Reader::from_str(&xml);
+        let mut stack: Vec<String> = Vec::new();
+        let mut node_ids = HashSet::new();
+        let mut node_types = HashMap::new();
+        let mut references = Vec::new();
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(element) => {
+                    let name = String::from_utf8(element.name().as_ref().to_vec()).unwrap();
+                    let attribute = |key: &str| {
+                        element
+                            .attributes()
+                            .map(|attr| attr.unwrap())
+                            .find(|attr| attr.key.as_ref() == key.as_bytes())
+                            .map(|attr| attr.unescape_value().unwrap().to_string())
+                    };
+
+                    if stack.is_empty() {
+                        assert_eq!(name, "UANodeSet");
+                        assert_eq!(
+                            attribute("xmlns").as_deref(),
+                            Some("http://opcfoundation.org/UA/2011/03/UANodeSet.xsd")
+                        );
+                    } else if stack.len() == 1 {
+                        if let Some(id) = attribute("NodeId") {
+                            assert!(node_ids.insert(id.clone()), "节点标识重复: {}", id);
+                            assert!(attribute("BrowseName").is_some());
+                            if let Some(data_type) = attribute("DataType") {
+                                assert!(aliases.contains(data_type.as_str()));
+                            }
+                            node_types.insert(id, name.clone());
+                        }
+                    }
+                    stack.push(name);
+                }
+                Event::Text(text) if stack.last().map(String::as_str) == Some("Reference") => {
+                    references.push(text.unescape().unwrap().to_string());
+                }
+                Event::End(_) => {
+                    stack.pop();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert!(stack.is_empty());
+
+        // 所有引用的自定义节点都存在
+        for target in &references {
+            assert!(target.starts_with("i=") || node_ids.contains(target), "引用了不存在的节点{}", target);
+        }
+
+        // 场站、机架、模块文件夹和带子变量的点位
+        let module = "ns=1;s=测试站.机架1.2槽_LK411<柜>";
+        assert_eq!(node_types.get("ns=1;s=测试站").map(String::as_str), Some("UAObject"));
+        assert_eq!(node_types.get(module).map(String::as_str), Some("UAObject"));
+        assert!(node_ids.contains(&format!("{}.1_2_AI_0.LoLoLimit", module)));
+        assert!(node_ids.contains(&format!("{}.1_2_AI_0.EURange", module)));
+        assert!(!node_ids.contains(&format!("{}.1_2_AI_1.EURange", module)));
+        assert!(!node_ids.contains("ns=1;s=测试站.机架1.3槽_LK610<柜>.1_3_DI_0.LL"));
+        assert!(xml.contains("&lt;柜&gt;"));
+    }
+}
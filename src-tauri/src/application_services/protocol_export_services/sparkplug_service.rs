@@ -0,0 +1,338 @@
+use crate::application_services::excel_services::io_excel_services::{DataType, IOExcelService, IoError, POINT_CONFIGS};
+use crate::model_domain::io_table_model::{IOTable, IOTableRow};
+use crate::model_domain::sparkplug_model::{
+    SparkplugDevice, SparkplugEdgeNode, SparkplugGroup, SparkplugMapping, SparkplugMetric,
+    SparkplugNamingRules, SparkplugTopics, SPARKPLUG_NAMESPACE,
+};
+use std::collections::{HashMap, HashSet};
+
+/// 组ID、边缘节点ID和设备ID中不允许出现的字符
+const RESERVED_ID_CHARS: [char; 3] = ['/', '+', '#'];
+/// 指标名称中不允许出现的字符（"/"表示文件夹层级）
+const RESERVED_METRIC_CHARS: [char; 2] = ['+', '#'];
+
+/// Sparkplug B映射导出服务
+///
+/// 按命名规则将IO点表的场站/机架/模块层级映射为组/边缘节点/设备，每个点位及其报警、
+/// 设定值附加点位映射为指标
+pub struct SparkplugService;
+
+impl SparkplugService {
+    /// 生成Sparkplug B映射
+    pub fn build_mapping(io_table: &IOTable<'_>, rules: &SparkplugNamingRules) -> Result<SparkplugMapping, IoError> {
+        Self::validate_rules(rules)?;
+
+        let mut groups: Vec<SparkplugGroup> = Vec::new();
+        // 别名在边缘节点内唯一，按(组, 边缘节点)计数
+        let mut next_aliases: HashMap<(usize, usize), u64> = HashMap::new();
+
+        for row in &io_table.rows {
+            let placeholders = Placeholders::from_row(row);
+            let group_id = sanitize(&placeholders.render(&rules.group_id), &RESERVED_ID_CHARS);
+            let (edge_node_id, device_id) = if row.rack_and_slot().is_some() {
+                (
+                    sanitize(&placeholders.render(&rules.edge_node_id), &RESERVED_ID_CHARS),
+                    sanitize(&placeholders.render(&rules.device_id), &RESERVED_ID_CHARS),
+                )
+            } else {
+                (
+                    sanitize(&rules.soft_point_edge_node_id, &RESERVED_ID_CHARS),
+                    sanitize(&placeholders.module, &RESERVED_ID_CHARS),
+                )
+            };
+            if group_id.is_empty() || edge_node_id.is_empty() || device_id.is_empty() {
+                return Err(IoError::NamingRule(format!(
+                    "点位 {} 按命名规则生成的组ID、边缘节点ID或设备ID为空", placeholders.name
+                )));
+            }
+
+            let group_idx = position_or_insert(&mut groups, |group| group.group_id == group_id, || SparkplugGroup {
+                group_id: group_id.clone(),
+                edge_nodes: Vec::new(),
+            });
+            let group = &mut groups[group_idx];
+            let node_idx = position_or_insert(&mut group.edge_nodes, |node| node.edge_node_id == edge_node_id, || {
+                SparkplugEdgeNode {
+                    topics: node_topics(&group_id, &edge_node_id),
+                    edge_node_id: edge_node_id.clone(),
+                    devices: Vec::new(),
+                }
+            });
+            let node = &mut group.edge_nodes[node_idx];
+            let device_idx = position_or_insert(&mut node.devices, |device| device.device_id == device_id, || {
+                SparkplugDevice {
+                    topics: device_topics(&group_id, &edge_node_id, &device_id),
+                    device_id: device_id.clone(),
+                    metrics: Vec::new(),
+                }
+            });
+
+            let next_alias = next_aliases.entry((group_idx, node_idx)).or_insert(1);
+            let device = &mut node.devices[device_idx];
+            let metric_name = sanitize(&placeholders.render(&rules.metric_name), &RESERVED_METRIC_CHARS);
+            Self::add_row_metrics(device, row, &metric_name, rules, next_alias);
+        }
+
+        Ok(SparkplugMapping {
+            namespace: SPARKPLUG_NAMESPACE.to_string(),
+            groups,
+        })
+    }
+
+    /// 点位数据类型对应的Sparkplug B数据类型名称和编号
+    pub fn datatype(data_type: DataType) -> (&'static str, u32) {
+        match data_type {
+            DataType::INT => ("Int16", 2),
+            DataType::DINT => ("Int32", 3),
+            DataType::UINT | DataType::WORD => ("UInt16", 6),
+            DataType::DWORD => ("UInt32", 7),
+            DataType::REAL => ("Float", 9),
+            DataType::LREAL => ("Double", 10),
+            DataType::BOOL => ("Boolean", 11),
+            DataType::STRING => ("String", 12),
+        }
+    }
+
+    /// 添加主点位及附加点位指标，同一设备内重名的指标追加序号
+    fn add_row_metrics(
+        device: &mut SparkplugDevice,
+        row: &IOTableRow<'_>,
+        metric_name: &str,
+        rules: &SparkplugNamingRules,
+        next_alias: &mut u64,
+    ) {
+        let mut used_names: HashSet<String> = device.metrics.iter().map(|metric| metric.name.clone()).collect();
+        let data_type = row.data_type.as_deref().and_then(DataType::from_name).unwrap_or(DataType::REAL);
+        let description = row.variable_description.as_deref().unwrap_or_default();
+        let writable = match row.module_type.as_deref() {
+            Some("AO") | Some("DO") => true,
+            Some("AI") | Some("DI") => false,
+            _ => row.read_write_property.as_deref().is_some_and(|property| property.contains('W')),
+        };
+        let parse = |value: Option<&str>| value.and_then(|value| value.trim().parse::<f64>().ok());
+
+        let mut push = |name: String, data_type: DataType, writable: bool, description: &str, plc_address: &str, range: Option<(f64, f64)>| {
+            let mut unique_name = name.clone();
+            let mut counter = 2;
+            while !used_names.insert(unique_name.clone()) {
+                unique_name = format!("{}_{}", name, counter);
+                counter += 1;
+            }
+
+            let (datatype, datatype_id) = Self::datatype(data_type);
+            device.metrics.push(SparkplugMetric {
+                name: unique_name,
+                alias: *next_alias,
+                datatype: datatype.to_string(),
+                datatype_id,
+                writable,
+                description: description.to_string(),
+                plc_address: plc_address.to_string(),
+                eng_low: range.map(|(low, _)| low),
+                eng_high: range.map(|(_, high)| high),
+            });
+            *next_alias += 1;
+        };
+
+        let range = parse(row.range_lower_limit.as_deref()).zip(parse(row.range_upper_limit.as_deref()));
+        push(
+            metric_name.to_string(),
+            data_type,
+            writable,
+            description,
+            row.plc_absolute_address.as_deref().unwrap_or_default(),
+            range,
+        );
+
+        for (column_name, suffix) in POINT_CONFIGS.iter() {
+            let plc_address = row
+                .get_field_by_name(&format!("{}_PLC地址", column_name))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty() && *value != "/");
+            let Some(plc_address) = plc_address else {
+                continue;
+            };
+
+            // 报警为只读布尔量，维护使能开关和设定值可写
+            let is_bool = IOExcelService::is_bool_address_field(column_name);
+            let is_alarm = column_name.ends_with("报警");
+            push(
+                format!("{}{}{}", metric_name, rules.sub_metric_separator, suffix.trim_start_matches('_')),
+                if is_bool { DataType::BOOL } else { data_type },
+                !is_alarm,
+                column_name,
+                plc_address,
+                None,
+            );
+        }
+    }
+
+    /// 校验命名规则：模板不能为空
+    fn validate_rules(rules: &SparkplugNamingRules) -> Result<(), IoError> {
+        let templates = [
+            ("组ID", &rules.group_id),
+            ("边缘节点ID", &rules.edge_node_id),
+            ("设备ID", &rules.device_id),
+            ("指标名称", &rules.metric_name),
+            ("软点位边缘节点ID", &rules.soft_point_edge_node_id),
+        ];
+        for (name, template) in templates {
+            if template.trim().is_empty() {
+                return Err(IoError::NamingRule(format!("{}模板不能为空", name)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 命名模板的占位符取值
+struct Placeholders {
+    station: String,
+    rack: String,
+    slot: String,
+    module: String,
+    module_type: String,
+    channel: String,
+    tag: String,
+    name: String,
+}
+
+impl Placeholders {
+    fn from_row(row: &IOTableRow<'_>) -> Self {
+        let text = |value: Option<&str>| value.unwrap_or_default().trim().to_string();
+        let (rack, slot) = row
+            .rack_and_slot()
+            .map(|(rack, slot)| (rack.to_string(), slot.to_string()))
+            .unwrap_or_default();
+        Self {
+            station: text(row.station_name.as_deref()),
+            rack,
+            slot,
+            module: text(row.module_name.as_deref()),
+            module_type: text(row.module_type.as_deref()),
+            channel: text(row.channel_tag.as_deref()),
+            tag: text(row.tag.as_deref()),
+            name: row.display_name().unwrap_or_default().to_string(),
+        }
+    }
+
+    /// 替换模板中的占位符
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{station}", &self.station)
+            .replace("{rack}", &self.rack)
+            .replace("{slot}", &self.slot)
+            .replace("{module_type}", &self.module_type)
+            .replace("{module}", &self.module)
+            .replace("{channel}", &self.channel)
+            .replace("{tag}", &self.tag)
+            .replace("{name}", &self.name)
+    }
+}
+
+fn node_topics(group_id: &str, edge_node_id: &str) -> SparkplugTopics {
+    let topic = |message_type: &str| format!("{}/{}/{}/{}", SPARKPLUG_NAMESPACE, group_id, message_type, edge_node_id);
+    SparkplugTopics {
+        birth: topic("NBIRTH"),
+        death: topic("NDEATH"),
+        data: topic("NDATA"),
+        command: topic("NCMD"),
+    }
+}
+
+fn device_topics(group_id: &str, edge_node_id: &str, device_id: &str) -> SparkplugTopics {
+    let topic = |message_type: &str| {
+        format!("{}/{}/{}/{}/{}", SPARKPLUG_NAMESPACE, group_id, message_type, edge_node_id, device_id)
+    };
+    SparkplugTopics {
+        birth: topic("DBIRTH"),
+        death: topic("DDEATH"),
+        data: topic("DDATA"),
+        command: topic("DCMD"),
+    }
+}
+
+/// 查找满足条件的元素位置，不存在时追加新元素
+fn position_or_insert<T>(items: &mut Vec<T>, matches: impl Fn(&T) -> bool, create: impl FnOnce() -> T) -> usize {
+    match items.iter().position(matches) {
+        Some(position) => position,
+        None => {
+            items.push(create());
+            items.len() - 1
+        }
+    }
+}
+
+/// 将保留字符和空白替换为下划线
+fn sanitize(text: &str, reserved: &[char]) -> String {
+    text.trim()
+        .chars()
+        .map(|c| if reserved.contains(&c) || c.is_whitespace() { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application_services::excel_services::io_excel_services::{EquipmentData, GenerationOptions, SoftPoint};
+
+    #[test]
+    fn test_build_mapping_hierarchy_and_metrics() {
+        let equipment = |model: &str| EquipmentData {
+            station_name: "测试 站".to_string(),
+            ..EquipmentData::for_test(model, model, 1)
+        };
+        let options = GenerationOptions {
+            soft_points: vec![SoftPoint {
+                tag: "FLOW_TOTAL".to_string(),
+                description: "累计流量".to_string(),
+                data_type: DataType::LREAL,
+                source: "流量计#1".to_string(),
+                station_name: "测试 站".to_string(),
+            }],
+            ..Default::default()
+        };
+        let (io_table, _, _) = IOExcelService::build_io_table(
+            &[equipment("LK411"), equipment("LK710")],
+            "测试 站",
+            &options,
+        )
+        .unwrap();
+
+        let mapping = SparkplugService::build_mapping(&io_table, &SparkplugNamingRules::default()).unwrap();
+        assert_eq!(mapping.groups.len(), 1);
+        let group = &mapping.groups[0];
+        assert_eq!(group.group_id, "测试_站");
+        assert_eq!(group.edge_nodes.len(), 2);
+
+        let rack = &group.edge_nodes[0];
+        assert_eq!(rack.edge_node_id, "Rack1");
+        assert_eq!(rack.topics.birth, "spBv1.0/测试_站/NBIRTH/Rack1");
+        assert_eq!(rack.devices[0].device_id, "Slot2_AI");
+        assert_eq!(rack.devices[0].topics.command, "spBv1.0/测试_站/DCMD/Rack1/Slot2_AI");
+
+        // 每个AI点位：主点位 + 10个附加点位
+        let ai_metrics = &rack.devices[0].metrics;
+        assert_eq!(ai_metrics.len(), 8 * 11);
+        assert_eq!(ai_metrics[0].name, "1_2_AI_0");
+        assert_eq!(ai_metrics[0].datatype_id, 9);
+        assert!(!ai_metrics[0].writable);
+        assert_eq!(ai_metrics[1].name, "1_2_AI_0/LoLoLimit");
+        assert!(ai_metrics[1].writable);
+        assert_eq!(ai_metrics[5].name, "1_2_AI_0/LL");
+        assert!(!ai_metrics[5].writable);
+        assert_eq!(ai_metrics[5].datatype, "Boolean");
+
+        // 别名在边缘节点内连续且唯一
+        let do_metrics = &rack.devices[1].metrics;
+        assert!(do_metrics[0].writable);
+        assert_eq!(do_metrics[0].alias, 8 * 11 + 1);
+
+        let soft = &group.edge_nodes[1];
+        assert_eq!(soft.edge_node_id, "SoftPoints");
+        assert_eq!(soft.devices[0].device_id, "流量计_1");
+        assert_eq!(soft.devices[0].metrics[0].name, "FLOW_TOTAL");
+        assert_eq!(soft.devices[0].metrics[0].datatype, "Double");
+        assert_eq!(soft.devices[0].metrics[0].alias, 1);
+    }
+}
@@ -51,6 +51,8 @@ impl QueryService {
             design_number: data.get("_widget_1636359817201")?.as_str()?.to_string(),
             customer_name: data.get("_widget_1635777114972")?.as_str()?.to_string(),
             station_name: data.get("_widget_1635777114991")?.as_str()?.to_string(),
+            // 技术负责人、项目经理（B1人员字段）不在项目表单中，生成封面时由文档信息填写
+            ..Default::default()
         })
     }
 
@@ -65,6 +67,7 @@ impl QueryService {
             quantity: data.get("_widget_1635777485580").and_then(|v| v.as_f64()).unwrap_or(0.0),
             unit: data.get("_widget_1654703913698")?.as_str()?.to_string(),
             external_param: data.get("_widget_1641439463480")?.as_str()?.to_string(),
+            subsystem: data.get("_widget_1636353456514").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
         })
     }
 } 
\ No newline at end of file
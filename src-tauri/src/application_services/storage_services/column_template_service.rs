@@ -0,0 +1,64 @@
+use crate::application_services::storage_services::json_store::{JsonStore, StoreError};
+use crate::model_domain::column_template_model::{ColumnTemplate, STANDARD_TEMPLATE_NAME};
+
+/// 列模板的存储类别
+const COLUMN_TEMPLATE_CATEGORY: &str = "column_templates";
+
+/// 项目选择的列模板名称的存储类别
+const PROJECT_TEMPLATE_CATEGORY: &str = "project_column_templates";
+
+/// 列模板服务，每个模板保存为一个文件，项目可以选择其中一个模板
+pub struct ColumnTemplateService;
+
+impl ColumnTemplateService {
+    /// 全部列模板，标准模板排在最前
+    pub fn list(store: &JsonStore) -> Result<Vec<ColumnTemplate>, StoreError> {
+        let mut templates = vec![ColumnTemplate::standard()];
+        templates.extend(
+            store
+                .list::<ColumnTemplate>(COLUMN_TEMPLATE_CATEGORY)?
+                .into_iter()
+                .filter(|template| template.name != STANDARD_TEMPLATE_NAME),
+        );
+        Ok(templates)
+    }
+
+    /// 按名称读取列模板，标准模板不需要保存
+    pub fn load(store: &JsonStore, name: &str) -> Result<Option<ColumnTemplate>, StoreError> {
+        if name == STANDARD_TEMPLATE_NAME {
+            return Ok(Some(ColumnTemplate::standard()));
+        }
+        store.load(COLUMN_TEMPLATE_CATEGORY, name)
+    }
+
+    /// 保存列模板，同名模板被覆盖
+    pub fn save(store: &JsonStore, template: &ColumnTemplate) -> Result<(), StoreError> {
+        store.save(COLUMN_TEMPLATE_CATEGORY, &template.name, template)
+    }
+
+    /// 删除列模板
+    pub fn remove(store: &JsonStore, name: &str) -> Result<(), StoreError> {
+        store.remove(COLUMN_TEMPLATE_CATEGORY, name)
+    }
+
+    /// 是否有项目选择了该列模板
+    pub fn is_selected(store: &JsonStore, name: &str) -> Result<bool, StoreError> {
+        Ok(store
+            .list::<String>(PROJECT_TEMPLATE_CATEGORY)?
+            .iter()
+            .any(|selected| selected == name))
+    }
+
+    /// 项目选择的列模板名称，未选择时返回None
+    pub fn selected_for_project(store: &JsonStore, project_number: &str) -> Result<Option<String>, StoreError> {
+        store.load(PROJECT_TEMPLATE_CATEGORY, project_number)
+    }
+
+    /// 为项目选择列模板，name为None时恢复使用标准模板
+    pub fn select_for_project(store: &JsonStore, project_number: &str, name: Option<&str>) -> Result<(), StoreError> {
+        match name {
+            Some(name) => store.save(PROJECT_TEMPLATE_CATEGORY, project_number, &name),
+            None => store.remove(PROJECT_TEMPLATE_CATEGORY, project_number),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::application_services::storage_services::json_store::{JsonStore, StoreError};
+use crate::model_domain::io_diff_model::IoTableSnapshotInfo;
+use crate::model_domain::io_table_model::IOTable;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 场站最新点表快照的存储类别
+const IO_TABLE_SNAPSHOT_CATEGORY: &str = "io_table_snapshots";
+
+/// 各版本点表快照的存储类别，键为"场站#版本号"
+const IO_TABLE_SNAPSHOT_VERSION_CATEGORY: &str = "io_table_snapshot_versions";
+
+/// 场站快照版本列表的存储类别
+const IO_TABLE_SNAPSHOT_INDEX_CATEGORY: &str = "io_table_snapshot_index";
+
+/// 点表快照服务，按场站保存每次生成的点表，用于版本对比
+///
+/// 每次保存都新增一个版本，并另存一份最新快照
+pub struct IoTableSnapshotService;
+
+impl IoTableSnapshotService {
+    /// 读取场站上次生成的点表，从未生成过时返回None
+    pub fn load(store: &JsonStore, station_name: &str) -> Result<Option<IOTable<'static>>, StoreError> {
+        store.load(IO_TABLE_SNAPSHOT_CATEGORY, station_name)
+    }
+
+    /// 读取场站指定版本的点表，版本不存在时返回None
+    pub fn load_version(
+        store: &JsonStore,
+        station_name: &str,
+        version: u32,
+    ) -> Result<Option<IOTable<'static>>, StoreError> {
+        store.load(IO_TABLE_SNAPSHOT_VERSION_CATEGORY, &version_key(station_name, version))
+    }
+
+    /// 场站已保存的快照版本，按版本号顺序排列
+    pub fn list(store: &JsonStore, station_name: &str) -> Result<Vec<IoTableSnapshotInfo>, StoreError> {
+        Ok(store.load(IO_TABLE_SNAPSHOT_INDEX_CATEGORY, station_name)?.unwrap_or_default())
+    }
+
+    /// 保存场站本次生成的点表，返回新增的快照版本
+    pub fn save(
+        store: &JsonStore,
+        station_name: &str,
+        table: &IOTable<'_>,
+        revision: Option<&str>,
+    ) -> Result<IoTableSnapshotInfo, StoreError> {
+        let mut versions = Self::list(store, station_name)?;
+        let info = IoTableSnapshotInfo {
+            version: versions.last().map_or(1, |last| last.version + 1),
+            revision: revision.map(str::to_string),
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        };
+
+        store.save(IO_TABLE_SNAPSHOT_VERSION_CATEGORY, &version_key(station_name, info.version), table)?;
+        store.save(IO_TABLE_SNAPSHOT_CATEGORY, station_name, table)?;
+        versions.push(info.clone());
+        store.save(IO_TABLE_SNAPSHOT_INDEX_CATEGORY, station_name, &versions)?;
+        Ok(info)
+    }
+}
+
+/// 版本快照的存储键
+fn version_key(station_name: &str, version: u32) -> String {
+    format!("{}#{}", station_name, version)
+}
@@ -0,0 +1,86 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// 本地存储错误类型
+#[derive(Error, Debug)]
+pub enum StoreError {
+    #[error("读写本地文件失败: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON序列化错误: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// 本地JSON存储
+/// 按"类别/键.json"保存项目级、场站级配置，例如地址规划
+pub struct JsonStore {
+    base_dir: PathBuf,
+}
+
+impl JsonStore {
+    /// 以指定目录（通常为应用数据目录）创建存储
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self { base_dir: base_dir.into() }
+    }
+
+    /// 读取数据，文件不存在时返回None
+    pub fn load<T: DeserializeOwned>(&self, category: &str, key: &str) -> Result<Option<T>, StoreError> {
+        let path = self.file_path(category, key);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// 保存数据，目录不存在时自动创建
+    pub fn save<T: Serialize>(&self, category: &str, key: &str, value: &T) -> Result<(), StoreError> {
+        let path = self.file_path(category, key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, serde_json::to_string_pretty(value)?)?;
+        Ok(())
+    }
+
+    /// 读取类别下的全部数据，按文件名排序；类别目录不存在时返回空列表
+    pub fn list<T: DeserializeOwned>(&self, category: &str) -> Result<Vec<T>, StoreError> {
+        let dir = self.base_dir.join(category);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(&dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+        paths
+            .iter()
+            .map(|path| Ok(serde_json::from_str(&fs::read_to_string(path)?)?))
+            .collect()
+    }
+
+    /// 删除数据，文件不存在时忽略
+    pub fn remove(&self, category: &str, key: &str) -> Result<(), StoreError> {
+        let path = self.file_path(category, key);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// 数据文件路径，键中不能用于文件名的字符替换为下划线
+    fn file_path(&self, category: &str, key: &str) -> PathBuf {
+        let file_name: String = key
+            .chars()
+            .map(|c| if matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') { '_' } else { c })
+            .collect();
+        self.base_dir.join(category).join(format!("{}.json", file_name))
+    }
+}
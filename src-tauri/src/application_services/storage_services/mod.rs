@@ -0,0 +1,4 @@
+pub mod json_store;
+pub mod io_table_snapshot_service;
+pub mod revision_history_service;
+pub mod column_template_service;
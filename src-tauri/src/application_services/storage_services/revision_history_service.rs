@@ -0,0 +1,20 @@
+use crate::application_services::storage_services::json_store::{JsonStore, StoreError};
+use crate::model_domain::revision_model::RevisionHistory;
+
+/// 修订历史的存储类别
+const REVISION_HISTORY_CATEGORY: &str = "revision_histories";
+
+/// 修订历史服务，按场站保存点表的修订记录
+pub struct RevisionHistoryService;
+
+impl RevisionHistoryService {
+    /// 读取场站的修订历史，从未生成过时为空
+    pub fn load(store: &JsonStore, station_name: &str) -> Result<RevisionHistory, StoreError> {
+        Ok(store.load(REVISION_HISTORY_CATEGORY, station_name)?.unwrap_or_default())
+    }
+
+    /// 保存场站的修订历史
+    pub fn save(store: &JsonStore, station_name: &str, history: &RevisionHistory) -> Result<(), StoreError> {
+        store.save(REVISION_HISTORY_CATEGORY, station_name, history)
+    }
+}
@@ -0,0 +1,256 @@
+use crate::application_services::excel_services::io_excel_reader::IOExcelReader;
+use crate::application_services::excel_services::io_excel_services::{get_column_letter, IoError};
+use crate::application_services::io_planning_services::spare_policy::SPARE_LABEL;
+use crate::model_domain::column_template_model::ColumnTemplate;
+use crate::model_domain::io_import_model::SheetLayout;
+use crate::model_domain::io_table_model::{IOTable, IOTableRow};
+use crate::model_domain::validation_model::{ValidationIssue, ValidationReport, ValidationRule, ValidationRuleSet};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 报警设定值列，按从小到大的顺序排列
+pub(crate) const ALARM_VALUE_FIELDS: [&str; 4] = ["SLL设定值", "SL设定值", "SH设定值", "SHH设定值"];
+
+/// IO点表工程校验服务
+pub struct IoTableValidator<'r> {
+    rules: &'r ValidationRuleSet,
+    layout: &'r SheetLayout,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<'r> IoTableValidator<'r> {
+    /// 按列模板读取点表文件并校验
+    pub fn validate_file(path: &Path, rules: &ValidationRuleSet, template: &ColumnTemplate) -> Result<ValidationReport, IoError> {
+        let imported = IOExcelReader::read_file(path, template)?;
+        let issues = IoTableValidator::validate(&imported.table, &imported.layout, rules);
+        Ok(ValidationReport::new(imported.sheet_name, issues))
+    }
+
+    /// 按规则集校验点表，问题按Excel行号和列号定位
+    pub fn validate(table: &IOTable<'_>, layout: &'r SheetLayout, rules: &'r ValidationRuleSet) -> Vec<ValidationIssue> {
+        let mut validator = Self { rules, layout, issues: Vec::new() };
+
+        for (row_index, row) in table.rows.iter().enumerate() {
+            validator.check_alarm_order(row_index, row);
+            validator.check_limits(row_index, row);
+            validator.check_description(row_index, row);
+            validator.check_tag(row_index, row);
+            validator.check_hmi_name(row_index, row);
+        }
+        validator.check_duplicates(table, "位号", ValidationRule::DuplicateTag, |row| {
+            row.tag.as_deref().map(str::trim).filter(|tag| *tag != SPARE_LABEL).map(str::to_string)
+        });
+        validator.check_duplicates(table, "变量名称（HMI）", ValidationRule::DuplicateHmiName, |row| {
+            row.variable_name_hmi.as_deref().map(|name| name.trim().to_ascii_uppercase())
+        });
+
+        let mut issues = validator.issues;
+        issues.sort_by_key(|issue| (issue.row, layout.column_number(&issue.column).unwrap_or(u32::MAX)));
+        issues
+    }
+
+    /// 报警设定值应从小到大排列，未填写的设定值跳过
+    fn check_alarm_order(&mut self, row_index: usize, row: &IOTableRow<'_>) {
+        let values: Vec<(&str, f64)> = ALARM_VALUE_FIELDS
+            .iter()
+            .filter_map(|field| Some((*field, numeric_value(row, field)?)))
+            .collect();
+
+        for pair in values.windows(2) {
+            let ((lower_field, lower), (upper_field, upper)) = (pair[0], pair[1]);
+            if lower > upper {
+                self.report(
+                    row_index,
+                    lower_field,
+                    ValidationRule::AlarmOrder,
+                    row,
+                    format!("{}({})大于{}({})", lower_field, lower, upper_field, upper),
+                );
+            }
+        }
+    }
+
+    /// 量程低限应小于量程高限，报警设定值应在量程范围内
+    fn check_limits(&mut self, row_index: usize, row: &IOTableRow<'_>) {
+        let (Some(low), Some(high)) = (numeric_value(row, "量程低限"), numeric_value(row, "量程高限")) else {
+            return;
+        };
+        if low >= high {
+            self.report(
+                row_index,
+                "量程高限",
+                ValidationRule::LimitOutOfRange,
+                row,
+                format!("量程低限({})不小于量程高限({})", low, high),
+            );
+            return;
+        }
+
+        for field in ALARM_VALUE_FIELDS {
+            let Some(value) = numeric_value(row, field) else {
+                continue;
+            };
+            if value < low || value > high {
+                self.report(
+                    row_index,
+                    field,
+                    ValidationRule::LimitOutOfRange,
+                    row,
+                    format!("{}({})超出量程{}~{}", field, value, low, high),
+                );
+            }
+        }
+    }
+
+    /// 已使用的通道应填写变量描述
+    fn check_description(&mut self, row_index: usize, row: &IOTableRow<'_>) {
+        let in_use = !row.is_spare
+            && [row.tag.as_deref(), row.variable_name_hmi.as_deref()]
+                .into_iter()
+                .any(|value| filled_value(value).is_some_and(|value| value != SPARE_LABEL));
+        if in_use && filled_value(row.variable_description.as_deref()).is_none() {
+            self.report(row_index, "变量描述", ValidationRule::MissingDescription, row, "已使用的通道未填写变量描述".to_string());
+        }
+    }
+
+    /// 已填写HMI变量名称或变量描述的通道应填写位号
+    fn check_tag(&mut self, row_index: usize, row: &IOTableRow<'_>) {
+        let in_use = !row.is_spare
+            && [row.variable_name_hmi.as_deref(), row.variable_description.as_deref()]
+                .into_iter()
+                .any(|value| filled_value(value).is_some_and(|value| value != SPARE_LABEL));
+        if in_use && row.tag.as_deref().unwrap_or_default().trim().is_empty() {
+            self.report(row_index, "位号", ValidationRule::MissingTag, row, "已使用的通道未填写位号".to_string());
+        }
+    }
+
+    /// HMI变量名称只能包含字母、数字和下划线，且不能以数字开头
+    fn check_hmi_name(&mut self, row_index: usize, row: &IOTableRow<'_>) {
+        let Some(name) = filled_value(row.variable_name_hmi.as_deref()) else {
+            return;
+        };
+        let valid_chars = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        let starts_with_digit = name.starts_with(|c: char| c.is_ascii_digit());
+        if !valid_chars || starts_with_digit {
+            self.report(
+                row_index,
+                "变量名称（HMI）",
+                ValidationRule::InvalidHmiName,
+                row,
+                "变量名称只能包含字母、数字和下划线，且不能以数字开头".to_string(),
+            );
+        }
+    }
+
+    /// 重复的值在第一次出现之后的每一行报告
+    fn check_duplicates(
+        &mut self,
+        table: &IOTable<'_>,
+        column: &str,
+        rule: ValidationRule,
+        key: impl Fn(&IOTableRow<'_>) -> Option<String>,
+    ) {
+        let mut first_rows: HashMap<String, u32> = HashMap::new();
+        for (row_index, row) in table.rows.iter().enumerate() {
+            let Some(value) = key(row).filter(|value| !value.is_empty() && value != "/") else {
+                continue;
+            };
+            match first_rows.get(&value) {
+                Some(first_row) => {
+                    let message = format!("{}与第{}行重复", column, first_row);
+                    self.report(row_index, column, rule, row, message);
+                }
+                None => {
+                    first_rows.insert(value, self.layout.row_number(row_index));
+                }
+            }
+        }
+    }
+
+    /// 按规则集的设置记录问题，规则未启用时忽略
+    fn report(&mut self, row_index: usize, column: &str, rule: ValidationRule, row: &IOTableRow<'_>, message: String) {
+        let Some(severity) = self.rules.severity_for(rule) else {
+            return;
+        };
+        let row_number = self.layout.row_number(row_index);
+        let cell = self.layout
+            .column_number(column)
+            .map(|col| format!("{}{}", get_column_letter(col), row_number))
+            .unwrap_or_default();
+        self.issues.push(ValidationIssue {
+            row: row_number,
+            column: column.to_string(),
+            cell,
+            rule,
+            severity,
+            value: row.get_field_by_name(column).map(|value| value.to_string()).unwrap_or_default(),
+            message,
+        });
+    }
+}
+
+/// 已填写的值，空值和"/"返回None
+fn filled_value(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty() && *value != "/")
+}
+
+/// 字段的数值，未填写或不是数值时返回None
+fn numeric_value(row: &IOTableRow<'_>, field: &str) -> Option<f64> {
+    filled_value(row.get_field_by_name(field).map(|value| value.as_ref()))?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_domain::validation_model::Severity;
+    use std::borrow::Cow;
+
+    fn row(tag: &str, hmi: &str, description: &str) -> IOTableRow<'static> {
+        IOTableRow {
+            tag: Some(Cow::Owned(tag.to_string())),
+            variable_name_hmi: Some(Cow::Owned(hmi.to_string())),
+            variable_description: Some(Cow::Owned(description.to_string())),
+            range_lower_limit: Some(Cow::Borrowed("0")),
+            range_upper_limit: Some(Cow::Borrowed("10")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_engineering_rules() {
+        let mut table = IOTable::new("校验".to_string());
+        let mut first = row("PT-101", "PT101", "进站压力");
+        first.sll_value = Some(Cow::Borrowed("3"));
+        first.sl_value = Some(Cow::Borrowed("2"));
+        first.shh_value = Some(Cow::Borrowed("12"));
+        table.add_row(first);
+        table.add_row(row("PT-101", "pt101", ""));
+        table.add_row(row("PT-103", "1PT-103", "出站压力"));
+        let mut spare = row(SPARE_LABEL, "", SPARE_LABEL);
+        spare.is_spare = true;
+        table.add_row(spare.clone());
+        table.add_row(spare);
+        table.add_row(row("", "PT104", "出站温度"));
+
+        let layout = SheetLayout::standard(table.len());
+        let issues = IoTableValidator::validate(&table, &layout, &ValidationRuleSet::default());
+        let found: Vec<(&str, ValidationRule)> = issues.iter().map(|issue| (issue.cell.as_str(), issue.rule)).collect();
+        assert_eq!(found, vec![
+            ("Q2", ValidationRule::AlarmOrder),
+            ("AC2", ValidationRule::LimitOutOfRange),
+            ("G3", ValidationRule::DuplicateTag),
+            ("I3", ValidationRule::DuplicateHmiName),
+            ("J3", ValidationRule::MissingDescription),
+            ("I4", ValidationRule::InvalidHmiName),
+            ("G7", ValidationRule::MissingTag),
+        ]);
+        assert_eq!(issues[2].message, "位号与第2行重复");
+        assert_eq!(issues[4].severity, Severity::Warning);
+
+        // 关闭的规则不再报告
+        let mut rules = ValidationRuleSet::default();
+        rules.missing_description.enabled = false;
+        let issues = IoTableValidator::validate(&table, &layout, &rules);
+        assert!(issues.iter().all(|issue| issue.rule != ValidationRule::MissingDescription));
+    }
+}
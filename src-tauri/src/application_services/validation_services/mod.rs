@@ -0,0 +1 @@
+pub mod io_table_validator;
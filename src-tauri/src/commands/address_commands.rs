@@ -0,0 +1,33 @@
+use tauri::{command, Manager};
+use crate::application_services::address_services::address_plan_service::AddressPlanService;
+use crate::application_services::storage_services::json_store::JsonStore;
+use crate::model_domain::address_plan_model::AddressPlan;
+
+/// 打开应用数据目录下的本地存储
+pub(crate) fn open_local_store(app_handle: &tauri::AppHandle) -> Result<JsonStore, String> {
+    let data_dir = app_handle.path()
+        .app_data_dir()
+        .map_err(|e| format!("获取应用数据目录失败: {}", e))?;
+    Ok(JsonStore::new(data_dir))
+}
+
+#[command]
+pub async fn get_address_plan(
+    #[allow(non_snake_case)] projectNumber: String,
+    app_handle: tauri::AppHandle
+) -> Result<AddressPlan, String> {
+    let store = open_local_store(&app_handle)?;
+    AddressPlanService::load(&store, &projectNumber)
+        .map_err(|e| format!("读取地址规划失败: {}", e))
+}
+
+#[command]
+pub async fn save_address_plan(
+    #[allow(non_snake_case)] projectNumber: String,
+    plan: AddressPlan,
+    app_handle: tauri::AppHandle
+) -> Result<(), String> {
+    let store = open_local_store(&app_handle)?;
+    AddressPlanService::save(&store, &projectNumber, &plan)
+        .map_err(|e| format!("保存地址规划失败: {}", e))
+}
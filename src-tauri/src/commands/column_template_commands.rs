@@ -0,0 +1,110 @@
+use tauri::command;
+use std::fs;
+use crate::application_services::storage_services::column_template_service::ColumnTemplateService;
+use crate::application_services::storage_services::json_store::JsonStore;
+use crate::model_domain::column_template_model::{ColumnTemplate, STANDARD_TEMPLATE_NAME};
+use super::address_commands::open_local_store;
+
+/// 确定使用的列模板：优先使用指定的模板，其次使用项目选择的模板，都没有时使用标准模板
+pub(crate) fn load_column_template(
+    store: &JsonStore,
+    template_name: Option<&str>,
+    project_number: Option<&str>
+) -> Result<ColumnTemplate, String> {
+    let selected = match (template_name, project_number) {
+        (Some(name), _) => Some(name.to_string()),
+        (None, Some(project_number)) => ColumnTemplateService::selected_for_project(store, project_number)
+            .map_err(|e| format!("读取项目列模板失败: {}", e))?,
+        (None, None) => None,
+    };
+    let Some(name) = selected else {
+        return Ok(ColumnTemplate::standard());
+    };
+
+    ColumnTemplateService::load(store, &name)
+        .map_err(|e| format!("读取列模板失败: {}", e))?
+        .ok_or_else(|| format!("列模板{}不存在", name))
+}
+
+/// 检查并保存列模板，标准模板不能覆盖
+fn check_and_save(store: &JsonStore, template: &ColumnTemplate) -> Result<(), String> {
+    template.check()?;
+    if template.name == STANDARD_TEMPLATE_NAME {
+        return Err(format!("不能覆盖{}模板", STANDARD_TEMPLATE_NAME));
+    }
+    ColumnTemplateService::save(store, template)
+        .map_err(|e| format!("保存列模板失败: {}", e))
+}
+
+#[command]
+pub async fn list_column_templates(
+    app_handle: tauri::AppHandle
+) -> Result<Vec<ColumnTemplate>, String> {
+    let store = open_local_store(&app_handle)?;
+    ColumnTemplateService::list(&store)
+        .map_err(|e| format!("读取列模板失败: {}", e))
+}
+
+#[command]
+pub async fn save_column_template(
+    template: ColumnTemplate,
+    app_handle: tauri::AppHandle
+) -> Result<(), String> {
+    let store = open_local_store(&app_handle)?;
+    check_and_save(&store, &template)
+}
+
+#[command]
+pub async fn import_column_template(
+    #[allow(non_snake_case)] filePath: String,
+    app_handle: tauri::AppHandle
+) -> Result<ColumnTemplate, String> {
+    let content = fs::read_to_string(&filePath)
+        .map_err(|e| format!("读取列模板文件失败: {}", e))?;
+    let template: ColumnTemplate = serde_json::from_str(&content)
+        .map_err(|e| format!("解析列模板文件失败: {}", e))?;
+
+    let store = open_local_store(&app_handle)?;
+    check_and_save(&store, &template)?;
+    Ok(template)
+}
+
+#[command]
+pub async fn delete_column_template(
+    #[allow(non_snake_case)] templateName: String,
+    app_handle: tauri::AppHandle
+) -> Result<(), String> {
+    let store = open_local_store(&app_handle)?;
+    // 仍有项目选择该模板时不允许删除，避免项目指向不存在的模板
+    let selected = ColumnTemplateService::is_selected(&store, &templateName)
+        .map_err(|e| format!("删除列模板失败: {}", e))?;
+    if selected {
+        return Err(format!("删除列模板失败: 模板\"{}\"正被项目使用，请先为项目改选其他模板", templateName));
+    }
+    ColumnTemplateService::remove(&store, &templateName)
+        .map_err(|e| format!("删除列模板失败: {}", e))
+}
+
+#[command]
+pub async fn get_project_column_template(
+    #[allow(non_snake_case)] projectNumber: String,
+    app_handle: tauri::AppHandle
+) -> Result<ColumnTemplate, String> {
+    let store = open_local_store(&app_handle)?;
+    load_column_template(&store, None, Some(&projectNumber))
+}
+
+#[command]
+pub async fn select_project_column_template(
+    #[allow(non_snake_case)] projectNumber: String,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    app_handle: tauri::AppHandle
+) -> Result<(), String> {
+    let store = open_local_store(&app_handle)?;
+    // 选择的模板必须已存在
+    if let Some(name) = templateName.as_deref() {
+        load_column_template(&store, Some(name), None)?;
+    }
+    ColumnTemplateService::select_for_project(&store, &projectNumber, templateName.as_deref())
+        .map_err(|e| format!("保存项目列模板失败: {}", e))
+}
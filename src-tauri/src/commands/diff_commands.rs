@@ -0,0 +1,123 @@
+use tauri::{command, Manager};
+use crate::application_services::diff_services::io_table_diff_service::{DiffSide, IoTableDiffService};
+use crate::application_services::excel_services::diff_sheet::IoDiffSheetService;
+use crate::application_services::excel_services::io_excel_reader::IOExcelReader;
+use crate::application_services::storage_services::io_table_snapshot_service::IoTableSnapshotService;
+use crate::model_domain::io_diff_model::{DiffMatchKey, DiffSource, IoTableDiff, IoTableSnapshotInfo};
+use crate::model_domain::io_import_model::SheetLayout;
+use crate::model_domain::io_table_model::IOTable;
+use super::address_commands::open_local_store;
+use super::column_template_commands::load_column_template;
+use super::export_commands::save_with_dialog;
+use super::import_commands::pick_xlsx_file;
+
+/// 读取参与对比的点表及其在工作表中的位置，快照没有位置信息时返回None
+async fn load_diff_source(
+    app_handle: &tauri::AppHandle,
+    source: DiffSource
+) -> Result<(IOTable<'static>, Option<SheetLayout>), String> {
+    match source {
+        DiffSource::File { path, template_name } => {
+            let store = open_local_store(app_handle)?;
+            let template = load_column_template(&store, template_name.as_deref(), None)?;
+            let path = pick_xlsx_file(app_handle, path).await?;
+            let imported = tauri::async_runtime::spawn_blocking(move || IOExcelReader::read_file(&path, &template))
+                .await
+                .map_err(|e| format!("读取文件操作失败: {}", e))?
+                .map_err(|e| format!("读取IO点表失败: {}", e))?;
+            Ok((imported.table, Some(imported.layout)))
+        }
+        DiffSource::Snapshot { station_name, version } => {
+            let store = open_local_store(app_handle)?;
+            let table = match version {
+                Some(version) => IoTableSnapshotService::load_version(&store, &station_name, version)
+                    .map_err(|e| format!("读取点表快照失败: {}", e))?
+                    .ok_or_else(|| format!("场站{}的点表快照版本{}不存在", station_name, version))?,
+                None => IoTableSnapshotService::load(&store, &station_name)
+                    .map_err(|e| format!("读取点表快照失败: {}", e))?
+                    .ok_or_else(|| format!("场站{}尚未生成过点表", station_name))?,
+            };
+            Ok((table, None))
+        }
+    }
+}
+
+/// 读取两版点表并对比
+async fn diff_sources(
+    app_handle: &tauri::AppHandle,
+    old_source: DiffSource,
+    new_source: DiffSource,
+    match_by: Option<DiffMatchKey>
+) -> Result<(IOTable<'static>, IOTable<'static>, IoTableDiff), String> {
+    let (old_table, old_layout) = load_diff_source(app_handle, old_source).await?;
+    let (new_table, new_layout) = load_diff_source(app_handle, new_source).await?;
+
+    let side = |table, layout: Option<SheetLayout>| match layout {
+        Some(layout) => DiffSide { table, layout },
+        None => DiffSide::standard(table),
+    };
+    let diff = IoTableDiffService::diff(
+        &side(&old_table, old_layout),
+        &side(&new_table, new_layout),
+        match_by.unwrap_or_default(),
+    );
+    Ok((old_table, new_table, diff))
+}
+
+#[command]
+pub async fn list_io_table_snapshots(
+    #[allow(non_snake_case)] stationName: String,
+    app_handle: tauri::AppHandle
+) -> Result<Vec<IoTableSnapshotInfo>, String> {
+    let store = open_local_store(&app_handle)?;
+    IoTableSnapshotService::list(&store, &stationName)
+        .map_err(|e| format!("读取点表快照失败: {}", e))
+}
+
+#[command]
+pub async fn diff_io_point_tables(
+    #[allow(non_snake_case)] oldSource: DiffSource,
+    #[allow(non_snake_case)] newSource: DiffSource,
+    #[allow(non_snake_case)] matchBy: Option<DiffMatchKey>,
+    window: tauri::Window
+) -> Result<IoTableDiff, String> {
+    let (_, _, diff) = diff_sources(window.app_handle(), oldSource, newSource, matchBy).await?;
+    Ok(diff)
+}
+
+#[command]
+pub async fn export_io_table_diff(
+    #[allow(non_snake_case)] oldSource: DiffSource,
+    #[allow(non_snake_case)] newSource: DiffSource,
+    #[allow(non_snake_case)] matchBy: Option<DiffMatchKey>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let (old_table, new_table, diff) = diff_sources(app_handle, oldSource, newSource, matchBy).await?;
+
+    let file_name = format!("{}_对比_{}.xlsx", diff.old_table, diff.new_table);
+    let content = IoDiffSheetService::build_workbook(&diff, &old_table, &new_table)
+        .map_err(|e| format!("生成点表差异表失败: {}", e))?;
+    save_with_dialog(app_handle, file_name, "Excel文件", &["xlsx"], content).await
+}
+
+#[command]
+pub async fn export_io_table_diff_report(
+    #[allow(non_snake_case)] oldSource: DiffSource,
+    #[allow(non_snake_case)] newSource: DiffSource,
+    #[allow(non_snake_case)] matchBy: Option<DiffMatchKey>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let (_, _, diff) = diff_sources(app_handle, oldSource, newSource, matchBy).await?;
+
+    let json = serde_json::to_vec_pretty(&diff)
+        .map_err(|e| format!("序列化点表差异失败: {}", e))?;
+    save_with_dialog(
+        app_handle,
+        format!("{}_对比_{}.json", diff.old_table, diff.new_table),
+        "JSON文件",
+        &["json"],
+        json,
+    ).await
+}
@@ -0,0 +1,140 @@
+use tauri::{command, Manager};
+use tauri_plugin_dialog::DialogExt;
+use std::fs;
+use crate::application_services::excel_services::io_excel_reader::IOExcelReader;
+use crate::application_services::address_services::address_plan_service::AddressPlanService;
+use crate::application_services::address_services::dnp3_allocator::Dnp3Allocator;
+use crate::application_services::protocol_export_services::opcua_nodeset_service::OpcUaNodeSetService;
+use crate::application_services::protocol_export_services::dnp3_csv_service::Dnp3CsvService;
+use crate::application_services::protocol_export_services::sparkplug_service::SparkplugService;
+use crate::model_domain::address_plan_model::AddressPlan;
+use crate::model_domain::io_table_model::IOTable;
+use crate::model_domain::sparkplug_model::SparkplugNamingRules;
+use super::address_commands::open_local_store;
+use super::column_template_commands::load_column_template;
+use super::import_commands::pick_xlsx_file;
+
+/// 读取工程师填写过的点表，导出的地址空间包含填写的位号、描述和量程
+///
+/// 未指定路径时提示用户选择；按指定的列模板读取，未指定时使用项目选择的模板
+async fn load_filled_io_table(
+    app_handle: &tauri::AppHandle,
+    file_path: Option<String>,
+    template_name: Option<String>,
+    project_number: Option<&str>
+) -> Result<IOTable<'static>, String> {
+    let store = open_local_store(app_handle)?;
+    let template = load_column_template(&store, template_name.as_deref(), project_number)?;
+    let path = pick_xlsx_file(app_handle, file_path).await?;
+
+    let imported = tauri::async_runtime::spawn_blocking(move || IOExcelReader::read_file(&path, &template))
+        .await
+        .map_err(|e| format!("读取文件操作失败: {}", e))?
+        .map_err(|e| format!("读取IO点表失败: {}", e))?;
+    Ok(imported.table)
+}
+
+/// 提示用户选择保存位置并写入文件，返回保存路径
+pub(crate) async fn save_with_dialog(
+    app_handle: &tauri::AppHandle,
+    file_name: String,
+    filter_name: &'static str,
+    extensions: &'static [&'static str],
+    content: Vec<u8>
+) -> Result<String, String> {
+    let app_handle = app_handle.clone();
+    let save_path = tauri::async_runtime::spawn_blocking(move || {
+        app_handle.dialog()
+            .file()
+            .add_filter(filter_name, extensions)
+            .set_file_name(&file_name)
+            .blocking_save_file()
+    }).await.map_err(|e| format!("对话框操作失败: {}", e))?;
+
+    let Some(filepath) = save_path else {
+        return Err("用户取消了保存操作".to_string());
+    };
+    let path_str = filepath.to_string();
+    let dest_path = path_str.clone();
+    tauri::async_runtime::spawn_blocking(move || fs::write(dest_path, content))
+        .await
+        .map_err(|e| format!("文件写入操作失败: {}", e))?
+        .map_err(|e| format!("保存文件失败: {}", e))?;
+    Ok(path_str)
+}
+
+#[command]
+pub async fn export_opcua_nodeset(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    #[allow(non_snake_case)] stationName: String,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    #[allow(non_snake_case)] projectNumber: Option<String>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let io_table = load_filled_io_table(app_handle, filePath, templateName, projectNumber.as_deref()).await?;
+
+    let xml = OpcUaNodeSetService::build_nodeset(&io_table);
+    save_with_dialog(
+        app_handle,
+        format!("{}.NodeSet2.xml", stationName),
+        "NodeSet2文件",
+        &["xml"],
+        xml.into_bytes(),
+    ).await
+}
+
+#[command]
+pub async fn export_dnp3_point_list(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    #[allow(non_snake_case)] stationName: String,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    #[allow(non_snake_case)] projectNumber: Option<String>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let io_table = load_filled_io_table(app_handle, filePath, templateName, projectNumber.as_deref()).await?;
+
+    // DNP3点表配置取项目保存的地址规划，未指定项目时使用默认规划
+    let plan = match projectNumber.as_deref() {
+        Some(project_number) => AddressPlanService::load(&open_local_store(app_handle)?, project_number)
+            .map_err(|e| format!("读取地址规划失败: {}", e))?,
+        None => AddressPlan::default(),
+    };
+    let points = Dnp3Allocator::new(&plan.dnp3)
+        .and_then(|mut allocator| allocator.allocate_rows(&io_table.rows))
+        .map_err(|e| format!("分配DNP3点位失败: {}", e))?;
+    let csv = Dnp3CsvService::build_csv(&points);
+    save_with_dialog(
+        app_handle,
+        format!("{}_DNP3点表.csv", stationName),
+        "CSV文件",
+        &["csv"],
+        csv.into_bytes(),
+    ).await
+}
+
+#[command]
+pub async fn export_sparkplug_mapping(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    #[allow(non_snake_case)] stationName: String,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    #[allow(non_snake_case)] projectNumber: Option<String>,
+    #[allow(non_snake_case)] namingRules: Option<SparkplugNamingRules>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let io_table = load_filled_io_table(app_handle, filePath, templateName, projectNumber.as_deref()).await?;
+
+    let mapping = SparkplugService::build_mapping(&io_table, &namingRules.unwrap_or_default())
+        .map_err(|e| format!("生成Sparkplug B映射失败: {}", e))?;
+    let json = serde_json::to_vec_pretty(&mapping)
+        .map_err(|e| format!("序列化Sparkplug B映射失败: {}", e))?;
+    save_with_dialog(
+        app_handle,
+        format!("{}_Sparkplug映射.json", stationName),
+        "JSON文件",
+        &["json"],
+        json,
+    ).await
+}
@@ -0,0 +1,42 @@
+use tauri::{command, Manager};
+use tauri_plugin_dialog::DialogExt;
+use std::path::PathBuf;
+use crate::application_services::excel_services::io_excel_reader::IOExcelReader;
+use crate::model_domain::io_import_model::IoImportResult;
+use super::address_commands::open_local_store;
+use super::column_template_commands::load_column_template;
+
+/// 未指定路径时提示用户选择点表文件
+pub(crate) async fn pick_xlsx_file(app_handle: &tauri::AppHandle, file_path: Option<String>) -> Result<PathBuf, String> {
+    if let Some(file_path) = file_path {
+        return Ok(PathBuf::from(file_path));
+    }
+
+    let app_handle = app_handle.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        app_handle.dialog()
+            .file()
+            .add_filter("Excel文件", &["xlsx"])
+            .blocking_pick_file()
+    }).await.map_err(|e| format!("对话框操作失败: {}", e))?;
+
+    picked
+        .map(|path| PathBuf::from(path.to_string()))
+        .ok_or_else(|| "用户取消了选择文件".to_string())
+}
+
+#[command]
+pub async fn import_io_point_table(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    window: tauri::Window
+) -> Result<IoImportResult, String> {
+    let store = open_local_store(window.app_handle())?;
+    let template = load_column_template(&store, templateName.as_deref(), None)?;
+    let path = pick_xlsx_file(window.app_handle(), filePath).await?;
+
+    tauri::async_runtime::spawn_blocking(move || IOExcelReader::read_file(&path, &template))
+        .await
+        .map_err(|e| format!("读取文件操作失败: {}", e))?
+        .map_err(|e| format!("导入IO点表失败: {}", e))
+}
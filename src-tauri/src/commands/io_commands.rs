@@ -5,18 +5,32 @@ use tauri::Manager;
 use tauri_plugin_dialog::DialogExt;
 use std::path::Path;
 use defer;
-use crate::application_services::excel_services::io_excel_services::{IOExcelService, convert_equipment_items};
+use crate::application_services::excel_services::io_excel_services::{IOExcelService, IOChannelType, GenerationOptions, convert_equipment_items};
+use crate::application_services::excel_services::io_excel_reader::IOExcelReader;
+use crate::application_services::io_planning_services::spare_policy::SparePolicy;
+use crate::application_services::address_services::address_plan_service::AddressPlanService;
+use crate::application_services::address_services::address_lock_service::AddressLockService;
+use crate::application_services::storage_services::io_table_snapshot_service::IoTableSnapshotService;
+use crate::application_services::storage_services::revision_history_service::RevisionHistoryService;
+use crate::application_services::storage_services::json_store::JsonStore;
+use crate::model_domain::generation_report_model::GenerationResult;
+use super::address_commands::open_local_store;
+use super::column_template_commands::load_column_template;
 use std::process::Command;
 
 #[command]
 pub async fn process_station_data(
-    #[allow(non_snake_case)] equipmentData: Vec<serde_json::Value>
+    #[allow(non_snake_case)] equipmentData: Vec<serde_json::Value>,
+    #[allow(non_snake_case)] sparePolicy: Option<SparePolicy>,
+    #[allow(non_snake_case)] signalCounts: Option<HashMap<IOChannelType, u32>>
 ) -> Result<HashMap<String, serde_json::Value>, String> {
     // 转换设备数据
     let equipment_list = convert_equipment_items(equipmentData);
+    let spare_policy = sparePolicy.unwrap_or_default();
+    let signal_counts = signalCounts.unwrap_or_default();
     
     // 处理数据并获取统计结果
-    match IOExcelService::process_station_data(&equipment_list) {
+    match IOExcelService::process_station_data(&equipment_list, &spare_policy, &signal_counts) {
         Ok(channel_totals) => {
             // 将结构体转换为可序列化的Map
             let result: HashMap<String, serde_json::Value> = channel_totals
@@ -24,7 +38,10 @@ pub async fn process_station_data(
                 .map(|(key, value)| {
                     (key, serde_json::json!({
                         "count": value.count,
-                        "data_type": value.data_type
+                        "data_type": value.data_type,
+                        "spare": value.spare,
+                        "used": value.used,
+                        "extra_modules": value.extra_modules
                     }))
                 })
                 .collect();
@@ -35,18 +52,70 @@ pub async fn process_station_data(
     }
 }
 
+/// 补全生成选项：读取项目保存的地址规划、列模板和场站上次锁定的地址
+fn load_generation_options(
+    store: &JsonStore,
+    options: Option<GenerationOptions>,
+    project_number: Option<&str>,
+    station_name: &str
+) -> Result<GenerationOptions, String> {
+    let mut options = options.unwrap_or_default();
+
+    // 未指定地址规划时使用该项目保存的地址规划
+    if let (None, Some(project_number)) = (&options.address_plan, project_number) {
+        options.address_plan = Some(AddressPlanService::load(store, project_number)
+            .map_err(|e| format!("读取地址规划失败: {}", e))?);
+    }
+
+    // 沿用该场站上次生成时锁定的地址
+    if !options.reset_address_lock {
+        options.address_lock = AddressLockService::load(store, station_name)
+            .map_err(|e| format!("读取地址锁定失败: {}", e))?;
+    }
+
+    // 点表列模板：指定的模板或项目选择的模板
+    options.column_template = load_column_template(store, options.column_template_name.as_deref(), project_number)?;
+
+    // 合并模式下读取上次填写过的点表
+    if let Some(path) = options.previous_table_path.as_deref() {
+        let previous = IOExcelReader::read_file(Path::new(path), &options.column_template)
+            .map_err(|e| format!("读取上次点表失败: {}", e))?;
+        options.previous_table = Some(previous.table);
+    }
+
+    // 生成封面时读取修订历史和上一版点表，用于确定修订号和标记变更
+    if options.document.is_some() {
+        options.revision_history = RevisionHistoryService::load(store, station_name)
+            .map_err(|e| format!("读取修订历史失败: {}", e))?;
+        // 以上一版修订保存的快照为基准，修订之间生成但未记录修订的点表不影响标记
+        let baseline = options.revision_history.entries.iter().rev().find_map(|entry| entry.snapshot_version);
+        options.previous_revision = match baseline {
+            Some(version) => IoTableSnapshotService::load_version(store, station_name, version)
+                .map_err(|e| format!("读取点表快照失败: {}", e))?,
+            None => None,
+        };
+    }
+
+    Ok(options)
+}
+
 #[command]
 pub async fn generate_io_point_table(
     #[allow(non_snake_case)] equipmentData: Vec<serde_json::Value>,
     #[allow(non_snake_case)] stationName: String,
+    options: Option<GenerationOptions>,
+    #[allow(non_snake_case)] projectNumber: Option<String>,
     window: tauri::Window
-) -> Result<String, String> {
+) -> Result<GenerationResult, String> {
     // 转换设备数据
     let equipment_list = convert_equipment_items(equipmentData);
+
+    let store = open_local_store(window.app_handle())?;
+    let options = load_generation_options(&store, options, projectNumber.as_deref(), &stationName)?;
     
     // 生成临时点表文件
-    let temp_file_path = match IOExcelService::generate_io_table(&equipment_list, &stationName) {
-        Ok(path) => path,
+    let (temp_file_path, mut report) = match IOExcelService::generate_io_table(&equipment_list, &stationName, &options) {
+        Ok(result) => result,
         Err(e) => return Err(e)
     };
     
@@ -90,7 +159,29 @@ pub async fn generate_io_point_table(
             match tauri::async_runtime::spawn_blocking(move || {
                 fs::copy(&temp_path, &dest_path)
             }).await.map_err(|e| format!("文件复制操作失败: {}", e))? {
-                Ok(_) => Ok(path_str),
+                Ok(_) => {
+                    // 点表保存成功后锁定本次分配的地址
+                    AddressLockService::save(&store, &stationName, &report.address_lock)
+                        .map_err(|e| format!("保存地址锁定失败: {}", e))?;
+                    // 保存本次点表，作为版本对比的基准
+                    let snapshot = match &report.io_table {
+                        Some(io_table) => {
+                            let revision = report.revision.as_ref().map(|revision| revision.revision.as_str());
+                            Some(IoTableSnapshotService::save(&store, &stationName, io_table, revision)
+                                .map_err(|e| format!("保存点表快照失败: {}", e))?)
+                        }
+                        None => None,
+                    };
+                    // 记录本次修订及其快照版本
+                    if let Some(revision) = report.revision.as_mut() {
+                        revision.snapshot_version = snapshot.map(|snapshot| snapshot.version);
+                        let mut history = options.revision_history.clone();
+                        history.entries.push(revision.clone());
+                        RevisionHistoryService::save(&store, &stationName, &history)
+                            .map_err(|e| format!("保存修订历史失败: {}", e))?;
+                    }
+                    Ok(GenerationResult { file_path: path_str, report })
+                },
                 Err(e) => Err(format!("保存文件失败: {}", e))
             }
         },
@@ -1,5 +1,19 @@
 mod query_commands;
 mod io_commands;
+mod planning_commands;
+mod address_commands;
+mod export_commands;
+mod import_commands;
+mod validation_commands;
+mod diff_commands;
+mod column_template_commands;
 
 pub use query_commands::*;
-pub use io_commands::*; 
\ No newline at end of file
+pub use io_commands::*;
+pub use planning_commands::*;
+pub use address_commands::*;
+pub use export_commands::*;
+pub use import_commands::*;
+pub use validation_commands::*;
+pub use diff_commands::*;
+pub use column_template_commands::*; 
\ No newline at end of file
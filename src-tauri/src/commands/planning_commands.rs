@@ -0,0 +1,34 @@
+use tauri::command;
+use crate::application_services::excel_services::io_excel_services::{GenerationOptions, IOChannelType, convert_equipment_items};
+use crate::application_services::io_planning_services::io_sizing_service::{IoSizingResult, IoSizingService, SignalList};
+use crate::application_services::io_planning_services::rack_planning_service::RackPlanningService;
+use crate::application_services::io_planning_services::spare_policy::SparePolicy;
+use crate::model_domain::rack_plan_model::RackCapacityPlan;
+
+#[command]
+pub async fn plan_rack_capacity(
+    #[allow(non_snake_case)] equipmentData: Vec<serde_json::Value>,
+    options: Option<GenerationOptions>
+) -> Result<RackCapacityPlan, String> {
+    // 转换设备数据
+    let equipment_list = convert_equipment_items(equipmentData);
+    // 按生成点表时的排布策略、槽位指定和备用策略规划
+    let options = options.unwrap_or_default();
+
+    RackPlanningService::plan_capacity(&equipment_list, &options)
+        .map_err(|e| format!("机架容量规划失败: {}", e))
+}
+
+#[command]
+pub async fn size_io_from_signals(
+    #[allow(non_snake_case)] signalList: SignalList,
+    #[allow(non_snake_case)] sparePolicy: Option<SparePolicy>,
+    #[allow(non_snake_case)] stationName: String,
+    #[allow(non_snake_case)] redundantTypes: Option<Vec<IOChannelType>>
+) -> Result<IoSizingResult, String> {
+    let spare_policy = sparePolicy.unwrap_or_default();
+    let redundant_types = redundantTypes.unwrap_or_default();
+
+    IoSizingService::size_from_signals(&signalList, &spare_policy, &stationName, &redundant_types)
+        .map_err(|e| format!("IO选型失败: {}", e))
+}
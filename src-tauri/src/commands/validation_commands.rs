@@ -0,0 +1,86 @@
+use tauri::{command, Manager};
+use std::path::PathBuf;
+use crate::application_services::excel_services::validation_annotator::ValidationAnnotator;
+use crate::application_services::validation_services::io_table_validator::IoTableValidator;
+use crate::model_domain::validation_model::{ValidationReport, ValidationRuleSet};
+use super::address_commands::open_local_store;
+use super::column_template_commands::load_column_template;
+use super::export_commands::save_with_dialog;
+use super::import_commands::pick_xlsx_file;
+
+/// 选择点表文件并按列模板校验，返回文件路径及校验结果
+async fn validate_picked_file(
+    app_handle: &tauri::AppHandle,
+    file_path: Option<String>,
+    rules: Option<ValidationRuleSet>,
+    template_name: Option<String>
+) -> Result<(PathBuf, ValidationReport), String> {
+    let store = open_local_store(app_handle)?;
+    let template = load_column_template(&store, template_name.as_deref(), None)?;
+    let path = pick_xlsx_file(app_handle, file_path).await?;
+    let rules = rules.unwrap_or_default();
+
+    let source = path.clone();
+    let report = tauri::async_runtime::spawn_blocking(move || IoTableValidator::validate_file(&source, &rules, &template))
+        .await
+        .map_err(|e| format!("读取文件操作失败: {}", e))?
+        .map_err(|e| format!("校验IO点表失败: {}", e))?;
+    Ok((path, report))
+}
+
+/// 点表文件名（不含扩展名）
+fn file_stem(path: &std::path::Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[command]
+pub async fn validate_io_point_table(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    rules: Option<ValidationRuleSet>,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    window: tauri::Window
+) -> Result<ValidationReport, String> {
+    let (_, report) = validate_picked_file(window.app_handle(), filePath, rules, templateName).await?;
+    Ok(report)
+}
+
+#[command]
+pub async fn export_validation_annotated_workbook(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    rules: Option<ValidationRuleSet>,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let (path, report) = validate_picked_file(app_handle, filePath, rules, templateName).await?;
+
+    let file_name = format!("{}_校验标注.xlsx", file_stem(&path));
+    let content = tauri::async_runtime::spawn_blocking(move || ValidationAnnotator::annotate_file(&path, &report))
+        .await
+        .map_err(|e| format!("标注文件操作失败: {}", e))?
+        .map_err(|e| format!("生成校验标注点表失败: {}", e))?;
+    save_with_dialog(app_handle, file_name, "Excel文件", &["xlsx"], content).await
+}
+
+#[command]
+pub async fn export_validation_report(
+    #[allow(non_snake_case)] filePath: Option<String>,
+    rules: Option<ValidationRuleSet>,
+    #[allow(non_snake_case)] templateName: Option<String>,
+    window: tauri::Window
+) -> Result<String, String> {
+    let app_handle = window.app_handle();
+    let (path, report) = validate_picked_file(app_handle, filePath, rules, templateName).await?;
+
+    let json = serde_json::to_vec_pretty(&report)
+        .map_err(|e| format!("序列化校验结果失败: {}", e))?;
+    save_with_dialog(
+        app_handle,
+        format!("{}_校验结果.json", file_stem(&path)),
+        "JSON文件",
+        &["json"],
+        json,
+    ).await
+}
@@ -16,6 +16,27 @@ pub fn run() {
       query_equipment_by_station,//查询设备数据
       process_station_data,//处理场站数据
       generate_io_point_table,//生成IO点表
+      plan_rack_capacity,//机架容量规划
+      size_io_from_signals,//根据信号清单选型
+      get_address_plan,//读取项目地址规划
+      save_address_plan,//保存项目地址规划
+      export_opcua_nodeset,//导出OPC UA NodeSet2
+      export_dnp3_point_list,//导出DNP3点表
+      export_sparkplug_mapping,//导出Sparkplug B映射
+      import_io_point_table,//导入IO点表
+      validate_io_point_table,//校验IO点表
+      export_validation_annotated_workbook,//导出校验标注点表
+      export_validation_report,//导出校验结果
+      list_io_table_snapshots,//读取场站的点表快照版本
+      diff_io_point_tables,//对比两版IO点表
+      export_io_table_diff,//导出点表差异表
+      export_io_table_diff_report,//导出点表差异JSON
+      list_column_templates,//读取全部列模板
+      save_column_template,//保存列模板
+      import_column_template,//从文件导入列模板
+      delete_column_template,//删除列模板
+      get_project_column_template,//读取项目使用的列模板
+      select_project_column_template,//为项目选择列模板
       open_file//打开文件
     ])
     .setup(|app| {
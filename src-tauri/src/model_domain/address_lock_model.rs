@@ -0,0 +1,57 @@
+use crate::model_domain::io_table_model::IOTableRow;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 地址锁定
+/// 记录场站上次生成时每个点位的PLC地址，重新生成时沿用，避免已投用的地址发生变化
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddressLock {
+    /// 点位标识 → (地址列名 → PLC地址)
+    pub points: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl AddressLock {
+    /// 根据点表行记录地址锁定，只记录已分配的PLC地址
+    pub fn from_rows(rows: &[IOTableRow<'_>]) -> Self {
+        let mut points = BTreeMap::new();
+        for row in rows {
+            let Some(point_key) = &row.point_key else {
+                continue;
+            };
+
+            let addresses: BTreeMap<String, String> = row
+                .plc_address_fields()
+                .filter_map(|(field, value)| {
+                    value
+                        .filter(|value| *value != "/")
+                        .map(|value| (field.to_string(), value.to_string()))
+                })
+                .collect();
+            if !addresses.is_empty() {
+                points.insert(point_key.clone(), addresses);
+            }
+        }
+        Self { points }
+    }
+
+    /// 点位上次使用的地址
+    pub fn addresses_for(&self, point_key: &str) -> Option<&BTreeMap<String, String>> {
+        self.points.get(point_key)
+    }
+}
+
+/// 重新生成后地址发生变化的点位
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovedPoint {
+    /// 点位标识
+    pub point_key: String,
+    /// 通道位号
+    pub channel_tag: String,
+    /// 地址列名
+    pub field: String,
+    /// 上次的PLC地址
+    pub previous_address: String,
+    /// 本次的PLC地址
+    pub current_address: String,
+}
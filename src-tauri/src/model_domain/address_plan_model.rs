@@ -0,0 +1,277 @@
+use crate::model_domain::dnp3_profile_model::Dnp3Profile;
+use crate::model_domain::iec104_profile_model::Iec104Profile;
+use crate::model_domain::modbus_profile_model::{ModbusPoint, ModbusProfile};
+use serde::{Deserialize, Serialize};
+
+/// 布尔量每字节位数
+pub const BOOL_BITS_PER_BYTE: u32 = 8;
+/// Modbus寄存器字节数
+pub const MODBUS_REGISTER_BYTES: u32 = 2;
+/// 状态字位数
+pub const STATUS_WORD_BITS: u32 = 16;
+
+/// 地址区范围（字节，首尾均包含）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl AddressRange {
+    /// 判断两个范围是否重叠
+    pub fn overlaps(&self, other: &AddressRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+/// PLC地址规划
+/// 定义REAL/BOOL地址区的范围、步长、对齐方式以及Modbus基址，按项目保存
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddressPlan {
+    /// REAL地址区前缀，同时用于DINT、DWORD等4字节数据
+    pub real_area: String,
+    /// 2字节数据（INT、UINT、WORD）的地址前缀
+    pub word_area: String,
+    /// 8字节数据（LREAL）的地址前缀
+    pub long_area: String,
+    /// 字符串的地址前缀
+    ///
+    /// 各寄存器类前缀只表示访问宽度，与REAL地址区共用同一段字节偏移
+    pub string_area: String,
+    /// REAL地址起始字节
+    pub real_start: u32,
+    /// REAL地址结束字节（包含）
+    pub real_end: u32,
+    /// REAL类型每点位占用字节数
+    pub real_stride: u32,
+    /// REAL地址对齐字节数
+    pub real_alignment: u32,
+    /// BOOL地址区前缀
+    pub bool_area: String,
+    /// BOOL地址起始字节
+    pub bool_start_byte: u32,
+    /// BOOL地址起始位
+    pub bool_start_bit: u32,
+    /// BOOL地址结束字节（包含）
+    pub bool_end_byte: u32,
+    /// REAL主地址区用尽后使用的备用地址区
+    pub real_secondary: Option<AddressRange>,
+    /// BOOL主地址区用尽后使用的备用地址区
+    pub bool_secondary: Option<AddressRange>,
+    /// REAL地址区起点（例如%MD0）对应的寄存器偏移
+    pub real_register_offset: u32,
+    /// BOOL地址区起点（例如%MX0.0）对应的线圈偏移
+    pub bool_register_offset: u32,
+    /// Modbus映射配置
+    pub modbus: ModbusProfile,
+    /// IEC 104信息体地址配置
+    pub iec104: Iec104Profile,
+    /// DNP3点表配置
+    pub dnp3: Dnp3Profile,
+}
+
+impl Default for AddressPlan {
+    fn default() -> Self {
+        Self {
+            real_area: "%MD".to_string(),
+            word_area: "%MW".to_string(),
+            long_area: "%ML".to_string(),
+            string_area: "%MB".to_string(),
+            real_start: 320,
+            // 保证REAL通讯地址不超过49999
+            real_end: 13999,
+            real_stride: 4,
+            real_alignment: 4,
+            bool_area: "%MX".to_string(),
+            bool_start_byte: 20,
+            bool_start_bit: 0,
+            bool_end_byte: 300,
+            real_secondary: None,
+            bool_secondary: None,
+            // 默认映射下%MD0对应43001，%MX0.0对应3001
+            real_register_offset: 3000,
+            bool_register_offset: 3000,
+            modbus: ModbusProfile::default(),
+            iec104: Iec104Profile::default(),
+            dnp3: Dnp3Profile::default(),
+        }
+    }
+}
+
+impl AddressPlan {
+    /// 按对齐要求向上取整
+    pub fn align_real(&self, offset: u32) -> u32 {
+        let alignment = self.real_alignment.max(1);
+        offset.div_ceil(alignment) * alignment
+    }
+
+    /// 寄存器类数据的对齐字节数：不超过数据本身的字节数，也不超过REAL对齐要求
+    pub fn alignment_for(&self, byte_size: u32) -> u32 {
+        byte_size.min(self.real_alignment).max(1)
+    }
+
+    /// REAL主地址区范围
+    pub fn real_range(&self) -> AddressRange {
+        AddressRange { start: self.real_start, end: self.real_end }
+    }
+
+    /// BOOL主地址区范围（按字节）
+    pub fn bool_range(&self) -> AddressRange {
+        AddressRange { start: self.bool_start_byte, end: self.bool_end_byte }
+    }
+
+    /// 校验地址规划：Modbus功能区及启用的IEC 104、DNP3配置有效，范围首尾有效，且同一存储区内的各地址区互不重叠
+    pub fn validate(&self) -> Result<(), String> {
+        self.modbus.validate()?;
+        if self.iec104.enabled {
+            self.iec104.validate()?;
+        }
+        if self.dnp3.enabled {
+            self.dnp3.validate()?;
+        }
+        if self.bool_start_bit >= BOOL_BITS_PER_BYTE {
+            return Err(format!("BOOL起始位 {} 超出范围0-7", self.bool_start_bit));
+        }
+
+        let mut areas = vec![("REAL主地址区", self.real_area.as_str(), self.real_range())];
+        if let Some(range) = self.real_secondary {
+            areas.push(("REAL备用地址区", self.real_area.as_str(), range));
+        }
+        areas.push(("BOOL主地址区", self.bool_area.as_str(), self.bool_range()));
+        if let Some(range) = self.bool_secondary {
+            areas.push(("BOOL备用地址区", self.bool_area.as_str(), range));
+        }
+
+        // 寄存器类前缀与REAL地址区共用字节偏移，必须位于同一存储区
+        for (name, prefix) in [("字", &self.word_area), ("长字", &self.long_area), ("字符串", &self.string_area)] {
+            if memory_area(prefix) != memory_area(&self.real_area) {
+                return Err(format!("{}地址前缀 {} 与REAL地址区 {} 不在同一存储区", name, prefix, self.real_area));
+            }
+        }
+
+        for (name, _, range) in &areas {
+            if range.start > range.end {
+                return Err(format!("{}起始字节 {} 大于结束字节 {}", name, range.start, range.end));
+            }
+        }
+
+        for (i, (name, prefix, range)) in areas.iter().enumerate() {
+            for (other_name, other_prefix, other_range) in &areas[i + 1..] {
+                if memory_area(prefix) == memory_area(other_prefix) && range.overlaps(other_range) {
+                    return Err(format!(
+                        "{}({}-{}) 与 {}({}-{}) 重叠",
+                        name, range.start, range.end, other_name, other_range.start, other_range.end
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 寄存器类数据的地址前缀，按数据本身的字节数选择访问宽度
+    pub fn register_area(&self, width: u32) -> &str {
+        match width {
+            MODBUS_REGISTER_BYTES => &self.word_area,
+            4 => &self.real_area,
+            8 => &self.long_area,
+            _ => &self.string_area,
+        }
+    }
+
+    /// 格式化REAL地址，例如%MD320
+    pub fn format_real(&self, offset: u32) -> String {
+        self.format_register(offset, 4)
+    }
+
+    /// 格式化寄存器类地址，例如INT为%MW320、LREAL为%ML328
+    pub fn format_register(&self, offset: u32, width: u32) -> String {
+        format!("{}{}", self.register_area(width), offset)
+    }
+
+    /// 格式化BOOL地址，例如%MX20.0
+    pub fn format_bool(&self, (byte, bit): (u32, u32)) -> String {
+        format!("{}{}.{}", self.bool_area, byte, bit)
+    }
+
+    /// 解析REAL地址的字节偏移
+    pub fn parse_real(&self, plc_address: &str) -> Result<u32, String> {
+        self.parse_register(plc_address, 4)
+    }
+
+    /// 解析寄存器类地址的字节偏移，前缀须与数据宽度对应的地址前缀一致
+    pub fn parse_register(&self, plc_address: &str, width: u32) -> Result<u32, String> {
+        let area = self.register_area(width);
+        let number = plc_address
+            .strip_prefix(area)
+            .ok_or_else(|| format!("无效的PLC地址: {}，该类型数据应使用{}地址", plc_address, area))?;
+        number
+            .parse::<u32>()
+            .map_err(|e| format!("PLC地址解析错误: {}", e))
+    }
+
+    /// 解析BOOL地址的字节和位
+    pub fn parse_bool(&self, plc_address: &str) -> Result<(u32, u32), String> {
+        let number = plc_address
+            .strip_prefix(self.bool_area.as_str())
+            .ok_or_else(|| format!("无效的PLC BOOL地址: {}", plc_address))?;
+        let (byte, bit) = number
+            .split_once('.')
+            .ok_or_else(|| format!("BOOL地址格式错误: {}", plc_address))?;
+
+        let byte = byte
+            .parse::<u32>()
+            .map_err(|e| format!("BOOL地址字节部分解析错误: {}", e))?;
+        let bit = bit
+            .parse::<u32>()
+            .map_err(|e| format!("BOOL地址bit部分解析错误: {}", e))?;
+        if bit >= BOOL_BITS_PER_BYTE {
+            return Err(format!("BOOL地址位号超出范围: {}", plc_address));
+        }
+
+        Ok((byte, bit))
+    }
+
+    /// 寄存器类地址对应的Modbus映射，占用的寄存器数按字节数向上取整
+    pub fn register_modbus_point(&self, offset: u32, byte_size: u32, multi_word: bool) -> ModbusPoint {
+        self.modbus.register_point(
+            offset / MODBUS_REGISTER_BYTES + self.real_register_offset,
+            byte_size.div_ceil(MODBUS_REGISTER_BYTES),
+            multi_word,
+        )
+    }
+
+    /// BOOL地址对应的Modbus映射
+    pub fn bool_modbus_point(&self, (byte, bit): (u32, u32)) -> ModbusPoint {
+        self.modbus.bool_point(byte * BOOL_BITS_PER_BYTE + bit + self.bool_register_offset)
+    }
+
+    /// BOOL地址所在的状态字（状态字起始字节, 字内位号）
+    ///
+    /// 状态字按偶数字节对齐，低地址字节为字内低8位
+    pub fn status_word_position(&self, (byte, bit): (u32, u32)) -> (u32, u32) {
+        let word_byte = byte - byte % MODBUS_REGISTER_BYTES;
+        (word_byte, (byte - word_byte) * BOOL_BITS_PER_BYTE + bit)
+    }
+
+    /// 状态字对应的Modbus映射
+    ///
+    /// 状态字与REAL地址区位于同一存储区，按REAL点位的寄存器功能区和偏移编址
+    pub fn status_word_point(&self, word_byte: u32) -> ModbusPoint {
+        self.register_modbus_point(word_byte, MODBUS_REGISTER_BYTES, false)
+    }
+
+    /// 打包到状态字中的BOOL地址对应的通讯地址，格式为"寄存器.位"
+    pub fn packed_bool_comm_address(&self, bool_addr: (u32, u32)) -> String {
+        let (word_byte, bit) = self.status_word_position(bool_addr);
+        format!("{}.{}", self.status_word_point(word_byte).address, bit)
+    }
+}
+
+/// 地址前缀所在的存储区，去掉表示访问宽度的后缀，例如%MD和%MX都属于%M
+fn memory_area(prefix: &str) -> &str {
+    prefix
+        .strip_suffix(['X', 'B', 'W', 'D', 'L'])
+        .unwrap_or(prefix)
+}
@@ -0,0 +1,115 @@
+use crate::model_domain::io_table_model::IO_TABLE_HEADERS;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// 标准模板名称，按IO_TABLE_HEADERS输出全部标准列
+pub const STANDARD_TEMPLATE_NAME: &str = "标准";
+
+/// 列模板中的一列
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateColumn {
+    /// 字段：标准列名（IO_TABLE_HEADERS中的列）或自定义列名（例如工程单位、备注）
+    pub field: String,
+    /// 表头文字，为空时使用字段名
+    #[serde(default)]
+    pub header: String,
+}
+
+impl TemplateColumn {
+    /// 点表中显示的表头文字
+    pub fn header_text(&self) -> &str {
+        if self.header.trim().is_empty() { &self.field } else { &self.header }
+    }
+
+    /// 是否为自定义列
+    pub fn is_extra(&self) -> bool {
+        !IO_TABLE_HEADERS.contains(&self.field.as_str())
+    }
+}
+
+/// 点表列模板：点表中出现哪些列、使用的表头文字及列的顺序
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnTemplate {
+    pub name: String,
+    pub columns: Vec<TemplateColumn>,
+}
+
+impl Default for ColumnTemplate {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl ColumnTemplate {
+    /// 标准模板：按IO_TABLE_HEADERS顺序输出全部标准列
+    pub fn standard() -> Self {
+        Self {
+            name: STANDARD_TEMPLATE_NAME.to_string(),
+            columns: IO_TABLE_HEADERS
+                .iter()
+                .map(|header| TemplateColumn { field: header.to_string(), header: String::new() })
+                .collect(),
+        }
+    }
+
+    /// 字段所在的列号（从1开始），模板中没有该字段时返回None
+    pub fn column_number(&self, field: &str) -> Option<u32> {
+        self.columns.iter().position(|column| column.field == field).map(|idx| idx as u32 + 1)
+    }
+
+    /// 检查模板：至少包含一列，字段和表头文字都不能重复
+    pub fn check(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("列模板名称不能为空".to_string());
+        }
+        if self.columns.is_empty() {
+            return Err("列模板至少应包含一列".to_string());
+        }
+
+        let mut fields = HashSet::new();
+        let mut headers = HashSet::new();
+        for column in &self.columns {
+            if column.field.trim().is_empty() {
+                return Err("列模板中存在字段名为空的列".to_string());
+            }
+            if !fields.insert(column.field.as_str()) {
+                return Err(format!("列模板中的字段重复: {}", column.field));
+            }
+            if !headers.insert(column.header_text()) {
+                return Err(format!("列模板中的表头重复: {}", column.header_text()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(field: &str, header: &str) -> TemplateColumn {
+        TemplateColumn { field: field.to_string(), header: header.to_string() }
+    }
+
+    #[test]
+    fn test_template_columns_and_check() {
+        let template = ColumnTemplate {
+            name: "客户A".to_string(),
+            columns: vec![column("位号", "Tag"), column("工程单位", ""), column("变量描述", "")],
+        };
+        assert!(template.check().is_ok());
+        assert_eq!(template.column_number("工程单位"), Some(2));
+        assert_eq!(template.column_number("通道位号"), None);
+        assert_eq!(template.columns[0].header_text(), "Tag");
+        assert!(template.columns[1].is_extra());
+        assert!(!template.columns[2].is_extra());
+
+        let duplicated = ColumnTemplate {
+            name: "重复".to_string(),
+            columns: vec![column("位号", "备注"), column("备注", "")],
+        };
+        assert_eq!(duplicated.check(), Err("列模板中的表头重复: 备注".to_string()));
+
+        assert_eq!(ColumnTemplate::standard().columns.len(), IO_TABLE_HEADERS.len());
+    }
+}
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// DNP3点类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dnp3PointType {
+    /// 二进制输入（组1/组2）
+    BinaryInput,
+    /// 模拟量输入（组30/组32）
+    AnalogInput,
+    /// 二进制输出（组10/组12）
+    BinaryOutput,
+    /// 模拟量输出（组40/组41）
+    AnalogOutput,
+}
+
+impl Dnp3PointType {
+    /// 全部点类型，按导出顺序排列
+    pub const ALL: [Dnp3PointType; 4] = [
+        Dnp3PointType::BinaryInput,
+        Dnp3PointType::AnalogInput,
+        Dnp3PointType::BinaryOutput,
+        Dnp3PointType::AnalogOutput,
+    ];
+
+    /// 静态数据的对象组号
+    pub fn static_group(&self) -> u8 {
+        match self {
+            Dnp3PointType::BinaryInput => 1,
+            Dnp3PointType::AnalogInput => 30,
+            Dnp3PointType::BinaryOutput => 10,
+            Dnp3PointType::AnalogOutput => 40,
+        }
+    }
+
+    /// 组态文件中使用的简写
+    pub fn code(&self) -> &'static str {
+        match self {
+            Dnp3PointType::BinaryInput => "BI",
+            Dnp3PointType::AnalogInput => "AI",
+            Dnp3PointType::BinaryOutput => "BO",
+            Dnp3PointType::AnalogOutput => "AO",
+        }
+    }
+}
+
+impl fmt::Display for Dnp3PointType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dnp3PointType::BinaryInput => write!(f, "二进制输入"),
+            Dnp3PointType::AnalogInput => write!(f, "模拟量输入"),
+            Dnp3PointType::BinaryOutput => write!(f, "二进制输出"),
+            Dnp3PointType::AnalogOutput => write!(f, "模拟量输出"),
+        }
+    }
+}
+
+/// DNP3事件等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventClass {
+    /// 只上送静态数据，不产生事件
+    Class0,
+    Class1,
+    Class2,
+    Class3,
+}
+
+impl fmt::Display for EventClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventClass::Class0 => write!(f, "0"),
+            EventClass::Class1 => write!(f, "1"),
+            EventClass::Class2 => write!(f, "2"),
+            EventClass::Class3 => write!(f, "3"),
+        }
+    }
+}
+
+/// 单个点位的事件等级和死区覆盖设置
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Dnp3PointOverride {
+    pub event_class: Option<EventClass>,
+    pub deadband: Option<f64>,
+}
+
+/// DNP3点表配置
+/// 各点类型的事件等级和模拟量输入死区，按项目保存
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Dnp3Profile {
+    /// 是否生成DNP3点表
+    pub enabled: bool,
+    pub binary_input_class: EventClass,
+    pub analog_input_class: EventClass,
+    pub binary_output_class: EventClass,
+    pub analog_output_class: EventClass,
+    /// 模拟量输入死区占量程的百分比
+    pub deadband_percent: f64,
+    /// 量程未填写时使用的死区
+    pub default_deadband: f64,
+    /// 按变量名称或通道位号覆盖单个点位的设置，附加点位使用"变量名称.点位"，例如PT101.H报警
+    pub overrides: BTreeMap<String, Dnp3PointOverride>,
+}
+
+impl Default for Dnp3Profile {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            binary_input_class: EventClass::Class1,
+            analog_input_class: EventClass::Class2,
+            binary_output_class: EventClass::Class0,
+            analog_output_class: EventClass::Class0,
+            deadband_percent: 1.0,
+            default_deadband: 0.0,
+            overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl Dnp3Profile {
+    /// 点类型默认的事件等级
+    pub fn class_for(&self, point_type: Dnp3PointType) -> EventClass {
+        match point_type {
+            Dnp3PointType::BinaryInput => self.binary_input_class,
+            Dnp3PointType::AnalogInput => self.analog_input_class,
+            Dnp3PointType::BinaryOutput => self.binary_output_class,
+            Dnp3PointType::AnalogOutput => self.analog_output_class,
+        }
+    }
+
+    /// 模拟量输入的死区：量程已知时按百分比计算，否则使用默认死区
+    pub fn deadband_for(&self, range: Option<(f64, f64)>) -> f64 {
+        match range {
+            Some((low, high)) => (high - low).abs() * self.deadband_percent / 100.0,
+            None => self.default_deadband,
+        }
+    }
+
+    /// 校验死区设置
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.deadband_percent >= 0.0 && self.deadband_percent <= 100.0) {
+            return Err(format!("DNP3死区百分比 {} 超出范围0-100", self.deadband_percent));
+        }
+        if self.default_deadband < 0.0 {
+            return Err(format!("DNP3默认死区 {} 不能为负数", self.default_deadband));
+        }
+        for (key, point_override) in &self.overrides {
+            if point_override.deadband.is_some_and(|deadband| deadband < 0.0) {
+                return Err(format!("DNP3点位 {} 的死区不能为负数", key));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 单个DNP3点位的分配结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dnp3Point {
+    pub point_type: Dnp3PointType,
+    /// 同类型点位内的索引（从0开始）
+    pub index: u16,
+    pub event_class: EventClass,
+    /// 死区，仅模拟量输入
+    pub deadband: Option<f64>,
+    pub channel_tag: String,
+    pub variable_name: String,
+    pub description: String,
+    /// 点位名称，主点位为"主点位"，附加点位为例如"LL报警"
+    pub point_name: String,
+    pub plc_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadband_and_validation() {
+        let profile = Dnp3Profile::default();
+        assert_eq!(profile.deadband_for(Some((0.0, 200.0))), 2.0);
+        assert_eq!(profile.deadband_for(None), 0.0);
+        assert!(profile.validate().is_ok());
+
+        let invalid = Dnp3Profile { deadband_percent: 120.0, ..Default::default() };
+        assert!(invalid.validate().is_err());
+    }
+}
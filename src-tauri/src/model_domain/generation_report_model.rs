@@ -0,0 +1,65 @@
+use crate::model_domain::address_lock_model::{AddressLock, MovedPoint};
+use crate::model_domain::io_table_model::{IOTable, IOTableRow};
+use crate::model_domain::revision_model::RevisionEntry;
+use serde::{Deserialize, Serialize};
+
+/// 单个地址区的使用情况
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AreaUtilization {
+    /// 地址区名称，例如REAL主地址区
+    pub area: String,
+    /// 地址范围，例如%MD320-%MD13999
+    pub range: String,
+    /// 已占用的单元数（REAL地址区为字节，BOOL地址区为位）
+    pub used: u32,
+    /// 可分配的单元数
+    pub capacity: u32,
+    /// 利用率（百分比）
+    pub utilization: f64,
+}
+
+/// 点表生成报告
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenerationReport {
+    /// 各地址区的使用情况
+    pub area_utilization: Vec<AreaUtilization>,
+    /// 与上次锁定地址相比发生变化的点位
+    pub moved_points: Vec<MovedPoint>,
+    /// 本次生成的地址分配，保存后作为下次生成的锁定地址（不返回前端）
+    #[serde(skip)]
+    pub address_lock: AddressLock,
+    /// 合并上次点表的结果，未使用合并模式时为空
+    pub merge: Option<MergeSummary>,
+    /// 本次修订记录，未填写文档信息时为空
+    pub revision: Option<RevisionEntry>,
+    /// 本次导出的点表，保存后作为版本对比的基准（不返回前端）
+    #[serde(skip)]
+    pub io_table: Option<IOTable<'static>>,
+}
+
+/// 合并上次点表的结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeSummary {
+    /// 上次点表的文件名
+    pub previous_table: String,
+    /// 沿用了上次填写内容的通道数
+    pub kept_channels: usize,
+    /// 新增通道的通道位号
+    pub new_channels: Vec<String>,
+    /// 已移除通道的通道位号
+    pub removed_channels: Vec<String>,
+    /// 通道位号相同但模块已更换的通道，按已移除和新增处理，不沿用上次填写的内容
+    pub replaced_channels: Vec<String>,
+    /// 已移除通道在上次点表中的行，写入"已移除通道"工作表（不返回前端）
+    #[serde(skip)]
+    pub removed_rows: Vec<IOTableRow<'static>>,
+}
+
+/// 点表生成结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationResult {
+    /// 保存的点表文件路径
+    pub file_path: String,
+    /// 生成报告
+    pub report: GenerationReport,
+}
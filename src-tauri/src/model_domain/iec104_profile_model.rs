@@ -0,0 +1,126 @@
+use crate::model_domain::address_plan_model::AddressRange;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// IEC 60870-5-104信息对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Iec104TypeId {
+    /// 单点信息（M_SP），用于开关量状态和报警
+    MSp,
+    /// 测量值（M_ME），用于模拟量和数值
+    MMe,
+    /// 设定值命令（C_SE），用于模拟量输出和报警设定值
+    CSe,
+}
+
+impl fmt::Display for Iec104TypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Iec104TypeId::MSp => write!(f, "M_SP"),
+            Iec104TypeId::MMe => write!(f, "M_ME"),
+            Iec104TypeId::CSe => write!(f, "C_SE"),
+        }
+    }
+}
+
+/// IEC 104信息体地址配置
+/// 各类型信息对象的IOA范围，按项目保存
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Iec104Profile {
+    /// 是否生成IEC 104信息体地址表
+    pub enabled: bool,
+    /// 单点信息（M_SP）的IOA范围
+    pub single_point: AddressRange,
+    /// 测量值（M_ME）的IOA范围
+    pub measured_value: AddressRange,
+    /// 设定值命令（C_SE）的IOA范围
+    pub setpoint_command: AddressRange,
+}
+
+impl Default for Iec104Profile {
+    fn default() -> Self {
+        // 按常见调度约定：遥信从0x0001开始，遥测从0x4001开始，遥调从0x6201开始
+        Self {
+            enabled: false,
+            single_point: AddressRange { start: 0x0001, end: 0x4000 },
+            measured_value: AddressRange { start: 0x4001, end: 0x6200 },
+            setpoint_command: AddressRange { start: 0x6201, end: 0x6600 },
+        }
+    }
+}
+
+impl Iec104Profile {
+    /// IOA最大值（3字节）
+    pub const MAX_IOA: u32 = 0xFF_FFFF;
+
+    /// 各类型的IOA范围
+    pub fn ranges(&self) -> [(Iec104TypeId, AddressRange); 3] {
+        [
+            (Iec104TypeId::MSp, self.single_point),
+            (Iec104TypeId::MMe, self.measured_value),
+            (Iec104TypeId::CSe, self.setpoint_command),
+        ]
+    }
+
+    /// 校验IOA范围：首尾有效、不超过3字节且互不重叠
+    pub fn validate(&self) -> Result<(), String> {
+        let ranges = self.ranges();
+        for (type_id, range) in &ranges {
+            if range.start == 0 || range.start > range.end || range.end > Self::MAX_IOA {
+                return Err(format!("{} IOA范围 {}-{} 无效", type_id, range.start, range.end));
+            }
+        }
+
+        for (i, (type_id, range)) in ranges.iter().enumerate() {
+            for (other_type, other_range) in &ranges[i + 1..] {
+                if range.overlaps(other_range) {
+                    return Err(format!(
+                        "{} IOA范围({}-{}) 与 {} IOA范围({}-{}) 重叠",
+                        type_id, range.start, range.end, other_type, other_range.start, other_range.end
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 单个信息对象的IOA分配结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Iec104Point {
+    /// 信息体地址
+    pub ioa: u32,
+    /// 信息对象类型
+    pub type_id: Iec104TypeId,
+    pub channel_tag: String,
+    pub variable_name: String,
+    pub description: String,
+    /// 点位名称，主点位为"主点位"，附加点位为例如"LL报警"
+    pub point_name: String,
+    pub plc_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_ranges() {
+        assert!(Iec104Profile::default().validate().is_ok());
+
+        let overlapping = Iec104Profile {
+            measured_value: AddressRange { start: 100, end: 0x6200 },
+            ..Default::default()
+        };
+        assert!(overlapping.validate().is_err());
+
+        let zero_start = Iec104Profile {
+            single_point: AddressRange { start: 0, end: 10 },
+            ..Default::default()
+        };
+        assert!(zero_start.validate().is_err());
+    }
+}
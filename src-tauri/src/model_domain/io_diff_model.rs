@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 对比两版点表时匹配行的依据
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffMatchKey {
+    /// 先按通道位号匹配，剩余的行再按位号匹配（通道变化的点位视为移动）
+    #[default]
+    ChannelTag,
+    /// 先按位号匹配，剩余的行再按通道位号匹配
+    Tag,
+}
+
+/// 行的对比结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStatus {
+    Added,
+    Removed,
+    /// 点位换到了其他通道
+    Moved,
+    Changed,
+    Unchanged,
+}
+
+impl fmt::Display for DiffStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DiffStatus::Added => "新增",
+            DiffStatus::Removed => "删除",
+            DiffStatus::Moved => "移动",
+            DiffStatus::Changed => "修改",
+            DiffStatus::Unchanged => "未变",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 发生变化的字段
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub column: String,
+    pub old_value: String,
+    pub new_value: String,
+    /// 是否为地址列（PLC地址、通讯地址及Modbus映射）
+    pub is_address: bool,
+}
+
+/// 单行的对比结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RowDiff {
+    pub status: DiffStatus,
+    /// 旧点表中的Excel行号
+    pub old_row: Option<u32>,
+    /// 新点表中的Excel行号
+    pub new_row: Option<u32>,
+    pub old_channel_tag: String,
+    pub new_channel_tag: String,
+    /// 位号（新点表中没有时取旧点表）
+    pub tag: String,
+    pub changes: Vec<FieldChange>,
+    /// 旧点表中的行序号（不返回前端）
+    #[serde(skip)]
+    pub old_index: Option<usize>,
+    /// 新点表中的行序号（不返回前端）
+    #[serde(skip)]
+    pub new_index: Option<usize>,
+}
+
+/// 对比汇总
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub moved: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+    /// 地址发生变化的点位数
+    pub address_changed: usize,
+}
+
+/// 两版点表的对比结果，只列出有变化的行
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IoTableDiff {
+    pub old_table: String,
+    pub new_table: String,
+    pub match_by: DiffMatchKey,
+    pub summary: DiffSummary,
+    pub rows: Vec<RowDiff>,
+}
+
+/// 参与对比的点表来源
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DiffSource {
+    /// 点表文件，未指定路径时提示用户选择；按指定的列模板读取，未指定时使用标准模板
+    File {
+        path: Option<String>,
+        #[serde(default)]
+        template_name: Option<String>,
+    },
+    /// 场站生成时保存的点表快照，未指定版本时使用最新的快照
+    Snapshot {
+        station_name: String,
+        #[serde(default)]
+        version: Option<u32>,
+    },
+}
+
+/// 保存的点表快照版本
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IoTableSnapshotInfo {
+    /// 版本号，按保存顺序从1开始
+    pub version: u32,
+    /// 保存时记录的修订号，未生成封面时为None
+    pub revision: Option<String>,
+    /// 保存时间（Unix时间戳，秒）
+    pub saved_at: u64,
+}
@@ -0,0 +1,66 @@
+use crate::model_domain::io_table_model::{IOTable, IO_TABLE_HEADERS};
+use serde::{Deserialize, Serialize};
+
+/// 导入点表时无法解析的单元格
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportIssue {
+    /// Excel行号（从1开始）
+    pub row: u32,
+    /// 列名
+    pub column: String,
+    /// 单元格位置，例如Q5
+    pub cell: String,
+    /// 单元格内容，公式单元格为公式文本
+    pub value: String,
+    pub message: String,
+}
+
+/// 点表导入结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IoImportResult {
+    /// 导入的点表，无法解析的单元格保留原文
+    pub table: IOTable<'static>,
+    /// 读取的工作表名称
+    pub sheet_name: String,
+    /// 无法解析的单元格
+    pub issues: Vec<ImportIssue>,
+    /// 工作表中缺少的列模板中的列
+    pub missing_columns: Vec<String>,
+    /// 工作表中不属于列模板、已忽略的列
+    pub ignored_columns: Vec<String>,
+    /// 点表行和列在工作表中的位置（不返回前端）
+    #[serde(skip)]
+    pub layout: SheetLayout,
+}
+
+/// 点表在工作表中的位置，行列均从1开始
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SheetLayout {
+    /// 表头所在行
+    pub header_row: u32,
+    /// 点表各行所在的Excel行号
+    pub row_numbers: Vec<u32>,
+    /// 各字段（标准列及自定义列）所在的列号
+    pub columns: Vec<(u32, String)>,
+}
+
+impl SheetLayout {
+    /// 按IO_TABLE_HEADERS顺序从第1行表头开始逐行写入的点表
+    pub fn standard(row_count: usize) -> Self {
+        Self {
+            header_row: 1,
+            row_numbers: (0..row_count as u32).map(|idx| idx + 2).collect(),
+            columns: IO_TABLE_HEADERS.iter().enumerate().map(|(idx, header)| (idx as u32 + 1, header.to_string())).collect(),
+        }
+    }
+
+    /// 第row_index行（从0开始）的Excel行号
+    pub fn row_number(&self, row_index: usize) -> u32 {
+        self.row_numbers.get(row_index).copied().unwrap_or(self.header_row + row_index as u32 + 1)
+    }
+
+    /// 字段所在的列号，工作表中缺少该列时返回None
+    pub fn column_number(&self, header: &str) -> Option<u32> {
+        self.columns.iter().find(|(_, h)| *h == header).map(|(col, _)| *col)
+    }
+}
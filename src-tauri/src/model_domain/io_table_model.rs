@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 
 /// IO点表数据模型
 /// 用于表示IO点表Excel的每一行数据
@@ -111,6 +112,35 @@ pub struct IOTableRow<'a> {
     pub plc_absolute_address: Option<Cow<'a, str>>,
     /// 上位机通讯地址
     pub host_comm_address: Option<Cow<'a, str>>,
+    /// Modbus功能区（主点位，附加点位见Modbus映射表）
+    pub modbus_register_area: Option<Cow<'a, str>>,
+    /// Modbus协议偏移
+    pub modbus_offset: Option<Cow<'a, str>>,
+    /// Modbus寄存器数
+    pub modbus_span: Option<Cow<'a, str>>,
+    /// 字序
+    pub modbus_word_order: Option<Cow<'a, str>>,
+    /// 是否位于待订购的虚拟机架（不对应Excel列）
+    #[serde(default)]
+    pub is_virtual_rack: bool,
+    /// 冗余伙伴模块的槽位（格式：机架_槽位，不对应Excel列）
+    #[serde(default)]
+    pub redundant_partner: Option<String>,
+    /// 是否为按备用策略预留的备用通道（不对应Excel列）
+    #[serde(default)]
+    pub is_spare: bool,
+    /// 点位标识（设备名称#规格型号#序号#通道），用于地址锁定和合并，保存在主点表末尾的隐藏列中
+    #[serde(default)]
+    pub point_key: Option<String>,
+    /// 合并模式下上次点表中没有的新增通道（不对应Excel列）
+    #[serde(default)]
+    pub is_new_channel: bool,
+    /// 合并模式下本次设备清单中已不存在的通道（不对应Excel列）
+    #[serde(default)]
+    pub is_removed_channel: bool,
+    /// 列模板中自定义列的内容，键为字段名
+    #[serde(default)]
+    pub extra_fields: BTreeMap<String, Cow<'a, str>>,
 }
 
 impl<'a> IOTableRow<'a> {
@@ -128,7 +158,7 @@ impl<'a> IOTableRow<'a> {
         result
     }
     
-    /// 通过字段名称获取对应的值
+    /// 通过字段名称获取对应的值，非标准列取自定义列的内容
     pub fn get_field_by_name(&self, field_name: &str) -> Option<&Cow<'a, str>> {
         match field_name {
             "序号" => self.index.as_ref(),
@@ -184,9 +214,113 @@ impl<'a> IOTableRow<'a> {
             "维护使能开关点位_通讯地址" => self.maintenance_enable_switch_comm_address.as_ref(),
             "PLC绝对地址" => self.plc_absolute_address.as_ref(),
             "上位机通讯地址" => self.host_comm_address.as_ref(),
+            "Modbus功能区" => self.modbus_register_area.as_ref(),
+            "Modbus偏移" => self.modbus_offset.as_ref(),
+            "Modbus寄存器数" => self.modbus_span.as_ref(),
+            "字序" => self.modbus_word_order.as_ref(),
+            _ => self.extra_fields.get(field_name),
+        }
+    }
+
+    /// 按表头顺序遍历全部PLC地址列及其值
+    pub fn plc_address_fields(&self) -> impl Iterator<Item = (&'static str, Option<&str>)> + '_ {
+        PLC_ADDRESS_FIELDS
+            .iter()
+            .map(|field| (*field, self.get_field_by_name(field).map(|value| value.as_ref())))
+    }
+
+    /// 点位的显示名称：优先使用变量名称，其次为通道位号（软点位为位号）
+    pub fn display_name(&self) -> Option<&str> {
+        fn non_empty(value: Option<&str>) -> Option<&str> {
+            value.map(str::trim).filter(|value| !value.is_empty() && *value != "/")
+        }
+        non_empty(self.variable_name_hmi.as_deref())
+            .or_else(|| non_empty(self.channel_tag.as_deref()))
+            .or_else(|| non_empty(self.tag.as_deref()))
+    }
+
+    /// 从通道位号（机架_槽位_类型_通道）解析机架和槽位，软点位返回None
+    pub fn rack_and_slot(&self) -> Option<(u32, u32)> {
+        let mut parts = self.channel_tag.as_deref()?.split('_');
+        let rack = parts.next()?.parse().ok()?;
+        let slot = parts.next()?.parse().ok()?;
+        Some((rack, slot))
+    }
+
+    /// 通过字段名称获取对应字段的可变引用
+    pub fn get_field_mut_by_name(&mut self, field_name: &str) -> Option<&mut Option<Cow<'a, str>>> {
+        match field_name {
+            "序号" => Some(&mut self.index),
+            "模块名称" => Some(&mut self.module_name),
+            "模块类型" => Some(&mut self.module_type),
+            "供电类型（有源/无源）" => Some(&mut self.power_supply_type),
+            "线制" => Some(&mut self.wire_system),
+            "通道位号" => Some(&mut self.channel_tag),
+            "位号" => Some(&mut self.tag),
+            "场站名" => Some(&mut self.station_name),
+            "变量名称（HMI）" => Some(&mut self.variable_name_hmi),
+            "变量描述" => Some(&mut self.variable_description),
+            "数据类型" => Some(&mut self.data_type),
+            "读写属性" => Some(&mut self.read_write_property),
+            "保存历史" => Some(&mut self.save_history),
+            "掉电保护" => Some(&mut self.power_off_protection),
+            "量程低限" => Some(&mut self.range_lower_limit),
+            "量程高限" => Some(&mut self.range_upper_limit),
+            "SLL设定值" => Some(&mut self.sll_value),
+            "SLL设定点位" => Some(&mut self.sll_setpoint),
+            "SLL设定点位_PLC地址" => Some(&mut self.sll_setpoint_plc_address),
+            "SLL设定点位_通讯地址" => Some(&mut self.sll_setpoint_comm_address),
+            "SL设定值" => Some(&mut self.sl_value),
+            "SL设定点位" => Some(&mut self.sl_setpoint),
+            "SL设定点位_PLC地址" => Some(&mut self.sl_setpoint_plc_address),
+            "SL设定点位_通讯地址" => Some(&mut self.sl_setpoint_comm_address),
+            "SH设定值" => Some(&mut self.sh_value),
+            "SH设定点位" => Some(&mut self.sh_setpoint),
+            "SH设定点位_PLC地址" => Some(&mut self.sh_setpoint_plc_address),
+            "SH设定点位_通讯地址" => Some(&mut self.sh_setpoint_comm_address),
+            "SHH设定值" => Some(&mut self.shh_value),
+            "SHH设定点位" => Some(&mut self.shh_setpoint),
+            "SHH设定点位_PLC地址" => Some(&mut self.shh_setpoint_plc_address),
+            "SHH设定点位_通讯地址" => Some(&mut self.shh_setpoint_comm_address),
+            "LL报警" => Some(&mut self.ll_alarm),
+            "LL报警_PLC地址" => Some(&mut self.ll_alarm_plc_address),
+            "LL报警_通讯地址" => Some(&mut self.ll_alarm_comm_address),
+            "L报警" => Some(&mut self.l_alarm),
+            "L报警_PLC地址" => Some(&mut self.l_alarm_plc_address),
+            "L报警_通讯地址" => Some(&mut self.l_alarm_comm_address),
+            "H报警" => Some(&mut self.h_alarm),
+            "H报警_PLC地址" => Some(&mut self.h_alarm_plc_address),
+            "H报警_通讯地址" => Some(&mut self.h_alarm_comm_address),
+            "HH报警" => Some(&mut self.hh_alarm),
+            "HH报警_PLC地址" => Some(&mut self.hh_alarm_plc_address),
+            "HH报警_通讯地址" => Some(&mut self.hh_alarm_comm_address),
+            "维护值设定" => Some(&mut self.maintenance_value),
+            "维护值设定点位" => Some(&mut self.maintenance_setpoint),
+            "维护值设定点位_PLC地址" => Some(&mut self.maintenance_setpoint_plc_address),
+            "维护值设定点位_通讯地址" => Some(&mut self.maintenance_setpoint_comm_address),
+            "维护使能开关点位" => Some(&mut self.maintenance_enable_switch),
+            "维护使能开关点位_PLC地址" => Some(&mut self.maintenance_enable_switch_plc_address),
+            "维护使能开关点位_通讯地址" => Some(&mut self.maintenance_enable_switch_comm_address),
+            "PLC绝对地址" => Some(&mut self.plc_absolute_address),
+            "上位机通讯地址" => Some(&mut self.host_comm_address),
+            "Modbus功能区" => Some(&mut self.modbus_register_area),
+            "Modbus偏移" => Some(&mut self.modbus_offset),
+            "Modbus寄存器数" => Some(&mut self.modbus_span),
+            "字序" => Some(&mut self.modbus_word_order),
             _ => None,
         }
     }
+
+    /// 通过字段名称设置对应的值，字段不存在时返回false
+    pub fn set_field_by_name(&mut self, field_name: &str, value: Cow<'a, str>) -> bool {
+        match self.get_field_mut_by_name(field_name) {
+            Some(field) => {
+                *field = Some(value);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl<'a> Default for IOTableRow<'a> {
@@ -245,6 +379,17 @@ impl<'a> Default for IOTableRow<'a> {
             maintenance_enable_switch_comm_address: None,
             plc_absolute_address: None,
             host_comm_address: None,
+            modbus_register_area: None,
+            modbus_offset: None,
+            modbus_span: None,
+            modbus_word_order: None,
+            is_virtual_rack: false,
+            redundant_partner: None,
+            is_spare: false,
+            point_key: None,
+            is_new_channel: false,
+            is_removed_channel: false,
+            extra_fields: BTreeMap::new(),
         }
     }
 }
@@ -285,7 +430,7 @@ impl<'a> IOTable<'a> {
 }
 
 /// 表头常量，用于Excel导入导出
-pub const IO_TABLE_HEADERS: [&str; 53] = [
+pub const IO_TABLE_HEADERS: [&str; 57] = [
     "序号", "模块名称", "模块类型", "供电类型（有源/无源）", "线制", "通道位号", "位号", "场站名", 
     "变量名称（HMI）", "变量描述", "数据类型", "读写属性", "保存历史", "掉电保护", 
     "量程低限", "量程高限", "SLL设定值", "SLL设定点位", "SLL设定点位_PLC地址", "SLL设定点位_通讯地址",
@@ -298,5 +443,40 @@ pub const IO_TABLE_HEADERS: [&str; 53] = [
     "HH报警", "HH报警_PLC地址", "HH报警_通讯地址",
     "维护值设定", "维护值设定点位", "维护值设定点位_PLC地址", "维护值设定点位_通讯地址", 
     "维护使能开关点位", "维护使能开关点位_PLC地址", "维护使能开关点位_通讯地址",
-    "PLC绝对地址", "上位机通讯地址"
+    "PLC绝对地址", "上位机通讯地址", "Modbus功能区", "Modbus偏移", "Modbus寄存器数", "字序"
+];
+
+/// 主PLC地址列
+pub const MAIN_PLC_ADDRESS_FIELD: &str = "PLC绝对地址";
+
+/// 点位标识列，写在列模板各列之后并隐藏，读回点表时用于按模块匹配
+pub const POINT_KEY_HEADER: &str = "点位标识";
+
+/// 全部PLC地址列，按表头顺序排列；除主地址外均为模拟量的附加点位地址
+pub const PLC_ADDRESS_FIELDS: [&str; 11] = [
+    "SLL设定点位_PLC地址", "SL设定点位_PLC地址", "SH设定点位_PLC地址", "SHH设定点位_PLC地址",
+    "LL报警_PLC地址", "L报警_PLC地址", "H报警_PLC地址", "HH报警_PLC地址",
+    "维护值设定点位_PLC地址", "维护使能开关点位_PLC地址",
+    MAIN_PLC_ADDRESS_FIELD
 ];
+
+/// 主点位在各协议点表中的点位名称
+pub const MAIN_POINT_NAME: &str = "主点位";
+
+/// PLC地址列对应的点位名称，附加点位为例如"LL报警"
+pub fn point_name(plc_field: &str) -> &str {
+    if plc_field == MAIN_PLC_ADDRESS_FIELD {
+        MAIN_POINT_NAME
+    } else {
+        plc_field.trim_end_matches("_PLC地址")
+    }
+}
+
+/// PLC地址列对应的通讯地址列
+pub fn comm_address_field(plc_field: &str) -> String {
+    if plc_field == MAIN_PLC_ADDRESS_FIELD {
+        "上位机通讯地址".to_string()
+    } else {
+        plc_field.replace("_PLC地址", "_通讯地址")
+    }
+}
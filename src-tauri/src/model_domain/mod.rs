@@ -1,3 +1,16 @@
 
 pub mod query_model;
-pub mod io_table_model;
\ No newline at end of file
+pub mod io_table_model;
+pub mod rack_plan_model;
+pub mod address_plan_model;
+pub mod generation_report_model;
+pub mod address_lock_model;
+pub mod modbus_profile_model;
+pub mod iec104_profile_model;
+pub mod dnp3_profile_model;
+pub mod sparkplug_model;
+pub mod io_import_model;
+pub mod validation_model;
+pub mod io_diff_model;
+pub mod revision_model;
+pub mod column_template_model;
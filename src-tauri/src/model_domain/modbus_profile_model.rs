@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 5位编号（例如4xxxx）可表示的最大寄存器号
+const SHORT_REGISTER_LIMIT: u32 = 9999;
+
+/// Modbus功能区
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisterArea {
+    /// 线圈（0xxxx）
+    Coil,
+    /// 离散输入（1xxxx）
+    DiscreteInput,
+    /// 输入寄存器（3xxxx）
+    InputRegister,
+    /// 保持寄存器（4xxxx）
+    HoldingRegister,
+}
+
+impl RegisterArea {
+    /// 传统编号中的功能区前缀数字
+    pub fn prefix_digit(&self) -> u32 {
+        match self {
+            RegisterArea::Coil => 0,
+            RegisterArea::DiscreteInput => 1,
+            RegisterArea::InputRegister => 3,
+            RegisterArea::HoldingRegister => 4,
+        }
+    }
+
+    /// 是否为按位访问的功能区
+    pub fn is_bit_area(&self) -> bool {
+        matches!(self, RegisterArea::Coil | RegisterArea::DiscreteInput)
+    }
+}
+
+impl fmt::Display for RegisterArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegisterArea::Coil => write!(f, "线圈"),
+            RegisterArea::DiscreteInput => write!(f, "离散输入"),
+            RegisterArea::InputRegister => write!(f, "输入寄存器"),
+            RegisterArea::HoldingRegister => write!(f, "保持寄存器"),
+        }
+    }
+}
+
+/// 32位数据的字序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WordOrder {
+    /// 高字在前
+    #[serde(rename = "ABCD")]
+    Abcd,
+    /// 低字在前
+    #[serde(rename = "CDAB")]
+    Cdab,
+}
+
+impl fmt::Display for WordOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordOrder::Abcd => write!(f, "ABCD"),
+            WordOrder::Cdab => write!(f, "CDAB"),
+        }
+    }
+}
+
+/// Modbus映射配置
+/// 决定点位映射到的功能区、编号方式以及浮点数字序，不同上位机软件的约定可能不同
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModbusProfile {
+    /// 寄存器类点位（REAL及整数、字、字符串等）映射的功能区
+    pub register_area: RegisterArea,
+    /// BOOL点位映射的功能区
+    pub bool_area: RegisterArea,
+    /// 通讯地址是否从1开始编号（否则与协议偏移相同，从0开始）
+    pub one_based: bool,
+    /// 通讯地址是否带功能区前缀（例如保持寄存器写作4xxxx）
+    pub prefixed: bool,
+    /// 32位及64位数值的字序
+    pub word_order: WordOrder,
+    /// 是否将每个点位的报警位打包到一个16位状态字中，上位机按"寄存器.位"读取
+    pub pack_alarm_bits: bool,
+}
+
+impl Default for ModbusProfile {
+    fn default() -> Self {
+        Self {
+            register_area: RegisterArea::HoldingRegister,
+            bool_area: RegisterArea::Coil,
+            one_based: true,
+            prefixed: true,
+            word_order: WordOrder::Abcd,
+            pack_alarm_bits: false,
+        }
+    }
+}
+
+impl ModbusProfile {
+    /// 校验功能区：寄存器类点位只能映射到寄存器，BOOL只能映射到线圈或离散输入
+    pub fn validate(&self) -> Result<(), String> {
+        if self.register_area.is_bit_area() {
+            return Err(format!("寄存器类点位不能映射到{}", self.register_area));
+        }
+        if !self.bool_area.is_bit_area() {
+            return Err(format!("BOOL点位不能映射到{}", self.bool_area));
+        }
+        Ok(())
+    }
+
+    /// 按协议偏移生成寄存器类点位的映射
+    ///
+    /// `span` 为占用的寄存器数，`multi_word` 表示需要按字序读取的32位/64位数值
+    pub fn register_point(&self, offset: u32, span: u32, multi_word: bool) -> ModbusPoint {
+        ModbusPoint {
+            area: self.register_area,
+            offset,
+            span,
+            address: self.display_address(self.register_area, offset),
+            word_order: multi_word.then_some(self.word_order),
+        }
+    }
+
+    /// 按协议偏移生成BOOL点位的映射
+    pub fn bool_point(&self, offset: u32) -> ModbusPoint {
+        ModbusPoint {
+            area: self.bool_area,
+            offset,
+            span: 1,
+            address: self.display_address(self.bool_area, offset),
+            word_order: None,
+        }
+    }
+
+    /// 上位机中填写的通讯地址
+    fn display_address(&self, area: RegisterArea, offset: u32) -> u32 {
+        let number = if self.one_based { offset + 1 } else { offset };
+        if !self.prefixed {
+            return number;
+        }
+
+        // 超过9999时使用6位编号，例如400001
+        let scale = if number <= SHORT_REGISTER_LIMIT { 10000 } else { 100000 };
+        area.prefix_digit() * scale + number
+    }
+}
+
+/// 单个点位的Modbus映射结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModbusPoint {
+    /// 功能区
+    pub area: RegisterArea,
+    /// 协议偏移（从0开始）
+    pub offset: u32,
+    /// 占用的寄存器或线圈数
+    pub span: u32,
+    /// 上位机通讯地址
+    pub address: u32,
+    /// 字序（仅32位及64位数值）
+    pub word_order: Option<WordOrder>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_address_conventions() {
+        let profile = ModbusProfile::default();
+        assert_eq!(profile.register_point(3160, 2, true).address, 43161);
+        assert_eq!(profile.bool_point(3160).address, 3161);
+        assert_eq!(profile.register_point(12000, 2, true).address, 412001);
+        assert_eq!(profile.register_point(3160, 1, false).word_order, None);
+
+        let plain = ModbusProfile {
+            one_based: false,
+            prefixed: false,
+            register_area: RegisterArea::InputRegister,
+            word_order: WordOrder::Cdab,
+            ..Default::default()
+        };
+        let point = plain.register_point(3160, 4, true);
+        assert_eq!(point.address, 3160);
+        assert_eq!(point.area, RegisterArea::InputRegister);
+        assert_eq!(point.word_order, Some(WordOrder::Cdab));
+        assert_eq!(point.span, 4);
+    }
+
+    #[test]
+    fn test_validate_rejects_mismatched_areas() {
+        let profile = ModbusProfile {
+            register_area: RegisterArea::Coil,
+            ..Default::default()
+        };
+        assert!(profile.validate().is_err());
+        assert!(ModbusProfile::default().validate().is_ok());
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// 项目信息模型
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectInfo {
     pub id: String,
     pub project_name: String,
@@ -9,6 +9,12 @@ pub struct ProjectInfo {
     pub design_number: String,
     pub customer_name: String,
     pub station_name: String,
+    /// 技术负责人（深化设计B1人员字段）
+    #[serde(default)]
+    pub technical_lead: String,
+    /// 项目经理（深化设计B1人员字段）
+    #[serde(default)]
+    pub project_manager: String,
 }
 
 /// 设备信息模型
@@ -22,6 +28,7 @@ pub struct EquipmentItem {
     pub quantity: f64,
     pub unit: String,
     pub external_param: String,
+    pub subsystem: String,
 }
 
 /// 项目查询响应
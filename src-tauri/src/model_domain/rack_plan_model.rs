@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// 单个机架的槽位使用情况
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RackUtilization {
+    /// 机架号（从1开始）
+    pub rack: u32,
+    /// 已占用的IO槽位数
+    pub used_slots: u32,
+    /// 可用的IO槽位数
+    pub available_slots: u32,
+    /// 槽位利用率（百分比）
+    pub utilization: f64,
+    /// 是否为需要额外订购的虚拟机架
+    pub is_virtual: bool,
+}
+
+/// 机架容量规划结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RackCapacityPlan {
+    /// 设备清单中已有的机架数（LK117数量）
+    pub installed_racks: u32,
+    /// 放下全部IO模块所需的最少机架数
+    pub required_racks: u32,
+    /// 建议额外订购的机架数
+    pub racks_to_order: u32,
+    /// 需要占用IO槽位的模块总数（含冗余控制器的备用CPU）
+    pub total_modules: u32,
+    /// 每个机架可用的IO槽位数
+    pub slots_per_rack: u32,
+    /// 各机架的槽位使用情况
+    pub racks: Vec<RackUtilization>,
+    /// 是否配置了冗余控制器
+    pub redundant_controller: bool,
+}
+
+impl RackCapacityPlan {
+    /// 已有机架是否足够放下全部模块
+    pub fn is_sufficient(&self) -> bool {
+        self.racks_to_order == 0
+    }
+}
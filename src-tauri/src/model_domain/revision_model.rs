@@ -0,0 +1,70 @@
+use crate::model_domain::query_model::ProjectInfo;
+use serde::{Deserialize, Serialize};
+
+/// 单次修订记录
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RevisionEntry {
+    /// 修订号（A、B、C…）
+    pub revision: String,
+    pub date: String,
+    /// 编制人
+    pub author: String,
+    /// 修订说明
+    pub description: String,
+    /// 与上一版相比标记的变更单元格数，首版为0
+    pub changed_cells: usize,
+    /// 本版点表保存的快照版本，下一版以该快照为基准标记变更
+    #[serde(default)]
+    pub snapshot_version: Option<u32>,
+}
+
+/// 场站点表的修订历史
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RevisionHistory {
+    pub entries: Vec<RevisionEntry>,
+}
+
+impl RevisionHistory {
+    /// 下一版的修订号，按A…Z、AA、AB…递增
+    pub fn next_revision(&self) -> String {
+        let mut number = self.entries.len() + 1;
+        let mut letters = Vec::new();
+        while number > 0 {
+            let remainder = (number - 1) % 26;
+            letters.push(b'A' + remainder as u8);
+            number = (number - 1) / 26;
+        }
+        letters.iter().rev().map(|c| *c as char).collect()
+    }
+}
+
+/// 点表文档信息，用于生成封面和修订记录
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DocumentInfo {
+    /// 项目信息；技术负责人和项目经理不在项目查询结果中，由调用方填写
+    pub project: ProjectInfo,
+    /// 编制人
+    pub author: String,
+    /// 修订日期（YYYY-MM-DD），由前端按本地时间填写，为空时使用UTC当天
+    pub date: String,
+    /// 本次修订说明
+    pub description: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_revision_letters() {
+        let mut history = RevisionHistory::default();
+        assert_eq!(history.next_revision(), "A");
+
+        history.entries = vec![RevisionEntry::default(); 25];
+        assert_eq!(history.next_revision(), "Z");
+        history.entries.push(RevisionEntry::default());
+        assert_eq!(history.next_revision(), "AA");
+    }
+}
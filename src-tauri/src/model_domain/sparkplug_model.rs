@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+/// Sparkplug B主题命名空间
+pub const SPARKPLUG_NAMESPACE: &str = "spBv1.0";
+
+/// Sparkplug B命名规则
+///
+/// 模板中可使用的占位符：{station}场站、{rack}机架、{slot}槽位、{module}模块名称、
+/// {module_type}模块类型、{channel}通道位号、{tag}位号、{name}变量名称（未填写时为通道位号）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SparkplugNamingRules {
+    /// 组ID模板
+    pub group_id: String,
+    /// 边缘节点ID模板
+    pub edge_node_id: String,
+    /// 设备ID模板
+    pub device_id: String,
+    /// 指标名称模板
+    pub metric_name: String,
+    /// 软点位所在的边缘节点ID，软点位按数据来源作为设备
+    pub soft_point_edge_node_id: String,
+    /// 附加点位指标名称与主点位名称之间的分隔符，"/"在Sparkplug中表示文件夹层级
+    pub sub_metric_separator: String,
+}
+
+impl Default for SparkplugNamingRules {
+    fn default() -> Self {
+        Self {
+            group_id: "{station}".to_string(),
+            edge_node_id: "Rack{rack}".to_string(),
+            device_id: "Slot{slot}_{module_type}".to_string(),
+            metric_name: "{name}".to_string(),
+            soft_point_edge_node_id: "SoftPoints".to_string(),
+            sub_metric_separator: "/".to_string(),
+        }
+    }
+}
+
+/// 上线、离线、数据及命令主题
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugTopics {
+    pub birth: String,
+    pub death: String,
+    pub data: String,
+    pub command: String,
+}
+
+/// 指标定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugMetric {
+    pub name: String,
+    /// 边缘节点内唯一的别名
+    pub alias: u64,
+    /// Sparkplug B数据类型名称
+    pub datatype: String,
+    /// Sparkplug B数据类型编号
+    pub datatype_id: u32,
+    pub writable: bool,
+    pub description: String,
+    pub plc_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eng_low: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eng_high: Option<f64>,
+}
+
+/// 设备及其指标
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugDevice {
+    pub device_id: String,
+    pub topics: SparkplugTopics,
+    pub metrics: Vec<SparkplugMetric>,
+}
+
+/// 边缘节点及其设备
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugEdgeNode {
+    pub edge_node_id: String,
+    pub topics: SparkplugTopics,
+    pub devices: Vec<SparkplugDevice>,
+}
+
+/// 组及其边缘节点
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugGroup {
+    pub group_id: String,
+    pub edge_nodes: Vec<SparkplugEdgeNode>,
+}
+
+/// 网关可直接加载的Sparkplug B映射
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SparkplugMapping {
+    pub namespace: String,
+    pub groups: Vec<SparkplugGroup>,
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 校验问题的严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "错误"),
+            Severity::Warning => write!(f, "警告"),
+        }
+    }
+}
+
+/// 点表校验规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationRule {
+    /// 报警设定值应按SLL、SL、SH、SHH从小到大排列
+    AlarmOrder,
+    /// 报警设定值应在量程范围内，量程低限应小于量程高限
+    LimitOutOfRange,
+    /// 位号不能重复
+    DuplicateTag,
+    /// HMI变量名称不能重复（不区分大小写）
+    DuplicateHmiName,
+    /// 已使用的通道应填写变量描述
+    MissingDescription,
+    /// 已使用的通道应填写位号
+    MissingTag,
+    /// HMI变量名称只能包含字母、数字和下划线，且不能以数字开头
+    InvalidHmiName,
+}
+
+impl fmt::Display for ValidationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ValidationRule::AlarmOrder => "报警设定值顺序",
+            ValidationRule::LimitOutOfRange => "超出量程",
+            ValidationRule::DuplicateTag => "位号重复",
+            ValidationRule::DuplicateHmiName => "HMI变量名称重复",
+            ValidationRule::MissingDescription => "缺少变量描述",
+            ValidationRule::MissingTag => "缺少位号",
+            ValidationRule::InvalidHmiName => "HMI变量名称不合法",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 单条规则的配置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RuleSetting {
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+impl RuleSetting {
+    const fn error() -> Self {
+        Self { enabled: true, severity: Severity::Error }
+    }
+
+    const fn warning() -> Self {
+        Self { enabled: true, severity: Severity::Warning }
+    }
+}
+
+/// 校验规则集，未配置的规则使用默认设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationRuleSet {
+    pub alarm_order: RuleSetting,
+    pub limit_out_of_range: RuleSetting,
+    pub duplicate_tag: RuleSetting,
+    pub duplicate_hmi_name: RuleSetting,
+    pub missing_description: RuleSetting,
+    pub missing_tag: RuleSetting,
+    pub invalid_hmi_name: RuleSetting,
+}
+
+impl Default for ValidationRuleSet {
+    fn default() -> Self {
+        Self {
+            alarm_order: RuleSetting::error(),
+            limit_out_of_range: RuleSetting::error(),
+            duplicate_tag: RuleSetting::error(),
+            duplicate_hmi_name: RuleSetting::error(),
+            missing_description: RuleSetting::warning(),
+            missing_tag: RuleSetting::error(),
+            invalid_hmi_name: RuleSetting::error(),
+        }
+    }
+}
+
+impl ValidationRuleSet {
+    /// 规则的严重程度，规则未启用时返回None
+    pub fn severity_for(&self, rule: ValidationRule) -> Option<Severity> {
+        let setting = match rule {
+            ValidationRule::AlarmOrder => self.alarm_order,
+            ValidationRule::LimitOutOfRange => self.limit_out_of_range,
+            ValidationRule::DuplicateTag => self.duplicate_tag,
+            ValidationRule::DuplicateHmiName => self.duplicate_hmi_name,
+            ValidationRule::MissingDescription => self.missing_description,
+            ValidationRule::MissingTag => self.missing_tag,
+            ValidationRule::InvalidHmiName => self.invalid_hmi_name,
+        };
+        setting.enabled.then_some(setting.severity)
+    }
+}
+
+/// 校验发现的问题
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// Excel行号（从1开始）
+    pub row: u32,
+    /// 列名
+    pub column: String,
+    /// 单元格位置，例如Q5；工作表中缺少该列时为空
+    pub cell: String,
+    pub rule: ValidationRule,
+    pub severity: Severity,
+    /// 单元格内容
+    pub value: String,
+    pub message: String,
+}
+
+/// 点表校验结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// 校验的工作表名称
+    pub sheet_name: String,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn new(sheet_name: String, issues: Vec<ValidationIssue>) -> Self {
+        let error_count = issues.iter().filter(|issue| issue.severity == Severity::Error).count();
+        Self {
+            sheet_name,
+            error_count,
+            warning_count: issues.len() - error_count,
+            issues,
+        }
+    }
+}
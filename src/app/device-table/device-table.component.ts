@@ -1,5 +1,5 @@
 import { Component, OnInit, ViewChild } from '@angular/core';
-import { CommonModule } from '@angular/common';
+import { CommonModule, formatDate } from '@angular/common';
 import { FormsModule } from '@angular/forms';
 import { NzTableModule } from 'ng-zorro-antd/table';
 import { NzButtonModule } from 'ng-zorro-antd/button';
@@ -15,8 +15,8 @@ import { NzTagModule } from 'ng-zorro-antd/tag';
 import { NzTransferModule, TransferChange, TransferItem, TransferSelectChange } from 'ng-zorro-antd/transfer';
 import { SelectDeviceComponent } from './select-device/select-device.component';
 
-// 导入共享服务中的设备项接口
-import { DeviceItem } from '../shared-data.service';
+// 导入共享服务中的接口
+import { DeviceItem, DocumentInfo, GenerationOptions, ProjectInfo } from '../shared-data.service';
 
 /**
  * 设备表组件
@@ -48,6 +48,10 @@ export class DeviceTableComponent implements OnInit {
   deviceData: DeviceItem[] = [];
   // 加载状态
   isLoading = false;
+  // 是否生成封面和修订记录
+  withDocument = false;
+  // 封面上的文档信息，技术负责人和项目经理不在项目查询结果中，需要在此填写
+  documentInfo = { author: '', technical_lead: '', project_manager: '', description: '' };
 
   // 引用穿梭框组件
   @ViewChild(SelectDeviceComponent) selectDeviceComponent!: SelectDeviceComponent;
@@ -343,21 +347,51 @@ export class DeviceTableComponent implements OnInit {
     // 获取当前窗口
     const currentWindow = await getCurrentWindow();
     const selectedProject = this.sharedDataService.getSelectedProject();
+    // 生成选项及项目编号，后端按项目编号读取地址规划和列模板
+    const options: GenerationOptions = {};
+    if (this.withDocument) {
+      options.document = this.buildDocumentInfo(selectedProject!);
+    }
 
-    // 调用后端生成IO点表，严格按照原来的参数格式
+    // 调用后端生成IO点表
     console.log('发送到后端的参数:', {
       equipmentData: equipmentItems,
-      stationName: selectedProject!.station_name
+      stationName: selectedProject!.station_name,
+      projectNumber: selectedProject!.project_number,
+      options
     });
 
-    const filePath: string = await invoke('generate_io_point_table', {
+    const result: { file_path: string; report: any } = await invoke('generate_io_point_table', {
       equipmentData: equipmentItems,
       stationName: selectedProject!.station_name,
+      projectNumber: selectedProject!.project_number,
+      options,
       window: currentWindow
     });
 
-    console.log('生成的IO点表路径:', filePath);
-    return filePath;
+    if (result.report.moved_points.length > 0) {
+      this.message.warning(`有 ${result.report.moved_points.length} 个点位地址与上次生成不一致，请核对`);
+    }
+    return result.file_path;
+  }
+
+  /**
+   * 组装封面的文档信息
+   * @param project 选中的项目
+   * @returns 文档信息
+   */
+  private buildDocumentInfo(project: ProjectInfo): DocumentInfo {
+    return {
+      project: {
+        ...project,
+        technical_lead: this.documentInfo.technical_lead.trim(),
+        project_manager: this.documentInfo.project_manager.trim()
+      },
+      author: this.documentInfo.author.trim(),
+      // 按本地时间填写修订日期，后端只能取得UTC日期
+      date: formatDate(new Date(), 'yyyy-MM-dd', 'en-US'),
+      description: this.documentInfo.description.trim()
+    };
   }
 
   /**
@@ -10,6 +10,9 @@ export interface ProjectInfo {
   design_number: string;
   customer_name: string;
   station_name: string;
+  // 技术负责人、项目经理（深化设计B1人员字段），项目查询结果中没有，生成封面时填写
+  technical_lead?: string;
+  project_manager?: string;
   checked?: boolean;
 }
 
@@ -25,6 +28,8 @@ export interface EquipmentItem {
   quantity: number;
   unit: string;
   external_param: string;
+  subsystem?: string;
+  redundant?: boolean;
 }
 
 /**
@@ -39,6 +44,35 @@ export interface DeviceItem {
   isEditing?: boolean;
 }
 
+/**
+ * 点表文档信息接口，用于生成封面和修订记录
+ */
+export interface DocumentInfo {
+  project: ProjectInfo;
+  author: string;
+  date?: string;
+  description: string;
+}
+
+/**
+ * 点表生成选项接口
+ * 字段名与后端GenerationOptions一致，未填写的字段使用后端默认值；
+ * 未填写address_plan时后端使用项目保存的地址规划
+ */
+export interface GenerationOptions {
+  allow_virtual_racks?: boolean;
+  placement_strategy?: 'sequential' | 'interleaved' | 'by_subsystem' | 'even_spread';
+  signal_counts?: Partial<Record<'AI' | 'AO' | 'DI' | 'DO', number>>;
+  address_plan?: Record<string, unknown>;
+  reset_address_lock?: boolean;
+  previous_table_path?: string;
+  protection_password?: string;
+  column_template_name?: string;
+  per_type_sheets?: boolean;
+  document?: DocumentInfo;
+  [key: string]: unknown;
+}
+
 /**
  * 共享数据服务
  * 用于在组件之间共享数据